@@ -0,0 +1,4115 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::LitStr;
+
+use crate::{
+    parse::{self, handlers_for_route},
+    table,
+};
+
+// A struct representing a directory in the module tree
+#[derive(Debug)]
+struct ModuleDir {
+    name:     String,
+    // File names & the cfg predicate of environment specific route files
+    files:    BTreeMap<String, Option<TokenStream>>,
+    children: BTreeMap<String, ModuleDir>,
+}
+
+impl ModuleDir {
+    fn new(name: &str) -> Self {
+        ModuleDir {
+            name:     name.to_string(),
+            files:    BTreeMap::new(),
+            children: BTreeMap::new(),
+        }
+    }
+
+    fn add_to_module_tree(&mut self, rel_path: &Path, cfg: Option<TokenStream>) {
+        let components = path_segments(rel_path);
+
+        if components.is_empty() {
+            self.files.insert("route.rs".to_string(), cfg);
+            return;
+        }
+
+        let mut root = self;
+
+        for (i, segment) in components.iter().enumerate() {
+            if i == components.len() - 1 {
+                root.files.insert(segment.clone(), cfg);
+                break;
+            }
+
+            root = root
+                .children
+                .entry(segment.clone())
+                .or_insert_with(|| ModuleDir::new(segment));
+        }
+    }
+}
+
+// Add a route to the module tree
+
+// The first API version folder of a route, e.g. `v1` for `v1/users/route.rs`
+fn api_version(rel_path: &Path) -> Option<String> {
+    path_segments(rel_path.parent()?)
+        .into_iter()
+        .find(|dir| parse::is_version_dir(dir))
+}
+
+// Module name of a file, e.g. `route_dev` for `route.dev.rs`
+fn file_module_name(file: &str) -> String {
+    file.trim_end_matches(".rs").replace('.', "_")
+}
+
+// Normalize a path segment for use as a module name
+fn normalize_module_name(name: &str) -> String {
+    if let Some(param) = host_param_name(name) {
+        format!("__host_{param}")
+    } else if name.starts_with('[') && name.ends_with(']') {
+        let inner = &name[1..name.len() - 1];
+        if let Some(stripped) = inner.strip_prefix("...") {
+            format!("___{}", escape_module_name(stripped))
+        } else {
+            format!("__{}", escape_module_name(split_param(inner).0))
+        }
+    } else if name.starts_with('(') && name.ends_with(')') {
+        format!("_{}", escape_module_name(&name[1..name.len() - 1]))
+    } else {
+        keyword_safe(&escape_module_name(name))
+    }
+}
+
+// Replaces `-` & `.` by `_` & escapes other characters which can't be part
+// of an ASCII identifier by their code point, e.g. `_ufc_ber` for `über`
+fn escape_module_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => escaped.push(c),
+            '-' | '.' => escaped.push('_'),
+            c => write!(escaped, "_u{:x}_", u32::from(c)).unwrap(),
+        }
+    }
+    escaped
+}
+
+// Percent-encodes the characters of a static path segment which aren't
+// allowed in URL paths, as axum matches the encoded path, e.g. `%C3%BCber`
+// for `über`
+fn percent_encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@%".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            write!(encoded, "%{byte:02X}").unwrap();
+        }
+    }
+    encoded
+}
+
+// Rust keywords, which folder & parameter names can't be used as is
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+// A keyword as raw identifier like `r#match`, or with a trailing `_` for the
+// keywords which can't be raw identifiers, e.g. `self_`
+fn keyword_safe(name: &str) -> String {
+    match name {
+        "self" | "Self" | "super" | "crate" => format!("{name}_"),
+        name if KEYWORDS.contains(&name) => format!("r#{name}"),
+        name => name.to_string(),
+    }
+}
+
+// The parameter name of a subdomain folder, e.g. `tenant` for `[tenant]!host`
+fn host_param_name(segment: &str) -> Option<&str> {
+    segment.strip_prefix('[')?.strip_suffix("]!host")
+}
+
+// The subdomain parameter of a route, from its top-level folder
+fn host_param(rel_path: &Path) -> Option<String> {
+    let first = rel_path.components().next()?;
+    host_param_name(&first.as_os_str().to_string_lossy()).map(ToString::to_string)
+}
+
+// The extension type carrying a subdomain parameter, e.g. `Tenant`
+fn host_param_type(param: &str) -> Ident {
+    let name: String = param
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    format_ident!("{}", name)
+}
+
+// A path parameter folder like `[id]` or `[id@u32]`, whose `regex.txt` is
+// looked up by its `dir`
+pub struct RouteParam {
+    pub name: String,
+    pub ty:   Option<String>,
+    pub dir:  PathBuf,
+}
+
+// Split a path parameter into its name & optional type
+fn split_param(param: &str) -> (&str, Option<&str>) {
+    match param.split_once('@') {
+        Some((name, ty)) => (name, Some(ty)),
+        None => (param, None),
+    }
+}
+
+// All path parameters of a route, in path order
+pub fn route_params(rel_path: &Path) -> Vec<RouteParam> {
+    let mut dir = PathBuf::new();
+    let mut params = Vec::new();
+
+    for segment in path_segments(rel_path) {
+        dir.push(&segment);
+        let Some(param) = segment
+            .strip_prefix('[')
+            .and_then(|param| param.strip_suffix(']'))
+        else {
+            continue;
+        };
+        let (name, ty) = match param.strip_prefix("...") {
+            Some(stripped) => (stripped, None),
+            None => split_param(param),
+        };
+        params.push(RouteParam {
+            name: name.to_string(),
+            ty:   ty.map(ToString::to_string),
+            dir:  dir.clone(),
+        });
+    }
+
+    params
+}
+
+// Report invalid path parameters & handlers of a route
+fn check_handlers(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    rel_path: &Path,
+    handlers: &parse::RouteHandlers,
+) {
+    check_params(errors, rel_path, handlers);
+    check_error_types(errors, args, rel_path, handlers);
+    check_registered(errors, rel_path, handlers);
+
+    let file = route_path_display(rel_path);
+    for (name, reason) in &handlers.unsupported {
+        let message =
+            format!("Handler `{name}` in {file} {reason}, handlers have to be plain `async fn`s");
+        errors.extend(quote! {
+            compile_error!(#message);
+        });
+    }
+}
+
+// Report route files without a single handler, naming why each of their
+// functions isn't one
+fn check_registered(errors: &mut TokenStream, rel_path: &Path, handlers: &parse::RouteHandlers) {
+    // Unknown content type variants & unsupported handlers are reported on
+    // their own
+    if handlers.method_router
+        || !handlers.methods.is_empty()
+        || !handlers.unknown_variants.is_empty()
+        || !handlers.unsupported.is_empty()
+    {
+        return;
+    }
+
+    let file = route_path_display(rel_path);
+    let message = if handlers.rejected.is_empty() {
+        format!(
+            "No handlers found in {file}, expected a `pub async fn` named after an HTTP method, \
+             e.g. `get`"
+        )
+    } else {
+        let reasons = handlers
+            .rejected
+            .iter()
+            .map(|(name, reason)| format!("`{name}` {reason}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("No handlers found in {file}: {reasons}")
+    };
+    errors.extend(quote! {
+        compile_error!(#message);
+    });
+}
+
+// Report handlers not returning a `Result` with the error type of the `error`
+// option
+fn check_error_types(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    rel_path: &Path,
+    handlers: &parse::RouteHandlers,
+) {
+    let Some(error) = &args.error else {
+        return;
+    };
+    let error = error.to_string().replace(' ', "");
+    let error_name = error.rsplit("::").next().unwrap_or(&error);
+    let file = route_path_display(rel_path);
+
+    for (name, error_type) in &handlers.error_types {
+        if error_type.as_deref() != Some(error_name) {
+            let message = format!("Handler `{name}` in {file} has to return `Result<_, {error}>`");
+            errors.extend(quote! {
+                compile_error!(#message);
+            });
+        }
+    }
+}
+
+/// Reports methods & paths registered by more than one file, e.g. by a route
+/// group & a folder of the same name, which would make axum panic when
+/// building the router
+pub fn check_conflicts(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    routes: &parse::FolderRouterRoutes,
+) {
+    let entries = table::route_table(args, &args.abs_norm_path(), routes);
+
+    for (i, entry) in entries.iter().enumerate() {
+        for other in entries[i + 1..].iter().take_while(|e| e.path == entry.path) {
+            if other.rel_path == entry.rel_path || route_host(entry) != route_host(other) {
+                continue;
+            }
+
+            // Environment variants & folders gated on different features
+            // aren't registered at the same time
+            if let (Some(cfg), Some(other_cfg)) = (routes.entry_cfg(entry), routes.entry_cfg(other))
+            {
+                if cfg.to_string() != other_cfg.to_string() {
+                    continue;
+                }
+            }
+
+            if let Some(method) = overlapping_method(entry, other) {
+                let message = format!(
+                    "`{method} {}` is registered by both {} and {}",
+                    entry.path,
+                    entry.file(),
+                    other.file()
+                );
+                errors.extend(quote! {
+                    compile_error!(#message);
+                });
+            }
+        }
+    }
+}
+
+// The subdomain folder a route is registered in, if any
+fn route_host(entry: &table::RouteEntry) -> Option<String> {
+    let first = entry.rel_path.components().next()?;
+    let first = first.as_os_str().to_string_lossy();
+    host_param_name(&first).map(ToString::to_string)
+}
+
+// A method both routes handle, with `any` & routes without a method router
+// overlapping every method
+fn overlapping_method(entry: &table::RouteEntry, other: &table::RouteEntry) -> Option<String> {
+    let (Some(methods), Some(other_methods)) = (&entry.methods, &other.methods) else {
+        return Some("*".to_string());
+    };
+
+    methods
+        .iter()
+        .find(|method| {
+            **method == "any" || other_methods.iter().any(|m| m == *method || *m == "any")
+        })
+        .map(|method| method.to_uppercase())
+}
+
+// Report invalid regexes, handlers extracting typed path parameters as a
+// different type & unknown content type suffixes
+fn check_params(errors: &mut TokenStream, rel_path: &Path, handlers: &parse::RouteHandlers) {
+    let params = route_params(rel_path);
+    let file = route_path_display(rel_path);
+
+    for dir in rel_path.components().skip(1) {
+        let dir = dir.as_os_str().to_string_lossy();
+        if host_param_name(&dir).is_some() {
+            let message =
+                format!("Subdomain folder `{dir}` in {file} has to be a top-level folder");
+            errors.extend(quote! {
+                compile_error!(#message);
+            });
+        }
+    }
+
+    for param in &params {
+        // Windows doesn't allow `:` in file names
+        if let Some((name, ty)) = param.name.split_once(':') {
+            let message = format!(
+                "Path parameter `{}` in {file} can't contain `:`, declare its type as \
+                 `[{name}@{ty}]`",
+                param.name
+            );
+            errors.extend(quote! {
+                compile_error!(#message);
+            });
+        }
+        // Neither can `"`, `*`, `?`, `|` & `\`, which regexes need
+        if let Some((name, _)) = param.name.split_once('~') {
+            let message = format!(
+                "Path parameter `{}` in {file} can't declare its regex in the folder name, put it \
+                 into `[{name}]/regex.txt`",
+                param.name
+            );
+            errors.extend(quote! {
+                compile_error!(#message);
+            });
+        }
+    }
+
+    for name in &handlers.unknown_variants {
+        let suffixes = parse::MEDIA_TYPES
+            .iter()
+            .map(|(suffix, _)| format!("`{suffix}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let message = format!(
+            "Unknown content type suffix of `{name}` in {file}, expected one of {suffixes}"
+        );
+        errors.extend(quote! {
+            compile_error!(#message);
+        });
+    }
+
+    for (method, extracted) in &handlers.path_types {
+        // Structs & mismatched tuples can't be compared param by param
+        if extracted.len() != params.len() {
+            continue;
+        }
+
+        for (param, extracted) in params.iter().zip(extracted) {
+            let Some(declared) = &param.ty else {
+                continue;
+            };
+            let declared_name = declared.rsplit("::").next().unwrap_or(declared);
+
+            if declared_name != extracted {
+                let message = format!(
+                    "Path parameter `{}` in {file} is declared as `{declared}`, but `{method}` \
+                     extracts it as `{extracted}`",
+                    param.name
+                );
+                errors.extend(quote! {
+                    compile_error!(#message);
+                });
+            }
+        }
+    }
+}
+
+// Render a path parameter, e.g. `{id}`, `{:id}` or `:id`
+fn param_segment(args: &parse::FolderRouterArgs, param: &str) -> String {
+    match args.param_syntax {
+        parse::ParamSyntax::Braces => format!("{{{param}}}"),
+        parse::ParamSyntax::ColonBraces => format!("{{:{param}}}"),
+        parse::ParamSyntax::Colon => format!(":{param}"),
+    }
+}
+
+// Render a catch-all parameter, e.g. `{*path}` or `*path`
+fn catch_all_segment(args: &parse::FolderRouterArgs, param: &str) -> String {
+    match args.param_syntax {
+        parse::ParamSyntax::Braces | parse::ParamSyntax::ColonBraces => format!("{{*{param}}}"),
+        parse::ParamSyntax::Colon => format!("*{param}"),
+    }
+}
+
+// Convert a relative path to module path segments and axum route path
+pub fn path_to_module_path(
+    args: &parse::FolderRouterArgs,
+    rel_path: &Path,
+) -> (String, Vec<String>) {
+    let mut axum_path = String::new();
+    let mut mod_path = Vec::new();
+
+    let components = path_segments(rel_path);
+
+    // Handle root route
+    if components.is_empty() {
+        return ("/".to_string(), vec!["route".to_string()]);
+    }
+
+    for (i, segment) in components.iter().enumerate() {
+        if i == components.len() - 1 && parse::ROUTE_FILES.contains(&segment.as_str()) {
+            mod_path.push(file_module_name(segment));
+        } else {
+            // Process directory name
+            let normalized = normalize_module_name(segment);
+            mod_path.push(normalized);
+
+            // Process URL path
+            if segment.starts_with('(') && segment.ends_with(')') {
+                // Route groups don't add a segment
+            } else if host_param_name(segment).is_some() {
+                // Subdomain parameters are extracted from the Host header
+            } else if segment.starts_with('[') && segment.ends_with(']') {
+                let param = &segment[1..segment.len() - 1];
+                if let Some(stripped) = param.strip_prefix("...") {
+                    write!(&mut axum_path, "/{}", catch_all_segment(args, stripped)).unwrap();
+                } else {
+                    let (param, _) = split_param(param);
+                    write!(&mut axum_path, "/{}", param_segment(args, param)).unwrap();
+                }
+            } else if args.case == parse::Case::Kebab {
+                let segment = percent_encode_segment(&segment.replace('_', "-"));
+                write!(&mut axum_path, "/{segment}").unwrap();
+            } else {
+                write!(&mut axum_path, "/{}", percent_encode_segment(segment)).unwrap();
+            }
+        }
+    }
+
+    if axum_path.is_empty() {
+        axum_path = "/".to_string();
+    }
+
+    (axum_path, mod_path)
+}
+
+// Convert a relative path of a folder file to its module path segments
+fn folder_file_module_path(rel_path: &Path) -> Vec<String> {
+    let components = path_segments(rel_path);
+
+    let (file, dirs) = components.split_last().unwrap();
+    dirs.iter()
+        .map(|dir| normalize_module_name(dir))
+        .chain(std::iter::once(file_module_name(file)))
+        .collect()
+}
+
+// The folder & file names of a relative path, skipping drive prefixes &
+// root or `.` components a path on Windows may start with
+fn path_segments(rel_path: &Path) -> Vec<String> {
+    rel_path
+        .components()
+        .filter_map(|c| match c {
+            std::path::Component::Normal(segment) => Some(segment.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+// Display a relative route path for error messages
+pub fn route_path_display(rel_path: &Path) -> String {
+    rel_path.to_string_lossy().replace('\\', "/")
+}
+
+// Generate tokens for a module path
+fn generate_mod_path_tokens(mod_path: &[String]) -> TokenStream {
+    let mut result = TokenStream::new();
+
+    for (i, segment) in mod_path.iter().enumerate() {
+        let segment_ident = format_ident!("{}", segment);
+
+        if i == 0 {
+            result = quote! { #segment_ident };
+        } else {
+            result = quote! { #result::#segment_ident };
+        }
+    }
+
+    result
+}
+
+// Generate module hierarchy code
+fn generate_module_hierarchy(dir: &ModuleDir, vis: &TokenStream) -> TokenStream {
+    let mut result = TokenStream::new();
+
+    // Add route.rs & folder file modules of this directory
+    for (file, cfg) in &dir.files {
+        let file_mod = format_ident!("{}", file_module_name(file));
+        let file_path_lit = LitStr::new(file, proc_macro2::Span::call_site());
+        let cfg = cfg.as_ref().map(|cfg| quote! { #[cfg(#cfg)] });
+        result.extend(quote! {
+            #cfg
+            #[path = #file_path_lit]
+            #vis mod #file_mod;
+        });
+    }
+
+    // Add subdirectories
+    for child in dir.children.values() {
+        let child_name = format_ident!("{}", normalize_module_name(&child.name));
+        let child_path_lit = LitStr::new(&child.name, proc_macro2::Span::call_site());
+        let child_content = generate_module_hierarchy(child, vis);
+
+        let child_mod = quote! {
+            #[path = #child_path_lit]
+            #vis mod #child_name {
+                #child_content
+            }
+        };
+
+        result.extend(child_mod);
+    }
+
+    result
+}
+
+// Check whether an axum path is equal to or below the given path prefix
+fn path_has_prefix(path: &str, prefix: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+
+    prefix.is_empty()
+        || path == prefix
+        || path
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+// Only compiles `registration` if `cfg` holds
+fn cfg_gate(cfg: Option<TokenStream>, registration: TokenStream) -> TokenStream {
+    let Some(cfg) = cfg else {
+        return registration;
+    };
+
+    quote! {
+        #[cfg(#cfg)]
+        {
+            #registration
+        }
+    }
+}
+
+// Only runs `registration` if the `FolderRouterConfig` of the `config` option
+// doesn't disable `path`
+fn config_gate(
+    args: &parse::FolderRouterArgs,
+    path: &str,
+    registration: TokenStream,
+) -> TokenStream {
+    if !args.config {
+        return registration;
+    }
+
+    quote! {
+        if config.is_enabled(#path) {
+            #registration
+        }
+    }
+}
+
+// Build the value of the `Allow` header for the given route methods
+fn allow_header(methods: &[&str]) -> String {
+    let mut allowed = Vec::new();
+
+    for method in methods {
+        allowed.push(method.to_uppercase());
+
+        // axum answers HEAD requests using the GET handler
+        if *method == "get" && !methods.contains(&"head") {
+            allowed.push("HEAD".to_string());
+        }
+    }
+    if !methods.contains(&"options") {
+        allowed.push("OPTIONS".to_string());
+    }
+
+    allowed.join(", ")
+}
+
+// Wrap a handler with the layers configured by its attributes
+fn handler_tokens(handler: TokenStream, attrs: &parse::HandlerAttrs) -> TokenStream {
+    let mut handler = handler;
+
+    if attrs.deprecated {
+        let layer = deprecation_layer(None, None);
+        handler = quote! { axum::handler::Handler::layer(#handler, #layer) };
+    }
+
+    if let Some(timeout) = attrs.timeout {
+        handler = quote! {
+            axum::handler::Handler::layer(
+                #handler,
+                tower_http::timeout::TimeoutLayer::with_status_code(
+                    axum::http::StatusCode::REQUEST_TIMEOUT,
+                    std::time::Duration::from_millis(#timeout),
+                ),
+            )
+        };
+    }
+
+    if let Some(body_limit) = attrs.body_limit {
+        handler = quote! {
+            axum::handler::Handler::layer(
+                #handler,
+                axum::extract::DefaultBodyLimit::max(#body_limit),
+            )
+        };
+    }
+
+    if let Some(upload) = &attrs.upload {
+        let layer = upload_layer(upload);
+        let limit = upload.limit;
+        handler = quote! {
+            axum::handler::Handler::layer(
+                axum::handler::Handler::layer(#handler, axum::extract::DefaultBodyLimit::max(#limit)),
+                #layer,
+            )
+        };
+    }
+
+    // Headers set by the handler itself win
+    if let Some(cache_control) = &attrs.cache_control {
+        handler = quote! {
+            axum::handler::Handler::layer(
+                #handler,
+                axum::middleware::map_response(|mut response: axum::response::Response| async move {
+                    response
+                        .headers_mut()
+                        .entry(axum::http::header::CACHE_CONTROL)
+                        .or_insert(const { axum::http::HeaderValue::from_static(#cache_control) });
+                    response
+                }),
+            )
+        };
+    }
+
+    if attrs.etag {
+        let layer = etag_layer();
+        handler = quote! { axum::handler::Handler::layer(#handler, #layer) };
+    }
+
+    // Handler layers are applied for every request, so the semaphore has to
+    // be shared by all services of the layer
+    if let Some(concurrency_limit) = attrs.concurrency_limit {
+        handler = quote! {
+            axum::handler::Handler::layer(
+                #handler,
+                tower::limit::GlobalConcurrencyLimitLayer::new(#concurrency_limit),
+            )
+        };
+    }
+
+    #[cfg(feature = "rate-limit")]
+    if let Some(rate_limit) = &attrs.rate_limit {
+        let period_ns = 1_000_000_000 / rate_limit.per_second;
+        let burst = rate_limit.burst;
+
+        handler = quote! {
+            axum::handler::Handler::layer(
+                #handler,
+                tower_governor::GovernorLayer::new(
+                    tower_governor::governor::GovernorConfigBuilder::default()
+                        .per_nanosecond(#period_ns)
+                        .burst_size(#burst)
+                        .finish()
+                        .unwrap(),
+                ),
+            )
+        };
+    }
+
+    handler
+}
+
+// Routes below a `[tenant]!host` folder only match hosts with a subdomain,
+// which is passed to the handlers as an `Extension<Tenant>`
+fn subdomain_layer(param: &str) -> TokenStream {
+    let param_type = host_param_type(param);
+    let host = request_host();
+
+    quote! {
+        axum::middleware::from_fn(
+            |mut request: axum::extract::Request, next: axum::middleware::Next| async move {
+                #host
+                match #param_type::from_host(host) {
+                    Some(subdomain) => {
+                        request.extensions_mut().insert(subdomain);
+                        next.run(request).await
+                    }
+                    None => axum::response::IntoResponse::into_response(
+                        axum::http::StatusCode::NOT_FOUND,
+                    ),
+                }
+            }
+        )
+    }
+}
+
+// `let host = ...;` of a `request`, from its URI or Host header
+fn request_host() -> TokenStream {
+    quote! {
+        let host = request
+            .uri()
+            .host()
+            .or_else(|| {
+                request
+                    .headers()
+                    .get(axum::http::header::HOST)
+                    .and_then(|host| host.to_str().ok())
+            })
+            .unwrap_or_default();
+    }
+}
+
+// Wrap a route in the `middleware` fn & `Guard` extractor of its route.rs,
+// inside all other layers
+fn route_middleware(
+    mut builder: TokenStream,
+    handler_path: &TokenStream,
+    handlers: &parse::RouteHandlers,
+) -> TokenStream {
+    if handlers.middleware {
+        builder = quote! {
+            #builder.route_layer(axum::middleware::from_fn(#handler_path::middleware))
+        };
+    }
+
+    let metadata = &handlers.metadata;
+    if metadata.deprecated {
+        let layer = deprecation_layer(
+            metadata.sunset.as_deref(),
+            metadata.deprecation_link.as_deref(),
+        );
+        builder = quote! { #builder.layer(#layer) };
+    }
+
+    if handlers.guard {
+        builder = quote! {
+            #builder.route_layer(axum::middleware::from_extractor::<#handler_path::Guard>())
+        };
+    }
+
+    builder
+}
+
+// Wraps HTML responses in all layout.rs of parent folders, starting with
+// the innermost. Fragments are swapped into an already wrapped page & GraphQL
+// playgrounds are complete documents.
+fn layout_layers(
+    mut builder: TokenStream,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    rel_path: &Path,
+) -> TokenStream {
+    let layouts = if rel_path.ends_with("fragment.rs") || rel_path.ends_with("graphql.rs") {
+        Vec::new()
+    } else {
+        routes.folder_files_for(rel_path, "layout.rs")
+    };
+    for file in layouts.iter().rev() {
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+
+        builder = quote! {
+            #builder.route_layer(axum::middleware::from_fn(
+                |request: axum::extract::Request, next: axum::middleware::Next| async move {
+                    wrap_in_layout(
+                        next.run(request).await,
+                        #mod_namespace::#file_mod_path_tokens::layout,
+                    )
+                    .await
+                }
+            ))
+        };
+    }
+
+    builder
+}
+
+// Wrap a route's method router with the layers of its folder files & options
+fn route_layers(
+    mut builder: TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    rel_path: &Path,
+    axum_path: &str,
+    methods: Option<&[&str]>,
+) -> TokenStream {
+    builder = layout_layers(builder, mod_namespace, routes, rel_path);
+
+    for layer in guard_layers(args, mod_namespace, routes, rel_path) {
+        builder = quote! { #builder.route_layer(#layer) };
+    }
+
+    // Answer 404 for path parameters not matching their regex, as if the
+    // route didn't match
+    let regex_checks: Vec<TokenStream> = route_params(rel_path)
+        .into_iter()
+        .filter_map(|param| {
+            let regex = format!("^(?:{})$", routes.param_regex(&param.dir)?);
+            let name = param.name;
+            let regex_ident = format_ident!("{}_REGEX", name.to_uppercase());
+            Some(quote! {
+                // Compiled once on first use
+                static #regex_ident: std::sync::LazyLock<regex::Regex> =
+                    std::sync::LazyLock::new(|| regex::Regex::new(#regex).unwrap());
+                if name == #name && !#regex_ident.is_match(value) {
+                    return axum::response::IntoResponse::into_response(
+                        axum::http::StatusCode::NOT_FOUND,
+                    );
+                }
+            })
+        })
+        .collect();
+
+    if !regex_checks.is_empty() {
+        builder = quote! {
+            #builder.route_layer(axum::middleware::from_fn(
+                |params: axum::extract::RawPathParams,
+                 request: axum::extract::Request,
+                 next: axum::middleware::Next| async move {
+                    for (name, value) in &params {
+                        #(#regex_checks)*
+                    }
+                    next.run(request).await
+                }
+            ))
+        };
+    }
+
+    if let Some(param) = host_param(rel_path) {
+        let layer = subdomain_layer(&param);
+        builder = quote! { #builder.route_layer(#layer) };
+    }
+
+    builder = access_layers(
+        builder,
+        args,
+        mod_namespace,
+        routes,
+        rel_path,
+        axum_path,
+        methods,
+    );
+
+    builder = config_layers(builder, routes, rel_path);
+
+    let compression = routes
+        .folder_config(rel_path)
+        .compression
+        .unwrap_or_else(|| {
+            args.compression
+                .iter()
+                .any(|prefix| path_has_prefix(axum_path, prefix))
+        });
+    if compression {
+        builder = quote! {
+            #builder.layer(tower_http::compression::CompressionLayer::new())
+        };
+    }
+
+    // Spans are named after the route's path, as their names have to be
+    // known at compile time
+    if args.trace {
+        builder = quote! {
+            #builder.layer::<_, std::convert::Infallible>(
+                tower_http::trace::TraceLayer::new_for_http().make_span_with(
+                |request: &axum::extract::Request| {
+                    tracing::info_span!(#axum_path, method = %request.method(), uri = %request.uri())
+                })
+            )
+        };
+    }
+
+    // Routes of deprecated API versions announce it in their response headers
+    if let Some(version) = api_version(rel_path).filter(|version| args.deprecated.contains(version))
+    {
+        let layer = deprecation_layer(args.sunset.get(&version).map(String::as_str), None);
+        builder = quote! { #builder.layer(#layer) };
+    }
+
+    // Apply the closest cors.rs to the whole route, including its fallback
+    if let Some(file) = routes.nearest_folder_file(rel_path, "cors.rs") {
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+
+        builder = quote! {
+            #builder.layer(#mod_namespace::#file_mod_path_tokens::cors())
+        };
+    }
+
+    builder
+}
+
+// The layers of all guard.rs of parent folders, innermost first so the
+// outermost runs first
+fn guard_layers(
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    rel_path: &Path,
+) -> Vec<TokenStream> {
+    let mut layers = Vec::new();
+
+    for file in routes.folder_files_for(rel_path, "guard.rs").iter().rev() {
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+        let guard_path = quote! { #mod_namespace::#file_mod_path_tokens };
+
+        layers.push(
+            if parse::is_extractor_guard(&args.abs_norm_path().join(file)) {
+                quote! { axum::middleware::from_extractor::<#guard_path::Guard>() }
+            } else {
+                quote! { axum::middleware::from_fn(#guard_path::guard) }
+            },
+        );
+    }
+
+    layers
+}
+
+// Answers requests without a key in the header of the api_key.rs `file` or
+// with one rejected by its `validate` fn with `401 Unauthorized`
+//
+// Middleware can't access the state, as it's only provided after the router is
+// built. So the method router is called by a handler extracting it instead,
+// registered for the same `methods` to still merge with other method routers
+// of its path, or for any method if they're unknown.
+fn api_key_check(
+    builder: &TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    file: &Path,
+    methods: Option<&[&str]>,
+) -> TokenStream {
+    let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+    let api_key_path = quote! { #mod_namespace::#file_mod_path_tokens };
+    let header = if parse::defines_const(&args.abs_norm_path().join(file), "HEADER") {
+        quote! { #api_key_path::HEADER }
+    } else {
+        quote! { "x-api-key" }
+    };
+    let state_type = &args.state_type;
+
+    let register = match methods {
+        Some(methods) if !methods.is_empty() && !methods.contains(&"any") => {
+            let mut filters = methods.iter().map(|method| {
+                let filter = format_ident!("{}", method.to_uppercase());
+                quote! { axum::routing::MethodFilter::#filter }
+            });
+            let first = filters.next();
+            quote! { axum::routing::on(#first #(.or(#filters))*, check) }
+        }
+        _ => quote! { axum::routing::any(check) },
+    };
+
+    quote! {{
+        let method_router = #builder;
+        let check = move |axum::extract::State(state): axum::extract::State<#state_type>,
+                          request: axum::extract::Request| async move {
+            let key = request
+                .headers()
+                .get(#header)
+                .and_then(|key| key.to_str().ok());
+            let valid = match key {
+                Some(key) => #api_key_path::validate(&state, key).await,
+                None => false,
+            };
+            if !valid {
+                return axum::response::IntoResponse::into_response(
+                    axum::http::StatusCode::UNAUTHORIZED,
+                );
+            }
+            axum::handler::Handler::call(method_router, request, state).await
+        };
+        #register
+    }}
+}
+
+// Restrict a route to requests with a valid API key, JWT, session or CSRF
+// token, as configured for its folders & path
+fn access_layers(
+    mut builder: TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    rel_path: &Path,
+    axum_path: &str,
+    methods: Option<&[&str]>,
+) -> TokenStream {
+    if let Some(file) = routes.nearest_folder_file(rel_path, "api_key.rs") {
+        builder = api_key_check(&builder, args, mod_namespace, file, methods);
+    }
+
+    for layer in token_layers(args, rel_path, axum_path, false) {
+        builder = quote! { #builder.route_layer(#layer) };
+    }
+
+    builder
+}
+
+// The middleware checking the JWT, session & CSRF token of requests to
+// `axum_path`, innermost first. Routers & services `nested` at `axum_path` are
+// checked as a whole if any of their paths has to be.
+fn token_layers(
+    args: &parse::FolderRouterArgs,
+    rel_path: &Path,
+    axum_path: &str,
+    nested: bool,
+) -> Vec<TokenStream> {
+    let covers = |prefix: &String| {
+        path_has_prefix(axum_path, prefix) || (nested && path_has_prefix(prefix, axum_path))
+    };
+    let mut layers = Vec::new();
+
+    // Validated before guards, so they can use the claims
+    if args
+        .auth
+        .as_ref()
+        .is_some_and(|auth| auth.paths.is_empty() || auth.paths.iter().any(covers))
+    {
+        layers.push(quote! { axum::middleware::from_fn(check_jwt) });
+    }
+
+    if in_session_group(rel_path) {
+        layers.push(quote! { axum::middleware::from_fn(require_session) });
+    }
+
+    if args.csrf.iter().any(covers) {
+        layers.push(quote! { axum::middleware::from_fn(check_csrf) });
+    }
+
+    layers
+}
+
+// Wrap a route with the layers of the `config.toml` files of its folders
+fn config_layers(
+    mut builder: TokenStream,
+    routes: &parse::FolderRouterRoutes,
+    rel_path: &Path,
+) -> TokenStream {
+    let config = routes.folder_config(rel_path);
+
+    if let Some(guard) = &config.guard {
+        let guard: syn::Path = syn::parse_str(guard).unwrap();
+        builder = quote! { #builder.route_layer(axum::middleware::from_fn(#guard)) };
+    }
+
+    if let Some(timeout) = config.timeout {
+        builder = quote! {
+            #builder.layer::<_, std::convert::Infallible>(
+                tower_http::timeout::TimeoutLayer::with_status_code(
+                    axum::http::StatusCode::REQUEST_TIMEOUT,
+                    std::time::Duration::from_millis(#timeout),
+                ),
+            )
+        };
+    }
+
+    if let Some(body_limit) = config.body_limit {
+        builder = quote! {
+            #builder.layer::<_, std::convert::Infallible>(axum::extract::DefaultBodyLimit::max(#body_limit))
+        };
+    }
+
+    if config.etag == Some(true) {
+        let layer = etag_layer();
+        builder = quote! { #builder.layer(#layer) };
+    }
+
+    builder
+}
+
+// Answers requests whose content type isn't accepted by `upload` with `415
+// Unsupported Media Type` & too large ones with `413 Payload Too Large`
+//
+// The files of `multipart/form-data` requests are checked instead of the
+// request, which requires buffering the body up to its limit
+fn upload_layer(upload: &parse::Upload) -> TokenStream {
+    let parse::Upload {
+        limit,
+        file_limit,
+        accept,
+    } = upload;
+    let file_limit_check = file_limit.map(|file_limit| {
+        quote! {
+            // The file ends with the CRLF before the next boundary
+            if part.len().saturating_sub(headers_end + 6) > #file_limit {
+                return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+            }
+        }
+    });
+
+    quote! {
+        axum::middleware::from_fn(
+            |request: axum::extract::Request, next: axum::middleware::Next| async move {
+                use axum::{
+                    http::{header, StatusCode},
+                    response::IntoResponse,
+                };
+
+                const ACCEPT: &[&str] = &[#(#accept),*];
+                let accepts = |content_type: &str| {
+                    let essence = content_type.split(';').next().unwrap_or_default();
+                    let essence = essence.trim().to_ascii_lowercase();
+                    ACCEPT.is_empty()
+                        || ACCEPT.iter().any(|accepted| match accepted.strip_suffix("/*") {
+                            Some(kind) => essence.split('/').next() == Some(kind),
+                            None => essence == *accepted,
+                        })
+                };
+
+                let content_type = request
+                    .headers()
+                    .get(header::CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                if !content_type.to_ascii_lowercase().starts_with("multipart/form-data") {
+                    if !accepts(&content_type) {
+                        return StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response();
+                    }
+                    return next.run(request).await;
+                }
+                let Some(boundary) = content_type
+                    .split(';')
+                    .find_map(|param| param.trim().strip_prefix("boundary="))
+                    .map(|boundary| format!("--{}", boundary.trim_matches('"')))
+                else {
+                    return StatusCode::BAD_REQUEST.into_response();
+                };
+
+                let (parts, body) = request.into_parts();
+                let Ok(bytes) = axum::body::to_bytes(body, #limit).await else {
+                    return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+                };
+
+                let find = |haystack: &[u8], needle: &[u8]| {
+                    haystack.windows(needle.len()).position(|window| window == needle)
+                };
+                let mut rest: &[u8] = &bytes;
+                while let Some(start) = find(rest, boundary.as_bytes()) {
+                    rest = &rest[start + boundary.len()..];
+                    // The closing boundary is followed by `--`
+                    if rest.starts_with(b"--") {
+                        break;
+                    }
+                    let part = &rest[..find(rest, boundary.as_bytes()).unwrap_or(rest.len())];
+                    let Some(headers_end) = find(part, b"\r\n\r\n") else {
+                        return StatusCode::BAD_REQUEST.into_response();
+                    };
+                    let headers = String::from_utf8_lossy(&part[..headers_end]).to_ascii_lowercase();
+
+                    // Files are the parts with a filename
+                    if headers.contains("filename=") {
+                        let file_type = headers
+                            .lines()
+                            .find_map(|line| line.strip_prefix("content-type:"))
+                            .map_or("application/octet-stream", str::trim);
+                        if !accepts(file_type) {
+                            return StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response();
+                        }
+                        #file_limit_check
+                    }
+                    rest = &rest[part.len()..];
+                }
+
+                let request = axum::extract::Request::from_parts(parts, axum::body::Body::from(bytes));
+                next.run(request).await
+            },
+        )
+    }
+}
+
+// Gives successful responses to `GET` & `HEAD` requests an `ETag` hashed from
+// their body, answering requests whose `If-None-Match` matches it with `304
+// Not Modified`
+//
+// Responses with an `ETag` are left alone, so nested layers don't hash twice
+fn etag_layer() -> TokenStream {
+    quote! {
+        axum::middleware::from_fn(
+            |request: axum::extract::Request, next: axum::middleware::Next| async move {
+                use axum::http::{header, HeaderValue, Method, StatusCode};
+
+                let cacheable = matches!(*request.method(), Method::GET | Method::HEAD);
+                let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+                let response = next.run(request).await;
+                if !cacheable
+                    || response.status() != StatusCode::OK
+                    || response.headers().contains_key(header::ETAG)
+                {
+                    return response;
+                }
+
+                let (mut parts, body) = response.into_parts();
+                let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+                    return axum::response::IntoResponse::into_response(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    );
+                };
+                // FNV-1a, which unlike `DefaultHasher` is stable across builds
+                let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                    (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+                });
+                let etag = format!("\"{hash:016x}\"");
+                let matches = if_none_match
+                    .as_ref()
+                    .and_then(|value| value.to_str().ok())
+                    .is_some_and(|value| {
+                        value.split(',').map(str::trim).any(|tag| {
+                            tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag
+                        })
+                    });
+
+                parts
+                    .headers
+                    .insert(header::ETAG, HeaderValue::from_str(&etag).unwrap());
+                if matches {
+                    parts.status = StatusCode::NOT_MODIFIED;
+                    parts.headers.remove(header::CONTENT_LENGTH);
+                    return axum::response::Response::from_parts(parts, axum::body::Body::empty());
+                }
+                axum::response::Response::from_parts(parts, axum::body::Body::from(bytes))
+            },
+        )
+    }
+}
+
+// Adds a `Deprecation` header to responses, along with a `Sunset` header if
+// a date is given & a `Link` header if a link is given
+fn deprecation_layer(sunset: Option<&str>, link: Option<&str>) -> TokenStream {
+    let header = |name: &str, value: &str| {
+        quote! {
+            response.headers_mut().insert(
+                axum::http::header::HeaderName::from_static(#name),
+                const { axum::http::HeaderValue::from_static(#value) },
+            );
+        }
+    };
+    let sunset = sunset.map(|date| header("sunset", date));
+    let link = link.map(|link| header("link", &format!("<{link}>; rel=\"deprecation\"")));
+    let deprecation = header("deprecation", "true");
+
+    quote! {
+        axum::middleware::map_response(|mut response: axum::response::Response| async move {
+            #deprecation
+            #sunset
+            #link
+            response
+        })
+    }
+}
+
+// Combine the `#[cfg(...)]` predicates of a handler into one
+fn cfg_predicate(attrs: &parse::HandlerAttrs) -> Option<TokenStream> {
+    let predicates: Vec<TokenStream> = attrs
+        .cfg
+        .iter()
+        .filter_map(|predicate| predicate.parse().ok())
+        .collect();
+
+    match predicates.as_slice() {
+        [] => None,
+        [predicate] => Some(predicate.clone()),
+        predicates => Some(quote! { all(#(#predicates),*) }),
+    }
+}
+
+// The handler of `method`, dispatching on the `Accept` header for content
+// negotiated variants like `get__json` & `get__html`
+fn method_handler(
+    args: &parse::FolderRouterArgs,
+    handler_path: &TokenStream,
+    handlers: &parse::RouteHandlers,
+    method: &str,
+) -> TokenStream {
+    let method_ident = format_ident!("{}", method);
+    let Some(variants) = handlers.variants.get(method) else {
+        return quote! { #handler_path::#method_ident };
+    };
+
+    let state_type = &args.state_type;
+    let media_types = variants.suffixes.iter().map(|suffix| {
+        parse::MEDIA_TYPES
+            .iter()
+            .find(|(known, _)| known == suffix)
+            .map(|(_, media_type)| *media_type)
+            .unwrap_or_default()
+    });
+    let arms = variants.suffixes.iter().enumerate().map(|(index, suffix)| {
+        let variant = format_ident!("{}__{}", method, suffix);
+        quote! {
+            Some(#index) => axum::handler::Handler::call(#handler_path::#variant, request, state).await,
+        }
+    });
+    // A plain handler answers requests accepting none of the variants
+    let fallback = if variants.fallback {
+        quote! { axum::handler::Handler::call(#handler_path::#method_ident, request, state).await }
+    } else {
+        quote! { axum::response::IntoResponse::into_response(axum::http::StatusCode::NOT_ACCEPTABLE) }
+    };
+
+    quote! {
+        |axum::extract::State(state): axum::extract::State<#state_type>,
+         request: axum::extract::Request| async move {
+            const MEDIA_TYPES: &[&str] = &[#(#media_types),*];
+
+            // The variant accepted with the highest quality, the first on ties
+            let accept = request
+                .headers()
+                .get(axum::http::header::ACCEPT)
+                .and_then(|accept| accept.to_str().ok())
+                .unwrap_or("*/*");
+            let mut best: Option<(usize, f32)> = None;
+            for media_range in accept.split(',') {
+                let mut parts = media_range.split(';');
+                let media_range = parts.next().unwrap_or_default().trim();
+                let quality: f32 = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .and_then(|quality| quality.parse().ok())
+                    .unwrap_or(1.0);
+                if quality <= 0.0 {
+                    continue;
+                }
+
+                for (index, media_type) in MEDIA_TYPES.iter().enumerate() {
+                    let is_match = media_range == "*/*"
+                        || media_range.eq_ignore_ascii_case(media_type)
+                        || media_range
+                            .strip_suffix("/*")
+                            .is_some_and(|ty| media_type.split('/').next() == Some(ty));
+                    if is_match && best.map_or(true, |(_, best_quality)| quality > best_quality) {
+                        best = Some((index, quality));
+                    }
+                }
+            }
+
+            let mut response = match best.map(|(index, _)| index) {
+                #(#arms)*
+                _ => #fallback,
+            };
+            response.headers_mut().append(
+                axum::http::header::VARY,
+                axum::http::HeaderValue::from_static("accept"),
+            );
+            response
+        }
+    }
+}
+
+// Build the method router of a route from its HTTP method handlers
+fn method_router(
+    args: &parse::FolderRouterArgs,
+    handler_path: &TokenStream,
+    handlers: &parse::RouteHandlers,
+    fallback: Option<TokenStream>,
+) -> TokenStream {
+    let method_registrations = &handlers.methods;
+
+    // (cfg predicate, method, handler) of every registered method
+    let mut registrations = Vec::new();
+    for method in method_registrations {
+        let method_ident = format_ident!("{}", method);
+        let handler = handler_tokens(
+            method_handler(args, handler_path, handlers, method),
+            &handlers.attrs[method],
+        );
+
+        registrations.push((
+            cfg_predicate(&handlers.attrs[method]),
+            method_ident,
+            handler,
+        ));
+    }
+
+    // Explicitly answer HEAD requests using the GET handler
+    if args.auto_head
+        && method_registrations.contains(&"get")
+        && !method_registrations.contains(&"head")
+    {
+        registrations.push((
+            cfg_predicate(&handlers.attrs["get"]),
+            format_ident!("head"),
+            method_handler(args, handler_path, handlers, "get"),
+        ));
+    }
+
+    // `any` already answers OPTIONS requests
+    if args.auto_options
+        && !method_registrations.contains(&"options")
+        && !method_registrations.contains(&"any")
+    {
+        let allow = allow_header(method_registrations);
+
+        registrations.push((
+            None,
+            format_ident!("options"),
+            quote! { || async { [(axum::http::header::ALLOW, #allow)] } },
+        ));
+    }
+
+    let mut builder = if registrations.iter().any(|(cfg, ..)| cfg.is_some()) {
+        cfg_method_chain(&registrations)
+    } else {
+        method_chain(&registrations)
+    };
+
+    if let Some(fallback) = fallback {
+        builder = quote! {
+            #builder.fallback(#fallback)
+        };
+    }
+
+    builder
+}
+
+// Chain all method registrations, starting with `axum::routing::<method>`
+fn method_chain(registrations: &[(Option<TokenStream>, Ident, TokenStream)]) -> TokenStream {
+    let (_, first_method, first_handler) = &registrations[0];
+    let mut builder = quote! {
+        axum::routing::#first_method(#first_handler)
+    };
+
+    for (_, method, handler) in &registrations[1..] {
+        builder = quote! {
+            #builder.#method(#handler)
+        };
+    }
+
+    builder
+}
+
+// Register methods one statement at a time, so that each registration can
+// carry the `#[cfg(...)]` of its handler
+fn cfg_method_chain(registrations: &[(Option<TokenStream>, Ident, TokenStream)]) -> TokenStream {
+    let mut statements = Vec::new();
+    let mut rest = registrations;
+
+    // `any` is only available as a constructor and has to come first
+    if let [(cfg, method, handler), tail @ ..] = registrations {
+        if *method == "any" {
+            statements.push(if let Some(cfg) = cfg {
+                quote! {
+                    #[cfg(#cfg)]
+                    let method_router = axum::routing::any(#handler);
+                    #[cfg(not(#cfg))]
+                    let method_router = axum::routing::MethodRouter::new();
+                }
+            } else {
+                quote! {
+                    let method_router = axum::routing::any(#handler);
+                }
+            });
+            rest = tail;
+        }
+    }
+
+    if statements.is_empty() {
+        statements.push(quote! {
+            let method_router = axum::routing::MethodRouter::new();
+        });
+    }
+
+    for (cfg, method, handler) in rest {
+        let cfg = cfg.as_ref().map(|cfg| quote! { #[cfg(#cfg)] });
+
+        statements.push(quote! {
+            #cfg
+            let method_router = method_router.#method(#handler);
+        });
+    }
+
+    quote! {
+        {
+            #(#statements)*
+            method_router
+        }
+    }
+}
+
+// A ROUTE_PATH const in route.rs overrides the folder based path
+fn override_path(
+    errors: &mut TokenStream,
+    rel_path: &Path,
+    handlers: &parse::RouteHandlers,
+    axum_path: String,
+) -> String {
+    let Some(route_path) = &handlers.route_path else {
+        return axum_path;
+    };
+
+    if !route_path.starts_with('/') {
+        let file = route_path_display(rel_path);
+        errors.extend(quote! {
+            compile_error!(concat!(
+                "Invalid ROUTE_PATH \"", #route_path, "\" in ", #file, "\n",
+                "Route paths must start with a `/`."
+            ));
+        });
+    }
+    route_path.clone()
+}
+
+fn route_registrations(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    chunked: bool,
+) -> TokenStream {
+    let mut route_method_registrations = Vec::new();
+    let mut redirect_trailing_slash = false;
+    for (route_path, rel_path) in routes {
+        // Generate module path and axum path
+        let (axum_path, mod_path) = path_to_module_path(args, rel_path);
+
+        #[cfg(feature = "debug")]
+        println!(
+            "/// [folder_router] Found route.rs for axum_path: {:?}, mod_path: {:?}",
+            axum_path, mod_path
+        );
+
+        let handlers = handlers_for_route(route_path);
+        check_handlers(errors, args, rel_path, &handlers);
+
+        #[cfg(feature = "debug")]
+        println!(
+            "/// [folder_router] Found methods for axum_path: {:?}, mod_path: {:?}, methods: {:?}",
+            axum_path, mod_path, handlers.methods
+        );
+
+        let mod_path_tokens = generate_mod_path_tokens(&mod_path);
+        let handler_path = quote! { #mod_namespace::#mod_path_tokens };
+
+        let axum_path = override_path(errors, rel_path, &handlers, axum_path);
+
+        let fallback = route_fallback(mod_namespace, routes, rel_path, &handler_path, &handlers);
+        let methods = answered_methods(args, &handlers, fallback.is_some());
+        let builder = if handlers.method_router {
+            // A method_router fn in route.rs is used as is
+            quote! { #handler_path::method_router() }
+        } else if !handlers.methods.is_empty() {
+            method_router(args, &handler_path, &handlers, fallback)
+        } else {
+            continue;
+        };
+
+        let builder = route_middleware(builder, &handler_path, &handlers);
+        let builder = route_layers(
+            builder,
+            args,
+            mod_namespace,
+            routes,
+            rel_path,
+            &axum_path,
+            methods.as_deref(),
+        );
+
+        // Reference ROUTE_PATH consts, so they aren't reported as unused
+        let path_tokens = if handlers.route_path.is_some() {
+            quote! { #handler_path::ROUTE_PATH }
+        } else {
+            axum_path.to_token_stream()
+        };
+
+        let slash_path = trailing_slash_path(&axum_path);
+        let mut registration = match (args.trailing_slash, slash_path) {
+            (parse::TrailingSlash::Merge, Some(slash_path)) => quote! {
+                let method_router = #builder;
+                router = router.route(#path_tokens, method_router.clone());
+                router = router.route(#slash_path, method_router);
+            },
+            (parse::TrailingSlash::Redirect, Some(slash_path)) => {
+                redirect_trailing_slash = true;
+                quote! {
+                    router = router.route(#path_tokens, #builder);
+                    router = router.route(
+                        #slash_path,
+                        axum::routing::any(redirect_trailing_slash),
+                    );
+                }
+            }
+            _ => quote! {
+                router = router.route(#path_tokens, #builder);
+            },
+        };
+        registration.extend(metadata_refs(&handler_path, &handlers.metadata));
+        // `#[deprecated]` handlers are registered without warnings
+        if handlers.attrs.values().any(|attrs| attrs.deprecated) {
+            registration = quote! { #[allow(deprecated)] { #registration } };
+        }
+
+        registration = config_gate(args, &axum_path, registration);
+
+        // Environment specific & feature gated route files are only registered
+        // in their builds
+        route_method_registrations.push((
+            top_level_folder(rel_path),
+            cfg_gate(routes.route_cfg(rel_path), registration),
+        ));
+    }
+    route_method_registrations.extend(resource_registrations(errors, args, mod_namespace, routes));
+    route_method_registrations.extend(page_registrations(args, mod_namespace, routes));
+    route_method_registrations.extend(fragment_registrations(args, mod_namespace, routes));
+    route_method_registrations.extend(graphql_registrations(errors, args, mod_namespace, routes));
+    route_method_registrations.extend(redirect_registrations(args, routes));
+
+    if route_method_registrations.is_empty() {
+        errors.extend(no_routes_error(routes));
+    }
+
+    check_session_groups(errors, routes);
+    let mut registrations = shared_fns(args, routes, redirect_trailing_slash);
+    if chunked {
+        registrations.extend(chunk_registrations(args, route_method_registrations));
+    } else {
+        registrations.extend(route_method_registrations.into_iter().map(|(_, r)| r));
+    }
+
+    registrations
+}
+
+// The fns shared by the registrations of all routes
+fn shared_fns(
+    args: &parse::FolderRouterArgs,
+    routes: &parse::FolderRouterRoutes,
+    redirect_trailing_slash: bool,
+) -> TokenStream {
+    let mut fns = TokenStream::new();
+    // Shared handler of all trailing slash redirects
+    if redirect_trailing_slash {
+        fns.extend(redirect_trailing_slash_fn());
+    }
+    // Shared middleware of all routes validated by `auth = jwt(...)`
+    if let Some(auth) = &args.auth {
+        fns.extend(check_jwt_fn(auth));
+    }
+    // Shared middleware of all routes below the `csrf` prefixes
+    if !args.csrf.is_empty() {
+        fns.extend(check_csrf_fn());
+        fns.extend(form_field_fn());
+    }
+    // Shared middleware of all routes in `(session)` groups
+    if session_group_paths(routes).any(in_session_group) {
+        fns.extend(require_session_fn());
+    }
+    // Shared wrapper of all layout.rs files
+    if routes
+        .folder_files()
+        .any(|file| file.ends_with("layout.rs"))
+    {
+        fns.extend(wrap_in_layout_fn());
+    }
+    fns
+}
+
+// The fallback of a route.rs answering requests with unregistered methods
+fn route_fallback(
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    rel_path: &Path,
+    handler_path: &TokenStream,
+    handlers: &parse::RouteHandlers,
+) -> Option<TokenStream> {
+    // A fallback in route.rs takes precedence over method_not_allowed.rs files
+    if handlers.method_not_allowed {
+        return Some(quote! { #handler_path::method_not_allowed });
+    }
+
+    routes
+        .nearest_folder_file(rel_path, "method_not_allowed.rs")
+        .map(|file| {
+            let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+            quote! { #mod_namespace::#file_mod_path_tokens::method_not_allowed }
+        })
+}
+
+// The methods answered by a route.rs, unknown for method_router fns &
+// fallbacks
+fn answered_methods(
+    args: &parse::FolderRouterArgs,
+    handlers: &parse::RouteHandlers,
+    fallback: bool,
+) -> Option<Vec<&'static str>> {
+    if handlers.method_router || fallback {
+        return None;
+    }
+
+    let mut methods = handlers.methods.clone();
+    if args.auto_options && !methods.contains(&"options") {
+        methods.push("options");
+    }
+    Some(methods)
+}
+
+// Reference the metadata consts of a route.rs, so they aren't reported as
+// unused & checked to have the expected types
+fn metadata_refs(handler_path: &TokenStream, metadata: &parse::RouteMetadata) -> TokenStream {
+    let mut refs = TokenStream::new();
+    if metadata.name.is_some() {
+        refs.extend(quote! { let _: &str = #handler_path::NAME; });
+    }
+    if !metadata.tags.is_empty() {
+        refs.extend(quote! { let _: &[&str] = #handler_path::TAGS; });
+    }
+    if metadata.description.is_some() {
+        refs.extend(quote! { let _: &str = #handler_path::DESCRIPTION; });
+    }
+    if metadata.deprecated {
+        refs.extend(quote! { let _: bool = #handler_path::DEPRECATED; });
+    }
+    if metadata.sunset.is_some() {
+        refs.extend(quote! { let _: &str = #handler_path::SUNSET; });
+    }
+    if metadata.deprecation_link.is_some() {
+        refs.extend(quote! { let _: &str = #handler_path::DEPRECATION_LINK; });
+    }
+    refs
+}
+
+// Lists the scanned route files & their `pub async fn`s, as none of them
+// is registered as a handler
+fn no_routes_error(routes: &parse::FolderRouterRoutes) -> TokenStream {
+    let mut message = String::from(
+        "No routes defined in your route.rs's !\nEnsure that at least one `pub async fn` named \
+         after an HTTP verb is defined. (e.g. get, post, put, delete)",
+    );
+
+    for (route_path, rel_path) in routes {
+        let fns = &handlers_for_route(route_path).pub_async_fns;
+        let file = route_path_display(rel_path);
+        let names = fns
+            .iter()
+            .map(|name| format!("`{name}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match fns.len() {
+            0 => write!(message, "\nScanned {file}: no `pub async fn`s"),
+            1 => write!(message, "\nScanned {file}: 1 `pub async fn`: {names}"),
+            n => write!(message, "\nScanned {file}: {n} `pub async fn`s: {names}"),
+        }
+        .unwrap();
+    }
+
+    quote! {
+        compile_error!(#message);
+    }
+}
+
+// The first folder of `rel_path`, empty for files in the root folder
+fn top_level_folder(rel_path: &Path) -> String {
+    let mut components = rel_path.components();
+    let folder = components.next();
+    if components.next().is_none() {
+        return String::new();
+    }
+    folder
+        .map(|folder| folder.as_os_str().to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+// Moves the registrations of every top-level folder into an `#[inline(never)]`
+// function, so rustc compiles many small functions instead of one huge one
+fn chunk_registrations(
+    args: &parse::FolderRouterArgs,
+    registrations: Vec<(String, TokenStream)>,
+) -> TokenStream {
+    // In the order of the registrations, which are sorted by specificity
+    let mut chunks: Vec<(String, Vec<TokenStream>)> = Vec::new();
+    for (folder, registration) in registrations {
+        match chunks.iter_mut().find(|(f, _)| *f == folder) {
+            Some((_, chunk)) => chunk.push(registration),
+            None => chunks.push((folder, vec![registration])),
+        }
+    }
+
+    let state_type = &args.state_type;
+    let (config_param, config_arg) = if args.config {
+        (
+            quote! { , config: &FolderRouterConfig },
+            quote! { , &config },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
+    let mut chunked = TokenStream::new();
+    for (folder, registrations) in chunks {
+        // Routes of the root folder stay inline
+        if folder.is_empty() {
+            chunked.extend(registrations);
+            continue;
+        }
+
+        let module_name = normalize_module_name(&folder);
+        let chunk_fn = format_ident!("__register_{}", module_name.trim_start_matches("r#"));
+        chunked.extend(quote! {
+            #[inline(never)]
+            #[allow(unused_mut)]
+            fn #chunk_fn(
+                mut router: axum::Router<#state_type> #config_param
+            ) -> axum::Router<#state_type> {
+                #(#registrations)*
+                router
+            }
+            router = #chunk_fn(router #config_arg);
+        });
+    }
+
+    chunked
+}
+
+// The collection & the member route of the resource.rs in `dir`, with the
+// (action, method) pairs of the defined `actions`
+pub fn resource_routes(
+    args: &parse::FolderRouterArgs,
+    dir: &Path,
+    actions: &[&'static str],
+) -> Vec<(String, Vec<(&'static str, &'static str)>)> {
+    let (axum_path, _) = path_to_module_path(args, dir);
+    let member_path = format!(
+        "{}/{}",
+        axum_path.trim_end_matches('/'),
+        param_segment(args, "id")
+    );
+
+    [
+        (axum_path, vec![("index", "get"), ("create", "post")]),
+        (member_path, vec![
+            ("show", "get"),
+            ("update", "put"),
+            ("update", "patch"),
+            ("destroy", "delete"),
+        ]),
+    ]
+    .into_iter()
+    .map(|(path, action_methods)| {
+        let action_methods = action_methods
+            .into_iter()
+            .filter(|(action, _)| actions.contains(action))
+            .collect();
+        (path, action_methods)
+    })
+    .collect()
+}
+
+// Expand resource.rs files into a collection & a member route
+fn resource_registrations(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+) -> Vec<(String, TokenStream)> {
+    let mut registrations = Vec::new();
+
+    for file in routes
+        .folder_files()
+        .filter(|file| file.file_name().unwrap_or_default() == "resource.rs")
+    {
+        let dir = file.parent().unwrap();
+        if routes
+            .into_iter()
+            .any(|(_, rel_path)| rel_path.parent() == Some(dir))
+        {
+            let file = route_path_display(file);
+            errors.extend(quote! {
+                compile_error!(concat!(
+                    "Both a route.rs & a resource.rs define ", #file, "\n",
+                    "Remove one of them."
+                ));
+            });
+            continue;
+        }
+
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+        let handler_path = quote! { #mod_namespace::#file_mod_path_tokens };
+        let actions = parse::actions_for_resource(&args.abs_norm_path().join(file));
+
+        for (path, action_methods) in resource_routes(args, dir, &actions) {
+            let methods: Vec<&str> = action_methods.iter().map(|(_, method)| *method).collect();
+            let method_registrations: Vec<_> = action_methods
+                .into_iter()
+                .map(|(action, method)| {
+                    let action = format_ident!("{}", action);
+                    (
+                        None,
+                        format_ident!("{}", method),
+                        quote! { #handler_path::#action },
+                    )
+                })
+                .collect();
+
+            if method_registrations.is_empty() {
+                continue;
+            }
+
+            let mut builder = method_chain(&method_registrations);
+            let mut methods = Some(methods.as_slice());
+            if let Some(fallback) = routes.nearest_folder_file(file, "method_not_allowed.rs") {
+                let fallback_mod_path_tokens =
+                    generate_mod_path_tokens(&folder_file_module_path(fallback));
+                builder = quote! {
+                    #builder.fallback(#mod_namespace::#fallback_mod_path_tokens::method_not_allowed)
+                };
+                methods = None;
+            }
+            let builder = route_layers(builder, args, mod_namespace, routes, file, &path, methods);
+
+            let registration = config_gate(args, &path, quote! {
+                router = router.route(#path, #builder);
+            });
+            registrations.push((
+                top_level_folder(file),
+                cfg_gate(routes.folder_cfg(file), registration),
+            ));
+        }
+    }
+
+    registrations
+}
+
+// Registers the `page` fn of every page.rs as the GET handler of its folder
+// & the `action` fn of an action.rs as its POST handler, next to the handlers
+// of a route.rs in the same folder
+fn page_registrations(
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+) -> Vec<(String, TokenStream)> {
+    let mut registrations = Vec::new();
+    let folder_files: Vec<&Path> = routes.folder_files().collect();
+
+    for &file in &folder_files {
+        let dir = file.parent().unwrap();
+        let page = dir.join("page.rs");
+        let action = dir.join("action.rs");
+        // Actions of a page are registered along with it
+        let is_page = file == page;
+        if !is_page && (file != action || folder_files.contains(&page.as_path())) {
+            continue;
+        }
+
+        let (path, _) = path_to_module_path(args, dir);
+        let handler = |file: &Path, method: TokenStream, name: TokenStream| {
+            let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+            quote! { #method(#mod_namespace::#file_mod_path_tokens::#name) }
+        };
+
+        let mut builder = if is_page {
+            handler(&page, quote! { axum::routing::get }, quote! { page })
+        } else {
+            handler(&action, quote! { axum::routing::post }, quote! { action })
+        };
+        let mut methods = vec![if is_page { "get" } else { "post" }];
+        if is_page && folder_files.contains(&action.as_path()) {
+            let action = handler(&action, quote! { .post }, quote! { action });
+            builder = quote! { #builder #action };
+            methods.push("post");
+        }
+        let builder = route_layers(
+            builder,
+            args,
+            mod_namespace,
+            routes,
+            file,
+            &path,
+            Some(&methods),
+        );
+
+        let registration = config_gate(args, &path, quote! {
+            router = router.route(#path, #builder);
+        });
+        registrations.push((
+            top_level_folder(file),
+            cfg_gate(routes.folder_cfg(file), registration),
+        ));
+    }
+
+    registrations
+}
+
+/// The `/fragments/{name}` route of every fragment fn of a fragment.rs in
+/// `dir`, along with the fn's name
+pub fn fragment_routes(
+    args: &parse::FolderRouterArgs,
+    dir: &Path,
+    fragments: &[String],
+) -> Vec<(String, String)> {
+    let (axum_path, _) = path_to_module_path(args, dir);
+
+    fragments
+        .iter()
+        .map(|name| {
+            let path = format!("{}/fragments/{name}", axum_path.trim_end_matches('/'));
+            (path, name.clone())
+        })
+        .collect()
+}
+
+// Registers every fn of a fragment.rs as a GET handler below the
+// `/fragments` path of its folder, next to the page they're part of
+fn fragment_registrations(
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+) -> Vec<(String, TokenStream)> {
+    let mut registrations = Vec::new();
+
+    for file in routes
+        .folder_files()
+        .filter(|file| file.file_name().unwrap_or_default() == "fragment.rs")
+    {
+        let dir = file.parent().unwrap();
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+        let fragments = parse::async_fns_for_file(&args.abs_norm_path().join(file));
+
+        for (path, name) in fragment_routes(args, dir, &fragments) {
+            let name = format_ident!("{}", name);
+            let builder = quote! {
+                axum::routing::get(#mod_namespace::#file_mod_path_tokens::#name)
+            };
+            let builder = route_layers(
+                builder,
+                args,
+                mod_namespace,
+                routes,
+                file,
+                &path,
+                Some(&["get"]),
+            );
+
+            let registration = config_gate(args, &path, quote! {
+                router = router.route(#path, #builder);
+            });
+            registrations.push((
+                top_level_folder(file),
+                cfg_gate(routes.folder_cfg(file), registration),
+            ));
+        }
+    }
+
+    registrations
+}
+
+/// The fns of a graphql.rs among `fns` along with their methods, the
+/// `playground` answering GET & `execute` answering POST requests
+pub fn graphql_methods(fns: &[String]) -> Vec<(&'static str, &'static str)> {
+    [("playground", "get"), ("execute", "post")]
+        .into_iter()
+        .filter(|(name, _)| fns.iter().any(|f| f == name))
+        .collect()
+}
+
+// Registers the GraphQL playground & endpoint of every graphql.rs on the path
+// of its folder
+fn graphql_registrations(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+) -> Vec<(String, TokenStream)> {
+    let mut registrations = Vec::new();
+
+    for file in routes
+        .folder_files()
+        .filter(|file| file.file_name().unwrap_or_default() == "graphql.rs")
+    {
+        let fns = parse::async_fns_for_file(&args.abs_norm_path().join(file));
+        let methods = graphql_methods(&fns);
+        if methods.is_empty() {
+            let file = route_path_display(file);
+            errors.extend(quote! {
+                compile_error!(concat!(
+                    "No GraphQL handlers found in ", #file, "\n",
+                    "Define a `pub async fn execute` answering queries and/or a ",
+                    "`pub async fn playground` serving the playground."
+                ));
+            });
+            continue;
+        }
+
+        let dir = file.parent().unwrap();
+        let (path, _) = path_to_module_path(args, dir);
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+        let method_names: Vec<&str> = methods.iter().map(|(_, method)| *method).collect();
+        let method_registrations: Vec<_> = methods
+            .into_iter()
+            .map(|(name, method)| {
+                let name = format_ident!("{}", name);
+                (
+                    None,
+                    format_ident!("{}", method),
+                    quote! { #mod_namespace::#file_mod_path_tokens::#name },
+                )
+            })
+            .collect();
+
+        let builder = method_chain(&method_registrations);
+        let builder = route_layers(
+            builder,
+            args,
+            mod_namespace,
+            routes,
+            file,
+            &path,
+            Some(&method_names),
+        );
+
+        let registration = config_gate(args, &path, quote! {
+            router = router.route(#path, #builder);
+        });
+        registrations.push((
+            top_level_folder(file),
+            cfg_gate(routes.folder_cfg(file), registration),
+        ));
+    }
+
+    registrations
+}
+
+/// The axum path of a redirect's `from` path, e.g. `/blog/:slug` for
+/// `/blog/{slug}` with axum 0.7
+pub fn redirect_path(args: &parse::FolderRouterArgs, from: &str) -> String {
+    from.split('/')
+        .map(|segment| {
+            match segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+            {
+                Some(param) => match param.strip_prefix('*') {
+                    Some(param) => catch_all_segment(args, param),
+                    None => param_segment(args, param.trim_start_matches(':')),
+                },
+                None => segment.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// Registers a handler for every redirect of the redirects.toml, filling the
+// path parameters of the old path into the new one
+fn redirect_registrations(
+    args: &parse::FolderRouterArgs,
+    routes: &parse::FolderRouterRoutes,
+) -> Vec<(String, TokenStream)> {
+    let mut registrations = Vec::new();
+
+    for redirect in routes.redirects() {
+        let path = redirect_path(args, &redirect.from);
+        let to = &redirect.to;
+        let status = match redirect.status {
+            301 => quote! { MOVED_PERMANENTLY },
+            302 => quote! { FOUND },
+            303 => quote! { SEE_OTHER },
+            307 => quote! { TEMPORARY_REDIRECT },
+            _ => quote! { PERMANENT_REDIRECT },
+        };
+
+        let handler = if redirect.from.contains('{') {
+            // Catch-all parameters keep their slashes
+            let catch_alls: Vec<&str> = redirect
+                .from
+                .split('/')
+                .filter_map(|segment| segment.strip_prefix("{*")?.strip_suffix('}'))
+                .collect();
+            let is_catch_all = if catch_alls.is_empty() {
+                quote! { false }
+            } else {
+                quote! { matches!(name, #(#catch_alls)|*) }
+            };
+            quote! {
+                |params: axum::extract::RawPathParams| async move {
+                    // The values are decoded, so they're encoded again to
+                    // keep the `Location` a valid URI
+                    let encode = |name: &str, value: &str| {
+                        use std::fmt::Write;
+
+                        let mut encoded = String::with_capacity(value.len());
+                        for byte in value.bytes() {
+                            if byte.is_ascii_alphanumeric()
+                                || b"-._~!$&'()*+,;=:@".contains(&byte)
+                                || (byte == b'/' && #is_catch_all)
+                            {
+                                encoded.push(char::from(byte));
+                            } else {
+                                write!(encoded, "%{byte:02X}").unwrap();
+                            }
+                        }
+                        encoded
+                    };
+                    let mut location = String::from(#to);
+                    for (name, value) in &params {
+                        let value = encode(name, value);
+                        location = location
+                            .replace(&format!("{{*{name}}}"), &value)
+                            .replace(&format!("{{{name}}}"), &value);
+                    }
+                    (
+                        axum::http::StatusCode::#status,
+                        [(axum::http::header::LOCATION, location)],
+                    )
+                }
+            }
+        } else {
+            quote! {
+                || async {
+                    (
+                        axum::http::StatusCode::#status,
+                        [(axum::http::header::LOCATION, #to)],
+                    )
+                }
+            }
+        };
+
+        let registration = config_gate(args, &path, quote! {
+            router = router.route(#path, axum::routing::any(#handler));
+        });
+        registrations.push((
+            String::new(),
+            cfg_gate(
+                routes.folder_cfg(Path::new(parse::REDIRECTS_FILE)),
+                registration,
+            ),
+        ));
+    }
+
+    registrations
+}
+
+fn wrap_in_layout_fn() -> TokenStream {
+    quote! {
+        async fn wrap_in_layout<F: std::future::Future<Output = String>>(
+            response: axum::response::Response,
+            layout: impl FnOnce(String) -> F,
+        ) -> axum::response::Response {
+            let is_html = response
+                .headers()
+                .get(axum::http::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("text/html"));
+            if !is_html {
+                return response;
+            }
+
+            let (mut parts, body) = response.into_parts();
+            let Ok(content) = axum::body::to_bytes(body, usize::MAX).await else {
+                return axum::response::IntoResponse::into_response(
+                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                );
+            };
+            let html = layout(String::from_utf8_lossy(&content).into_owned()).await;
+            parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+            axum::response::Response::from_parts(parts, axum::body::Body::from(html))
+        }
+    }
+}
+
+// Validates the bearer token of requests with the `auth = jwt(...)` options,
+// answering `401 Unauthorized` for missing or invalid tokens & passing on the
+// claims of valid ones as an extension
+//
+// The key is read from its env var when the router is built, panicking if
+// it's missing or invalid
+fn check_jwt_fn(auth: &parse::JwtAuth) -> TokenStream {
+    let key_env = &auth.key_env;
+    let algorithm = format_ident!("{}", auth.algorithm);
+    let claims = &auth.claims;
+    let pem_kind = match &auth.algorithm[..2] {
+        "HS" => None,
+        "RS" | "PS" => Some("rsa"),
+        "ES" => Some("ec"),
+        _ => Some("ed"),
+    };
+    let decoding_key = if let Some(pem_kind) = pem_kind {
+        let from_pem = format_ident!("from_{}_pem", pem_kind);
+        let message = format!("The {key_env} env var isn't a PEM encoded public key");
+        quote! { jsonwebtoken::DecodingKey::#from_pem(key.as_bytes()).expect(#message) }
+    } else {
+        quote! { jsonwebtoken::DecodingKey::from_secret(key.as_bytes()) }
+    };
+    let audience = if let Some(audience) = &auth.audience {
+        quote! { validation.set_audience(&[#audience]); }
+    } else {
+        quote! { validation.validate_aud = false; }
+    };
+    let issuer = auth
+        .issuer
+        .as_ref()
+        .map(|issuer| quote! { validation.set_issuer(&[#issuer]); });
+    let missing_key = format!("The {key_env} env var holding the JWT key isn't set");
+
+    quote! {
+        // Read when the router is built, so a missing or invalid key fails at
+        // startup instead of on the first request
+        static JWT_KEY: std::sync::LazyLock<jsonwebtoken::DecodingKey> =
+            std::sync::LazyLock::new(|| {
+                let key = std::env::var(#key_env).expect(#missing_key);
+                #decoding_key
+            });
+        std::sync::LazyLock::force(&JWT_KEY);
+
+        async fn check_jwt(
+            mut request: axum::extract::Request,
+            next: axum::middleware::Next,
+        ) -> axum::response::Response {
+            static VALIDATION: std::sync::LazyLock<jsonwebtoken::Validation> =
+                std::sync::LazyLock::new(|| {
+                    let mut validation =
+                        jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::#algorithm);
+                    #audience
+                    #issuer
+                    validation
+                });
+
+            let claims = request
+                .headers()
+                .get(axum::http::header::AUTHORIZATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .and_then(|token| jsonwebtoken::decode::<#claims>(token, &JWT_KEY, &VALIDATION).ok());
+            let Some(token) = claims else {
+                return axum::response::IntoResponse::into_response((
+                    axum::http::StatusCode::UNAUTHORIZED,
+                    [(axum::http::header::WWW_AUTHENTICATE, "Bearer")],
+                ));
+            };
+
+            request.extensions_mut().insert(token.claims);
+            next.run(request).await
+        }
+    }
+}
+
+// Checks the CSRF token of mutating requests by double submit, comparing the
+// `csrf_token` cookie to the `x-csrf-token` header or the `_csrf` field of URL
+// encoded forms & answering `403 Forbidden` if they differ
+//
+// Requests without a cookie holding a token in the issued format, 64 lowercase
+// hex digits, are issued a new random token in a `Secure` & `SameSite=Strict`
+// cookie, which is passed to the handlers as an `Extension<CsrfToken>` for
+// rendering it into forms
+fn check_csrf_fn() -> TokenStream {
+    quote! {
+        async fn check_csrf(
+            mut request: axum::extract::Request,
+            next: axum::middleware::Next,
+        ) -> axum::response::Response {
+            use axum::http::{header, HeaderValue, Method, StatusCode};
+
+            let cookie = request
+                .headers()
+                .get_all(header::COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(';'))
+                .find_map(|cookie| cookie.trim().strip_prefix("csrf_token="))
+                // Only tokens as issued below, so cookies planted by other
+                // subdomains or breaking out of templates are replaced
+                .filter(|token| {
+                    token.len() == 64
+                        && token.bytes().all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+                })
+                .map(ToString::to_string);
+
+            if !matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS) {
+                let mut submitted = request
+                    .headers()
+                    .get("x-csrf-token")
+                    .and_then(|value| value.to_str().ok())
+                    .map(ToString::to_string);
+                if submitted.is_none() {
+                    (request, submitted) = match form_field(request, "_csrf").await {
+                        Ok(field) => field,
+                        Err(response) => return response,
+                    };
+                }
+
+                // Compared in constant time, so the token can't be guessed bytewise
+                let valid = cookie.as_ref().zip(submitted).is_some_and(|(cookie, submitted)| {
+                    cookie.len() == submitted.len()
+                        && cookie
+                            .bytes()
+                            .zip(submitted.bytes())
+                            .fold(0, |diff, (a, b)| diff | (a ^ b))
+                            == 0
+                });
+                if !valid {
+                    return axum::response::IntoResponse::into_response(StatusCode::FORBIDDEN);
+                }
+            }
+
+            let issued = cookie.is_none();
+            let token = if let Some(cookie) = cookie {
+                cookie
+            } else {
+                // 256 bits from the CSPRNG of the OS
+                let mut bytes = [0u8; 32];
+                if getrandom::fill(&mut bytes).is_err() {
+                    return axum::response::IntoResponse::into_response(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    );
+                }
+                bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+            };
+            request.extensions_mut().insert(CsrfToken(token.clone()));
+
+            let mut response = next.run(request).await;
+            if issued {
+                let cookie = format!("csrf_token={token}; Path=/; Secure; SameSite=Strict");
+                response
+                    .headers_mut()
+                    .append(header::SET_COOKIE, HeaderValue::from_str(&cookie).unwrap());
+            }
+            response
+        }
+    }
+}
+
+// Reads the `field` of URL encoded form requests, returning the request with
+// the buffered body, for the `csrf` & `method_override` options
+//
+// Forms are buffered up to the `DefaultBodyLimit` of the request, answering
+// larger ones with `413 Payload Too Large`
+fn form_field_fn() -> TokenStream {
+    quote! {
+        async fn form_field(
+            request: axum::extract::Request,
+            field: &str,
+        ) -> Result<(axum::extract::Request, Option<String>), axum::response::Response> {
+            let is_form = request
+                .headers()
+                .get(axum::http::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+            if !is_form {
+                return Ok((request, None));
+            }
+
+            let (parts, body) = axum::RequestExt::with_limited_body(request).into_parts();
+            let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+                return Err(axum::response::IntoResponse::into_response(
+                    axum::http::StatusCode::PAYLOAD_TOO_LARGE,
+                ));
+            };
+            let value = String::from_utf8_lossy(&bytes)
+                .split('&')
+                .find_map(|pair| pair.strip_prefix(field)?.strip_prefix('='))
+                .map(ToString::to_string);
+            let request = axum::extract::Request::from_parts(parts, axum::body::Body::from(bytes));
+            Ok((request, value))
+        }
+    }
+}
+
+// Whether `rel_path` is in a `(session)` group requiring a session, with the
+// `sessions` feature
+fn in_session_group(rel_path: &Path) -> bool {
+    cfg!(feature = "sessions") && is_in_session_group(rel_path)
+}
+
+fn is_in_session_group(rel_path: &Path) -> bool {
+    rel_path
+        .components()
+        .any(|component| component.as_os_str() == "(session)")
+}
+
+// The files & public folders whose routes could be in a `(session)` group
+fn session_group_paths(routes: &parse::FolderRouterRoutes) -> impl Iterator<Item = &Path> {
+    routes
+        .into_iter()
+        .map(|(_, rel_path)| rel_path)
+        .chain(routes.folder_files())
+        .chain(routes.public_dirs().map(|(_, rel_path)| rel_path))
+}
+
+// Report `(session)` groups without the `sessions` feature, as their routes
+// would be served without requiring a session
+fn check_session_groups(errors: &mut TokenStream, routes: &parse::FolderRouterRoutes) {
+    if cfg!(feature = "sessions") {
+        return;
+    }
+
+    if let Some(rel_path) =
+        session_group_paths(routes).find(|rel_path| is_in_session_group(rel_path))
+    {
+        let message = format!(
+            "{} is in a `(session)` group, which requires the `sessions` feature of \
+             axum-folder-router",
+            route_path_display(rel_path)
+        );
+        errors.extend(quote! {
+            compile_error!(#message);
+        });
+    }
+}
+
+// Answers requests without a session of `tower-sessions` holding any data, like
+// the user id stored by `axum-login`, with `401 Unauthorized`
+fn require_session_fn() -> TokenStream {
+    quote! {
+        async fn require_session(
+            session: tower_sessions::Session,
+            request: axum::extract::Request,
+            next: axum::middleware::Next,
+        ) -> axum::response::Response {
+            if session.is_empty().await {
+                return axum::response::IntoResponse::into_response(
+                    axum::http::StatusCode::UNAUTHORIZED,
+                );
+            }
+            next.run(request).await
+        }
+    }
+}
+
+fn redirect_trailing_slash_fn() -> TokenStream {
+    quote! {
+        async fn redirect_trailing_slash(
+            axum::extract::OriginalUri(uri): axum::extract::OriginalUri,
+        ) -> axum::response::Redirect {
+            let path = uri.path().trim_end_matches('/');
+            match uri.query() {
+                Some(query) => axum::response::Redirect::permanent(&format!("{path}?{query}")),
+                None => axum::response::Redirect::permanent(path),
+            }
+        }
+    }
+}
+
+// The trailing slash variant of a route's path, unless the route already
+// matches it
+fn trailing_slash_path(axum_path: &str) -> Option<String> {
+    // Catch-all parameters like `{*path}` or `*path` already match it
+    let last_segment = axum_path.rsplit('/').next().unwrap_or_default();
+    if axum_path.ends_with('/') || last_segment.starts_with("{*") || last_segment.starts_with('*') {
+        return None;
+    }
+
+    Some(format!("{axum_path}/"))
+}
+
+// Wraps a router or service nested at the folder of `rel_path` in the guards,
+// token checks, `config.toml` guard & CORS layer of its folders, like the
+// routes next to it. API keys are validated with the state, which isn't
+// available to nested routers & services, so they can't be below an api_key.rs.
+fn nested_layers(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    rel_path: &Path,
+    axum_path: &str,
+) -> Vec<TokenStream> {
+    if let Some(file) = routes.nearest_folder_file(rel_path, "api_key.rs") {
+        let message = format!(
+            "{} can't be below {}, as API keys are validated with the state nested routers & \
+             services don't get",
+            route_path_display(rel_path),
+            route_path_display(file)
+        );
+        errors.extend(quote! { compile_error!(#message); });
+    }
+
+    let mut layers = guard_layers(args, mod_namespace, routes, rel_path);
+    layers.extend(token_layers(args, rel_path, axum_path, true));
+
+    if let Some(guard) = &routes.folder_config(rel_path).guard {
+        let guard: syn::Path = syn::parse_str(guard).unwrap();
+        layers.push(quote! { axum::middleware::from_fn(#guard) });
+    }
+
+    if let Some(file) = routes.nearest_folder_file(rel_path, "cors.rs") {
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+        layers.push(quote! { #mod_namespace::#file_mod_path_tokens::cors() });
+    }
+
+    layers
+}
+
+// A service wrapped in `layers`, as a router whose fallback is the service
+fn layered_service(service: TokenStream, layers: &[TokenStream]) -> TokenStream {
+    if layers.is_empty() {
+        return service;
+    }
+
+    quote! {
+        axum::Router::<()>::new()
+            .fallback_service(#service)
+            #(.layer(#layers))*
+    }
+}
+
+fn service_registrations(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let mut service_registrations = Vec::new();
+
+    // Serve public dirs at their own path
+    for (path, rel_path) in routes.public_dirs() {
+        let (axum_path, _) = path_to_module_path(args, rel_path);
+        let path_lit = LitStr::new(&parse::path_literal(path), proc_macro2::Span::call_site());
+        let layers = nested_layers(errors, args, mod_namespace, routes, rel_path, &axum_path);
+        let service = layered_service(
+            quote! { tower_http::services::ServeDir::new(#path_lit) },
+            &layers,
+        );
+
+        let registration = config_gate(args, &axum_path, quote! {
+            router = router.nest_service(#axum_path, #service);
+        });
+        service_registrations.push(cfg_gate(routes.folder_cfg(rel_path), registration));
+    }
+
+    // Nest service.rs services at their folder's path
+    for file in routes
+        .folder_files()
+        .filter(|file| file.file_name().unwrap_or_default() == "service.rs")
+    {
+        let (axum_path, _) = path_to_module_path(args, file.parent().unwrap());
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+        let layers = nested_layers(errors, args, mod_namespace, routes, file, &axum_path);
+        let service = layered_service(
+            quote! { #mod_namespace::#file_mod_path_tokens::service() },
+            &layers,
+        );
+
+        // axum doesn't support nesting at the root
+        let registration = if axum_path == "/" {
+            quote! { router = router.fallback_service(#service); }
+        } else {
+            quote! { router = router.nest_service(#axum_path, #service); }
+        };
+        let registration = config_gate(args, &axum_path, registration);
+        service_registrations.push(cfg_gate(routes.folder_cfg(file), registration));
+    }
+
+    // Nest router.rs routers & the folder routers named by the `Mount` type
+    // of mount.rs files at their folder's path
+    for file in routes.folder_files() {
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+        let nested_router = match file.file_name().unwrap_or_default().to_str() {
+            Some("router.rs") => quote! { #mod_namespace::#file_mod_path_tokens::router() },
+            Some("mount.rs") => {
+                quote! { <#mod_namespace::#file_mod_path_tokens::Mount>::into_router() }
+            }
+            _ => continue,
+        };
+        let (axum_path, _) = path_to_module_path(args, file.parent().unwrap());
+        let layers = nested_layers(errors, args, mod_namespace, routes, file, &axum_path);
+        let nested_router = quote! { #nested_router #(.layer(#layers))* };
+
+        // axum doesn't support nesting at the root
+        let registration = if axum_path == "/" {
+            quote! { router = router.merge(#nested_router); }
+        } else {
+            quote! { router = router.nest(#axum_path, #nested_router); }
+        };
+        let registration = config_gate(args, &axum_path, registration);
+        service_registrations.push(cfg_gate(routes.folder_cfg(file), registration));
+    }
+
+    TokenStream::from_iter(service_registrations)
+}
+
+// Escape text for use in XML
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+// The liveness route of the `health` option answering `200 OK` & the readiness
+// route below it, using the `ready` fn of the root health.rs if there's one
+fn health_registration(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let health_file = routes
+        .folder_files()
+        .find(|file| *file == Path::new("health.rs"));
+    let Some(health) = &args.health else {
+        if health_file.is_some() {
+            errors.extend(quote! {
+                compile_error!(
+                    "health.rs is only used with the `health` option, e.g. `health = \"/healthz\"`"
+                );
+            });
+        }
+        return TokenStream::new();
+    };
+
+    let ready_path = format!("{health}/ready");
+    let ready = if let Some(file) = health_file {
+        let file_mod_path_tokens = generate_mod_path_tokens(&folder_file_module_path(file));
+        quote! { #mod_namespace::#file_mod_path_tokens::ready }
+    } else {
+        quote! { || async { "OK" } }
+    };
+
+    quote! {
+        router = router.route(#health, axum::routing::get(|| async { "OK" }));
+        router = router.route(#ready_path, axum::routing::get(#ready));
+    }
+}
+
+// A `/sitemap.xml` route listing all GET routes without path parameters,
+// unless they're below one of the `sitemap_exclude` prefixes
+fn sitemap_registration(
+    args: &parse::FolderRouterArgs,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let Some(base_url) = &args.sitemap else {
+        return TokenStream::new();
+    };
+
+    let mut urls = Vec::new();
+    for entry in table::route_table(args, &args.abs_norm_path(), routes) {
+        let is_page = matches!(
+            entry.kind,
+            table::RouteKind::Route | table::RouteKind::Resource | table::RouteKind::Page
+        ) && entry
+            .methods
+            .as_ref()
+            .is_some_and(|methods| methods.contains(&"get"));
+        let is_static = !entry.path.contains(['{', ':', '*']);
+        let is_excluded = args
+            .sitemap_exclude
+            .iter()
+            .any(|prefix| path_has_prefix(&entry.path, prefix));
+        if !is_page || !is_static || is_excluded {
+            continue;
+        }
+
+        let url = format!(
+            "  <url><loc>{}</loc></url>\n",
+            xml_escape(&format!("{base_url}{}", entry.path))
+        );
+        // Environment specific & feature gated routes are only listed in their
+        // builds
+        if let Some(cfg) = routes.entry_cfg(&entry) {
+            urls.push(quote! { #[cfg(#cfg)] { sitemap.push_str(#url); } });
+        } else {
+            urls.push(quote! { sitemap.push_str(#url); });
+        }
+    }
+
+    quote! {
+        async fn sitemap() -> impl axum::response::IntoResponse {
+            let mut sitemap = String::from(concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+            ));
+            #(#urls)*
+            sitemap.push_str("</urlset>\n");
+
+            ([(axum::http::header::CONTENT_TYPE, "application/xml")], sitemap)
+        }
+        router = router.route("/sitemap.xml", axum::routing::get(sitemap));
+    }
+}
+
+// `HOST` & `into_host_router()`, dispatching requests for the `host` option's
+// host to the folder router & all others to a fallback router
+fn host_router_fn(
+    args: &parse::FolderRouterArgs,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let Some(host) = &args.host else {
+        return TokenStream::new();
+    };
+    let state_type = &args.state_type;
+
+    let host_expr = request_host();
+
+    // Subdomains of the host are served too, if there are subdomain folders.
+    // Like `from_host`, only a single label is a subdomain
+    let subdomain_check = routes
+        .into_iter()
+        .any(|(_, rel_path)| host_param(rel_path).is_some())
+        .then(|| {
+            let suffix = format!(".{host}");
+            let compared = if host.contains(':') {
+                quote! { host }
+            } else {
+                quote! { host_name }
+            };
+            quote! {
+                || #compared
+                    .strip_suffix(#suffix)
+                    .is_some_and(|subdomain| !subdomain.is_empty() && !subdomain.contains('.'))
+            }
+        });
+
+    quote! {
+        pub const HOST: &'static str = #host;
+
+        pub fn into_host_router(state: #state_type, fallback: axum::Router) -> axum::Router {
+            let router = Self::into_router_with_state(state);
+
+            axum::Router::new().fallback(move |request: axum::extract::Request| {
+                #host_expr
+                // The port is only compared if HOST has one
+                let host_name = host.split(':').next().unwrap_or_default();
+                let is_host = host == Self::HOST || host_name == Self::HOST #subdomain_check;
+                let service = if is_host { router.clone() } else { fallback.clone() };
+
+                tower::ServiceExt::oneshot(service, request)
+            })
+        }
+    }
+}
+
+// The extension types of all subdomain folders, parsing the subdomain of a
+// Host header relative to the `host` option's host, if given
+fn host_param_types(
+    args: &parse::FolderRouterArgs,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let mut params: Vec<String> = routes
+        .into_iter()
+        .map(|(_, rel_path)| rel_path)
+        .chain(routes.folder_files())
+        .filter_map(host_param)
+        .collect();
+    params.sort();
+    params.dedup();
+
+    // Only a single label is a subdomain, e.g. `acme` for `acme.example.com`
+    // but none for `eu.acme.example.com`
+    let subdomain = if let Some(host) = &args.host {
+        let host_name = host.split(':').next().unwrap_or_default();
+        let suffix = format!(".{host_name}");
+        quote! {
+            host.strip_suffix(#suffix)
+                .filter(|subdomain| !subdomain.is_empty() && !subdomain.contains('.'))
+        }
+    } else {
+        // e.g. `acme` for `acme.example.com` or `acme.localhost`
+        quote! {
+            match host.split('.').collect::<Vec<_>>().as_slice() {
+                [subdomain, "localhost"] | [subdomain, _, _] => Some(*subdomain),
+                _ => None,
+            }
+        }
+    };
+
+    params
+        .iter()
+        .map(|param| {
+            let param_type = host_param_type(param);
+            let doc =
+                format!("The `{param}` subdomain of a request, from its `[{param}]!host` folder");
+            quote! {
+                #[doc = #doc]
+                #[derive(Clone, Debug, PartialEq, Eq)]
+                pub struct #param_type(pub String);
+
+                impl #param_type {
+                    fn from_host(host: &str) -> Option<Self> {
+                        let host = host.split(':').next().unwrap_or_default();
+                        let subdomain: Option<&str> = #subdomain;
+                        subdomain.map(|subdomain| Self(subdomain.to_string()))
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+// Nests the router below every locale prefix of the `locales` option, with a
+// `Locale` extension, & redirects other paths based on `Accept-Language`
+fn localized_router(args: &parse::FolderRouterArgs) -> TokenStream {
+    if args.locales.is_empty() {
+        return TokenStream::new();
+    }
+    let locales = &args.locales;
+    let prefixes = locales.iter().map(|locale| format!("/{locale}"));
+
+    quote! {
+        async fn redirect_to_locale(
+            axum::extract::OriginalUri(uri): axum::extract::OriginalUri,
+            headers: axum::http::HeaderMap,
+        ) -> axum::response::Response {
+            const LOCALES: &[&str] = &[#(#locales),*];
+
+            // Unknown paths of a locale stay unknown
+            let path = uri.path().trim_end_matches('/');
+            let first_segment = path.trim_start_matches('/').split('/').next().unwrap_or_default();
+            if LOCALES.contains(&first_segment) {
+                return axum::response::IntoResponse::into_response(
+                    axum::http::StatusCode::NOT_FOUND,
+                );
+            }
+
+            // Accepted languages ordered by their quality, e.g. `de-DE,en;q=0.8`
+            let accept_language = headers
+                .get(axum::http::header::ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default();
+            let mut languages: Vec<(&str, f32)> = accept_language
+                .split(',')
+                .map(|language| {
+                    let mut parts = language.split(';');
+                    let tag = parts.next().unwrap_or_default().trim();
+                    let quality = parts
+                        .find_map(|param| param.trim().strip_prefix("q="))
+                        .and_then(|quality| quality.parse().ok())
+                        .unwrap_or(1.0);
+                    (tag, quality)
+                })
+                .collect();
+            languages.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+            // Exact matches first, then primary language matches like `de` for `de-DE`
+            let locale = languages
+                .iter()
+                .find_map(|(tag, _)| {
+                    LOCALES
+                        .iter()
+                        .find(|locale| tag.eq_ignore_ascii_case(locale))
+                        .or_else(|| {
+                            let primary = tag.split('-').next().unwrap_or_default();
+                            LOCALES.iter().find(|locale| primary.eq_ignore_ascii_case(locale))
+                        })
+                })
+                .unwrap_or(&LOCALES[0]);
+
+            let location = match uri.query() {
+                Some(query) => format!("/{locale}{path}?{query}"),
+                None => format!("/{locale}{path}"),
+            };
+            let mut response = axum::response::IntoResponse::into_response(
+                axum::response::Redirect::temporary(&location),
+            );
+            response.headers_mut().insert(
+                axum::http::header::VARY,
+                axum::http::HeaderValue::from_static("accept-language"),
+            );
+            response
+        }
+
+        let localized = router;
+        let mut router = axum::Router::new();
+        #(
+            router = router.nest(
+                #prefixes,
+                localized.clone().layer(axum::middleware::from_fn(
+                    |mut request: axum::extract::Request, next: axum::middleware::Next| async move {
+                        request.extensions_mut().insert(Locale(#locales));
+                        next.run(request).await
+                    },
+                )),
+            );
+        )*
+        router = router.fallback(redirect_to_locale);
+    }
+}
+
+// The `Locale` extension type of the `locales` option
+fn locale_type(args: &parse::FolderRouterArgs) -> TokenStream {
+    if args.locales.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        /// The locale prefix of a request, e.g. `en` for `/en/users`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct Locale(pub &'static str);
+    }
+}
+
+// The `CsrfToken` extension type of the `csrf` option
+fn csrf_token_type(args: &parse::FolderRouterArgs) -> TokenStream {
+    if args.csrf.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        /// The CSRF token of a request, to be submitted as the `_csrf` field
+        /// of forms or in the `x-csrf-token` header
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct CsrfToken(pub String);
+    }
+}
+
+// The statements building the `Router` of all routes, with the route modules
+// at `mod_namespace`
+//
+// Nested functions can't use the generic parameters of generic structs, so
+// their registrations aren't `chunked` into functions
+fn router_body(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    chunked: bool,
+) -> TokenStream {
+    let main_routes = routes.without_subtrees(&args.abs_norm_path());
+    let registrations = route_registrations(errors, args, mod_namespace, &main_routes, chunked);
+    let subtrees = subtree_registrations(errors, args, mod_namespace, routes, chunked);
+    let services = service_registrations(errors, args, mod_namespace, &main_routes);
+    let sitemap = sitemap_registration(args, routes);
+    let health = health_registration(errors, args, mod_namespace, routes);
+    let localized_router = localized_router(args);
+    let router_layers = router_layers(args);
+
+    quote! {
+        let mut router = axum::Router::new();
+        #registrations
+        #subtrees
+        #sitemap
+        #health
+        #services
+        #localized_router
+        #router_layers
+        router
+    }
+}
+
+// The routers of the subtrees with a `state.rs`, built with the state type
+// it declares & provided with the state of its `state` fn before they're
+// merged
+fn subtree_registrations(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+    chunked: bool,
+) -> TokenStream {
+    let state_dirs = routes.state_dirs();
+    let mut registrations = TokenStream::new();
+
+    for dir in &state_dirs {
+        let file = dir.join("state.rs");
+        let display = route_path_display(&file);
+        let nested = state_dirs
+            .iter()
+            .any(|other| other != dir && dir.starts_with(other));
+        let message = if dir.as_os_str().is_empty() {
+            Some(format!(
+                "{display} can't be in the route root, the macro's state is used there"
+            ))
+        } else if nested {
+            Some(format!(
+                "{display} can't be nested in another state.rs subtree"
+            ))
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            errors.extend(quote! { compile_error!(#message); });
+            continue;
+        }
+        let Some(state_type) = parse::subtree_state_type(&args.abs_norm_path().join(&file)) else {
+            let message = format!(
+                "{display} has to declare its state type like `pub type State = AdminState;`"
+            );
+            errors.extend(quote! { compile_error!(#message); });
+            continue;
+        };
+        // Subtrees deriving their state from the macro's state stay in its
+        // router, see `subtree_state_glue`
+        if parse::subtree_state_source(&args.abs_norm_path().join(&file)) != parse::StateSource::Fn
+        {
+            continue;
+        }
+
+        let state_mod = generate_mod_path_tokens(&folder_file_module_path(&file));
+        let subtree_args = parse::FolderRouterArgs {
+            state_type: state_type.clone(),
+            ..args.clone()
+        };
+        let subtree_routes = routes.subtree(dir);
+        let subtree_registrations = route_registrations(
+            errors,
+            &subtree_args,
+            mod_namespace,
+            &subtree_routes,
+            chunked,
+        );
+        let services = service_registrations(errors, &subtree_args, mod_namespace, &subtree_routes);
+
+        let registration = quote! {
+            router = router.merge({
+                type #state_type = #mod_namespace::#state_mod::State;
+
+                let mut router = axum::Router::<#state_type>::new();
+                #subtree_registrations
+                #services
+                router.with_state(#mod_namespace::#state_mod::state())
+            });
+        };
+        registrations.extend(cfg_gate(routes.folder_cfg(&file), registration));
+    }
+
+    registrations
+}
+
+// Layers of the options applying to the whole router
+fn router_layers(args: &parse::FolderRouterArgs) -> TokenStream {
+    let mut layers = TokenStream::new();
+
+    // Responses carry the id of their request, which is generated unless the
+    // request has one
+    if let Some(header) = &args.request_id {
+        layers.extend(quote! {
+            let request_id_header = axum::http::HeaderName::from_static(#header);
+            router = router
+                .layer(tower_http::request_id::PropagateRequestIdLayer::new(
+                    request_id_header.clone(),
+                ))
+                .layer(tower_http::request_id::SetRequestIdLayer::new(
+                    request_id_header,
+                    tower_http::request_id::MakeRequestUuid,
+                ));
+        });
+    }
+
+    // The injected script reloads the page once the server is back, e.g.
+    // after a rebuild for a changed route tree
+    if args.livereload {
+        layers.extend(quote! {
+            #[cfg(debug_assertions)]
+            {
+                router = router.layer(tower_livereload::LiveReloadLayer::new());
+            }
+        });
+    }
+
+    layers
+}
+
+// The router built by the `rebuild` expression & provided with the `state`,
+// rebuilt in debug builds whenever a hot patch is applied, see the
+// `hot_reload` option
+//
+// `subsecond::call` runs the patched code of the closure, so the new router
+// refers to the patched handlers as well. It's only rebuilt per patch, so
+// layers like concurrency & rate limits keep their state between requests.
+fn hot_reloaded_router(rebuild: &TokenStream) -> TokenStream {
+    quote! {{
+        #[cfg(debug_assertions)]
+        let router = {
+            let build = move || subsecond::call(|| #rebuild).with_state(state.clone());
+            let current = std::sync::Arc::new(std::sync::RwLock::new(build()));
+            subsecond::register_handler(std::sync::Arc::new({
+                let current = current.clone();
+                move || {
+                    let router = build();
+                    *current.write().unwrap() = router;
+                }
+            }));
+
+            axum::Router::new().fallback(move |request: axum::extract::Request| {
+                let router = current.read().unwrap().clone();
+                tower::ServiceExt::oneshot(router, request)
+            })
+        };
+        #[cfg(not(debug_assertions))]
+        let router = #rebuild.with_state(state);
+        router
+    }}
+}
+
+// `router` wrapped for the options rewriting requests before they're routed
+fn rewriting_router(args: &parse::FolderRouterArgs, router: &TokenStream) -> TokenStream {
+    method_override_router(args, &normalized_router(args, router))
+}
+
+// `router` wrapped for the `method_override` option, changing the method of
+// `POST` requests to the `PUT`, `PATCH` or `DELETE` of their
+// `X-HTTP-Method-Override` header or `_method` field of URL encoded forms
+fn method_override_router(args: &parse::FolderRouterArgs, router: &TokenStream) -> TokenStream {
+    if !args.method_override {
+        return router.clone();
+    }
+
+    let form_field_fn = form_field_fn();
+    quote! {
+        #form_field_fn
+
+        let router = { #router };
+
+        axum::Router::new().fallback(move |mut request: axum::extract::Request| {
+            let router = router.clone();
+            async move {
+                use axum::http::Method;
+
+                if request.method() == Method::POST {
+                    let mut method = request
+                        .headers()
+                        .get("x-http-method-override")
+                        .and_then(|value| value.to_str().ok())
+                        .map(ToString::to_string);
+                    if method.is_none() {
+                        (request, method) = match form_field(request, "_method").await {
+                            Ok(field) => field,
+                            Err(response) => return response,
+                        };
+                    }
+
+                    let method = method
+                        .and_then(|method| method.to_ascii_uppercase().parse::<Method>().ok())
+                        .filter(|method| matches!(*method, Method::PUT | Method::PATCH | Method::DELETE));
+                    if let Some(method) = method {
+                        *request.method_mut() = method;
+                    }
+                }
+
+                axum::response::IntoResponse::into_response(
+                    tower::ServiceExt::oneshot(router, request).await,
+                )
+            }
+        })
+    }
+}
+
+// `router` wrapped for the `normalize_path` option, removing duplicate &
+// trailing slashes from request paths before they're routed
+//
+// Layers of a `Router` only run after a route was matched, so the router is
+// served as the fallback of an outer router rewriting the URI instead
+fn normalized_router(args: &parse::FolderRouterArgs, router: &TokenStream) -> TokenStream {
+    if !args.normalize_path {
+        return router.clone();
+    }
+
+    quote! {
+        let router = #router;
+
+        axum::Router::new().fallback(move |mut request: axum::extract::Request| {
+            let path = request.uri().path();
+            let mut normalized = String::with_capacity(path.len());
+            for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+                normalized.push('/');
+                normalized.push_str(segment);
+            }
+            if normalized.is_empty() {
+                normalized.push('/');
+            }
+
+            if normalized != path {
+                if let Some(query) = request.uri().query() {
+                    normalized = format!("{normalized}?{query}");
+                }
+                let mut parts = request.uri().clone().into_parts();
+                parts.path_and_query = normalized.parse().ok();
+                if let Ok(uri) = axum::http::Uri::from_parts(parts) {
+                    *request.uri_mut() = uri;
+                }
+            }
+
+            tower::ServiceExt::oneshot(router.clone(), request)
+        })
+    }
+}
+
+// The `FolderRouterConfig` of the `config` option
+fn config_type(args: &parse::FolderRouterArgs) -> TokenStream {
+    if !args.config {
+        return TokenStream::new();
+    }
+
+    quote! {
+        /// Route subtrees to leave out of the router at startup
+        #[derive(Clone, Debug, Default)]
+        pub struct FolderRouterConfig {
+            disabled: Vec<String>,
+        }
+
+        impl FolderRouterConfig {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Disables all routes at or below `prefix`, e.g. `/admin`
+            pub fn disable(mut self, prefix: impl Into<String>) -> Self {
+                self.disabled.push(prefix.into());
+                self
+            }
+
+            pub fn is_enabled(&self, path: &str) -> bool {
+                !self.disabled.iter().any(|prefix| {
+                    let prefix = prefix.trim_end_matches('/');
+                    prefix.is_empty()
+                        || path == prefix
+                        || path
+                            .strip_prefix(prefix)
+                            .is_some_and(|rest| rest.starts_with('/'))
+                })
+            }
+        }
+    }
+}
+
+pub fn router_impl(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    item: &parse::FolderRouterItem,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let struct_name = item.struct_name();
+    let state_type = args.state_type.clone();
+    let chunked = item.generics().params.is_empty();
+    let mut build_router = router_body(errors, args, &item.module_namespace(), routes, chunked);
+    let host_router = host_router_fn(args, routes);
+    let host_param_types = host_param_types(args, routes);
+    let locale_type = locale_type(args);
+    let csrf_token_type = csrf_token_type(args);
+    let config_type = config_type(args);
+
+    let config_fn = args.config.then(|| {
+        let config_fn = quote! {
+            pub fn into_router_with_config(config: FolderRouterConfig) -> axum::Router<#state_type> {
+                #build_router
+            }
+        };
+        build_router = quote! { Self::into_router_with_config(FolderRouterConfig::default()) };
+        config_fn
+    });
+    // Structs with fields are converted into the state
+    let into_router = if item.has_fields() {
+        let body = if args.hot_reload {
+            let rebuild = quote! {{
+                let router: axum::Router<#state_type> = { #build_router };
+                router
+            }};
+            rewriting_router(args, &hot_reloaded_router(&rebuild))
+        } else {
+            let with_state = rewriting_router(args, &quote! { router.with_state(state) });
+            quote! {
+                let router: axum::Router<#state_type> = { #build_router };
+                #with_state
+            }
+        };
+        quote! {
+            pub fn into_router(self) -> axum::Router {
+                Self::into_router_with_state(self.into())
+            }
+
+            pub fn into_router_with_state(state: #state_type) -> axum::Router {
+                #body
+            }
+        }
+    } else {
+        let with_state = if args.hot_reload {
+            hot_reloaded_router(&quote! { Self::into_router() })
+        } else {
+            quote! { Self::into_router().with_state(state) }
+        };
+        let with_state = rewriting_router(args, &with_state);
+        quote! {
+            pub fn into_router() -> axum::Router<#state_type> {
+                #build_router
+            }
+
+            pub fn into_router_with_state(state: #state_type) -> axum::Router {
+                #with_state
+            }
+        }
+    };
+
+    let fmt_impls = fmt_impls(args, item, routes);
+    let route_table = route_table_const(args, routes);
+
+    // Generic structs require the bounds of `Router<S>` from their state
+    let mut generics = item.generics().clone();
+    if !generics.params.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#state_type: Clone + Send + Sync + 'static));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #host_param_types
+        #locale_type
+        #csrf_token_type
+        #config_type
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// The `(method, path, file)` of every route, checked for
+            /// conflicts by `merge_all!`
+            pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = #route_table;
+
+            #into_router
+            #config_fn
+
+            #host_router
+        }
+
+        #fmt_impls
+    }
+}
+
+/// Merges the routers of folder router structs, failing to compile if two of
+/// them register the same method at the same path
+pub fn merge_all(routers: &[syn::Type]) -> TokenStream {
+    let Some((first, rest)) = routers.split_first() else {
+        return quote! {
+            compile_error!("merge_all! expects at least one folder router, e.g. `merge_all!(ApiRouter, AdminRouter)`")
+        };
+    };
+
+    // One const per pair of routers, named after them as the compiler shows
+    // the name of the failing const & the panic message holds the path
+    let mut checks = Vec::new();
+    for (i, a) in routers.iter().enumerate() {
+        for b in &routers[i + 1..] {
+            let name = format!("{}_conflicts_with_{}", type_name(a), type_name(b));
+            let check = format_ident!("{}", name);
+            checks.push(quote! {
+                #[allow(non_upper_case_globals)]
+                const #check: () = if let Some(path) = conflict(<#a>::ROUTES, <#b>::ROUTES) {
+                    panic!("{}", path)
+                };
+                #check;
+            });
+        }
+    }
+
+    quote! {
+        {
+            const _: () = {
+                // Parameters are segments like `{id}`, `{*path}`, `:id` or `*path`
+                const fn is_param(path: &[u8], i: usize) -> bool {
+                    i > 0 && path[i - 1] == b'/' && matches!(path[i], b'{' | b':' | b'*')
+                }
+
+                // The index after the parameter starting at `i`
+                const fn param_end(path: &[u8], mut i: usize) -> usize {
+                    let braced = path[i] == b'{';
+                    while i < path.len() && path[i] != if braced { b'}' } else { b'/' } {
+                        i += 1;
+                    }
+                    if braced && i < path.len() { i + 1 } else { i }
+                }
+
+                // Paths are the same if they only differ in parameter names,
+                // as axum can't tell them apart either
+                const fn same_path(a: &str, b: &str) -> bool {
+                    let (a, b) = (a.as_bytes(), b.as_bytes());
+                    let (mut i, mut j) = (0, 0);
+                    while i < a.len() && j < b.len() {
+                        if is_param(a, i) && is_param(b, j) {
+                            i = param_end(a, i);
+                            j = param_end(b, j);
+                        } else if a[i] != b[j] {
+                            return false;
+                        } else {
+                            i += 1;
+                            j += 1;
+                        }
+                    }
+                    i == a.len() && j == b.len()
+                }
+
+                const fn same_str(a: &str, b: &str) -> bool {
+                    let (a, b) = (a.as_bytes(), b.as_bytes());
+                    if a.len() != b.len() {
+                        return false;
+                    }
+                    let mut i = 0;
+                    while i < a.len() {
+                        if a[i] != b[i] {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+
+                // The first path both route tables register the same method
+                // at, with `*` matching any method
+                const fn conflict(
+                    a: &'static [(&'static str, &'static str, &'static str)],
+                    b: &'static [(&'static str, &'static str, &'static str)],
+                ) -> Option<&'static str> {
+                    let mut i = 0;
+                    while i < a.len() {
+                        let mut j = 0;
+                        while j < b.len() {
+                            let ((method_a, path_a, _), (method_b, path_b, _)) = (a[i], b[j]);
+                            let same_method = same_str(method_a, method_b)
+                                || same_str(method_a, "*")
+                                || same_str(method_b, "*");
+                            if same_method && same_path(path_a, path_b) {
+                                return Some(path_a);
+                            }
+                            j += 1;
+                        }
+                        i += 1;
+                    }
+                    None
+                }
+
+                #(#checks)*
+            };
+
+            <#first>::into_router()#(.merge(<#rest>::into_router()))*
+        }
+    }
+}
+
+// A type as identifier part, e.g. `Lib_App` for `Lib<App>`
+fn type_name(ty: &syn::Type) -> String {
+    let name = quote! { #ty }
+        .to_string()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+// `Display` & `Debug` impls listing the registered routes, unless `Debug` is
+// derived
+fn fmt_impls(
+    args: &parse::FolderRouterArgs,
+    item: &parse::FolderRouterItem,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let struct_name = item.struct_name();
+    let (impl_generics, ty_generics, where_clause) = item.generics().split_for_impl();
+    let entries = table::route_table(args, &args.abs_norm_path(), routes);
+    let rendered = table::render_routes(&entries);
+    let lines = table::route_lines(&entries);
+
+    let debug_impl = (!item.derives("Debug")).then(|| {
+        quote! {
+            impl #impl_generics ::core::fmt::Debug for #struct_name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let routes: &[&str] = &[#(#lines),*];
+                    f.debug_list().entries(routes).finish()
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::core::fmt::Display for #struct_name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(#rendered)
+            }
+        }
+
+        #debug_impl
+    }
+}
+
+// A request path matching `axum_path`, with sample values for the path
+// parameters of the route at `rel_path`, unless they're regex constrained
+fn sample_path(
+    routes: &parse::FolderRouterRoutes,
+    axum_path: &str,
+    rel_path: &Path,
+) -> Option<String> {
+    let params = route_params(rel_path);
+    let mut path = Vec::new();
+
+    for segment in axum_path.split('/') {
+        let name = segment.trim_matches(['{', '}', ':', '*']);
+        if name == segment {
+            path.push(segment.to_string());
+            continue;
+        }
+
+        let param = params.iter().find(|param| param.name == name);
+        if param.is_some_and(|param| routes.param_regex(&param.dir).is_some()) {
+            return None;
+        }
+        let ty = param
+            .and_then(|param| param.ty.as_deref())
+            .unwrap_or_default();
+        let value = match ty.rsplit("::").next().unwrap_or_default() {
+            "bool" => "true",
+            "char" => "a",
+            "Uuid" => "00000000-0000-0000-0000-000000000000",
+            _ => "1",
+        };
+        path.push(value.to_string());
+    }
+
+    Some(path.join("/"))
+}
+
+/// Asserts the `State<T>` arguments of all handlers can be extracted from the
+/// state type, so a mismatch is reported naming the file & handler before
+/// axum's `Handler` trait errors
+pub fn state_checks(
+    args: &parse::FolderRouterArgs,
+    item: &parse::FolderRouterItem,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    // The state of generic structs is only known to their users
+    if !item.generics().params.is_empty() {
+        return TokenStream::new();
+    }
+
+    let mod_namespace = item.module_namespace();
+    let state_dirs = routes.routed_state_dirs(&args.abs_norm_path());
+    let mut checks = Vec::new();
+
+    for (route_path, rel_path) in routes {
+        let handlers = handlers_for_route(route_path);
+        let mod_path_tokens = generate_mod_path_tokens(&path_to_module_path(args, rel_path).1);
+        let file = route_path_display(rel_path);
+
+        // Routes of a state.rs subtree are checked against its state
+        let subtree_state = state_dirs
+            .iter()
+            .find(|dir| !dir.as_os_str().is_empty() && rel_path.starts_with(dir))
+            .map(|dir| dir.join("state.rs"))
+            .and_then(|file| {
+                let state_type = parse::subtree_state_type(&args.abs_norm_path().join(&file))?;
+                let state_mod = generate_mod_path_tokens(&folder_file_module_path(&file));
+                Some((state_type, state_mod))
+            });
+        let state_alias = subtree_state.as_ref().map(|(state_type, state_mod)| {
+            quote! { type #state_type = #mod_namespace::#state_mod::State; }
+        });
+        let state_type = subtree_state
+            .as_ref()
+            .map_or(&args.state_type, |(state_type, _)| state_type);
+
+        for (method, state) in &handlers.state_types {
+            // Every state can be extracted as itself
+            if *state_type == state.ty {
+                continue;
+            }
+
+            let message = format!(
+                "`{method}` in {file} extracts `State<{{Self}}>`, which can't be extracted from \
+                 `{state_type}`"
+            );
+            let label = format!(
+                "implement `FromRef<{state_type}>` for `{{Self}}` or extract `State<{state_type}>`"
+            );
+            let check_trait = format_ident!("__ExtractState{}", checks.len());
+            let method_ident = format_ident!("{}", method);
+
+            // The handler as a fn pointer, inferring all types but the state
+            let params: Vec<_> = (0..state.arity)
+                .filter(|i| *i != state.index)
+                .map(|i| format_ident!("__A{}", i))
+                .collect();
+            let fn_args = (0..state.arity).map(|i| {
+                if i == state.index {
+                    quote! { axum::extract::State<__T> }
+                } else {
+                    format_ident!("__A{}", i).to_token_stream()
+                }
+            });
+
+            let check = quote! {
+                {
+                    #state_alias
+                    #[diagnostic::on_unimplemented(message = #message, label = #label)]
+                    trait #check_trait {}
+                    impl<__T: axum::extract::FromRef<#state_type>> #check_trait for __T {}
+                    fn check<#(#params,)* __T: #check_trait, __R>(_: fn(#(#fn_args),*) -> __R) {}
+                    check(#mod_namespace::#mod_path_tokens::#method_ident);
+                }
+            };
+            let cfg = cfg_predicate(
+                handlers
+                    .attrs
+                    .get(method)
+                    .unwrap_or(&parse::HandlerAttrs::default()),
+            );
+            checks.push(cfg_gate(routes.route_cfg(rel_path), cfg_gate(cfg, check)));
+        }
+    }
+
+    if checks.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            fn __check_states() {
+                #(#checks)*
+            }
+        };
+    }
+}
+
+/// The `FromRef` impls of the `state.rs` files with a `from_ref` fn & checks
+/// that the states of those without any fn implement `FromRef` of the
+/// macro's state
+pub fn subtree_state_glue(
+    args: &parse::FolderRouterArgs,
+    item: &parse::FolderRouterItem,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let mod_namespace = item.module_namespace();
+    let macro_state = &args.state_type;
+    let generic = !item.generics().params.is_empty();
+    let mut glue = TokenStream::new();
+
+    for dir in routes.state_dirs() {
+        let file = dir.join("state.rs");
+        let abs_file = args.abs_norm_path().join(&file);
+        // Misplaced state.rs files are reported by `subtree_registrations`
+        let Some(state_type) = parse::subtree_state_type(&abs_file) else {
+            continue;
+        };
+        let state_mod = generate_mod_path_tokens(&folder_file_module_path(&file));
+        let display = route_path_display(&file);
+
+        let tokens = match parse::subtree_state_source(&abs_file) {
+            parse::StateSource::Fn => continue,
+            // The state of generic structs is only known to their users
+            parse::StateSource::FromRef if generic => {
+                let message = format!(
+                    "the `from_ref` fn of {display} needs a concrete state, implement \
+                     `FromRef<{macro_state}>` for `{state_type}` instead"
+                );
+                quote! { compile_error!(#message); }
+            }
+            parse::StateSource::Existing if generic => continue,
+            parse::StateSource::FromRef => quote! {
+                impl axum::extract::FromRef<#macro_state> for #mod_namespace::#state_mod::State {
+                    fn from_ref(state: &#macro_state) -> Self {
+                        #mod_namespace::#state_mod::from_ref(state)
+                    }
+                }
+            },
+            parse::StateSource::Existing => {
+                let message =
+                    format!("`{state_type}` of {display} can't be extracted from `{macro_state}`");
+                let label = format!(
+                    "implement `FromRef<{macro_state}>` for `{state_type}` or add a `from_ref` fn \
+                     to {display}"
+                );
+                quote! {
+                    #[diagnostic::on_unimplemented(message = #message, label = #label)]
+                    trait __SubtreeState {}
+                    impl<__T: axum::extract::FromRef<#macro_state>> __SubtreeState for __T {}
+                    const fn check<__T: __SubtreeState>() {}
+                    check::<#mod_namespace::#state_mod::State>();
+                }
+            }
+        };
+        let cfg = routes.folder_cfg(&file).map(|cfg| quote! { #[cfg(#cfg)] });
+        glue.extend(quote! {
+            #cfg
+            const _: () = {
+                #tokens
+            };
+        });
+    }
+
+    glue
+}
+
+// A `#[cfg(test)]` module checking that every route responds to its methods
+// with something other than `404` or `405`
+pub fn route_tests(
+    args: &parse::FolderRouterArgs,
+    item: &parse::FolderRouterItem,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    // The state of generic structs is only known to their users
+    if !cfg!(feature = "test-helpers") || !item.generics().params.is_empty() {
+        return TokenStream::new();
+    }
+
+    let struct_name = item.struct_name();
+    let state_type = &args.state_type;
+    let state = if let Some(factory) = &args.test_state {
+        quote! { #factory() }
+    } else {
+        quote! { <#state_type as ::core::default::Default>::default() }
+    };
+    let tests_mod = format_ident!(
+        "__folder_router_tests__{}",
+        struct_name.to_string().to_lowercase()
+    );
+
+    let mut tests = Vec::new();
+    let mut test_names = BTreeSet::new();
+    let base_dir = args.abs_norm_path();
+    for entry in table::route_table(args, &base_dir, routes) {
+        let Some(methods) = &entry.methods else {
+            continue;
+        };
+        if methods.is_empty() || entry.kind == table::RouteKind::Public {
+            continue;
+        }
+        let Some(path) = sample_path(routes, &entry.path, &entry.rel_path) else {
+            continue;
+        };
+
+        // Routes of subdomain folders need a subdomain
+        let host = match (host_param(&entry.rel_path), &args.host) {
+            (Some(_), Some(host)) => format!("test.{host}"),
+            (Some(_), None) => "test.localhost".to_string(),
+            (None, host) => host.clone().unwrap_or_else(|| "localhost".to_string()),
+        };
+
+        // Conditional handlers are only checked in their builds
+        let handlers = match entry.kind {
+            table::RouteKind::Route => handlers_for_route(&base_dir.join(&entry.rel_path)),
+            _ => Arc::default(),
+        };
+        let mut checked = BTreeSet::new();
+        let checks = methods.iter().filter_map(|method| {
+            // `any` handlers accept every method, so a GET will do
+            let method_name = match *method {
+                "any" => "GET".to_string(),
+                method => method.to_uppercase(),
+            };
+            if !checked.insert(method_name.clone()) {
+                return None;
+            }
+            let check = quote! { assert_routed(#method_name, #path, #host).await; };
+            Some(match handlers.attrs.get(method).and_then(cfg_predicate) {
+                Some(cfg) => quote! { #[cfg(#cfg)] { #check } },
+                None => check,
+            })
+        });
+        // e.g. `route_users_id` for `/users/{id}`, numbered if taken
+        let mut test_name = format!("route {}", entry.path.to_lowercase())
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join("_");
+        let base_name = test_name.clone();
+        let mut number = 1;
+        while !test_names.insert(test_name.clone()) {
+            number += 1;
+            test_name = format!("{base_name}_{number}");
+        }
+        let test_name = format_ident!("{}", test_name);
+        let cfg = routes.entry_cfg(&entry).map(|cfg| quote! { #[cfg(#cfg)] });
+
+        tests.push(quote! {
+            #cfg
+            #[tokio::test]
+            async fn #test_name() {
+                #(#checks)*
+            }
+        });
+    }
+
+    quote! {
+        #[cfg(test)]
+        mod #tests_mod {
+            use super::*;
+
+            async fn assert_routed(method: &str, path: &str, host: &str) {
+                let router = #struct_name::into_router_with_state(#state);
+                let request = axum::http::Request::builder()
+                    .method(method)
+                    .uri(path)
+                    .header(axum::http::header::HOST, host)
+                    .body(axum::body::Body::empty())
+                    .unwrap();
+                let status = tower::ServiceExt::oneshot(router, request)
+                    .await
+                    .unwrap()
+                    .status();
+
+                assert!(
+                    status != axum::http::StatusCode::NOT_FOUND
+                        && status != axum::http::StatusCode::METHOD_NOT_ALLOWED,
+                    "{method} {path} responded with {status}"
+                );
+            }
+
+            #(#tests)*
+        }
+    }
+}
+
+// The route lines of `routes` as a `&[&str]` slice, or an assertion comparing
+// them to the `expected` lines
+pub fn assert_routes(
+    args: &parse::FolderRouterArgs,
+    routes: &parse::FolderRouterRoutes,
+    expected: Option<&syn::ExprArray>,
+) -> TokenStream {
+    let lines = table::route_lines(&table::route_table(args, &args.abs_norm_path(), routes));
+    let actual = quote! { &[#(#lines),*] as &[&str] };
+
+    match expected {
+        Some(expected) => quote! {
+            assert_eq!(
+                #actual,
+                &#expected as &[&str],
+                "The routes changed, update the expected routes if this was intended"
+            )
+        },
+        None => actual,
+    }
+}
+
+// The `(method, path, file)` tuples of `routes` as a `&[(&str, &str, &str)]`
+// slice
+pub fn route_table_const(
+    args: &parse::FolderRouterArgs,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let entries = table::route_table(args, &args.abs_norm_path(), routes);
+    let rows = table::route_methods(&entries)
+        .into_iter()
+        .map(|(method, entry)| {
+            let path = &entry.path;
+            let file = entry.file();
+            quote! { (#method, #path, #file) }
+        });
+
+    quote! { &[#(#rows),*] as &[(&str, &str, &str)] }
+}
+
+pub fn module_tree(
+    args: &parse::FolderRouterArgs,
+    item: &parse::FolderRouterItem,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let base_path_lit = base_path_lit(args);
+    let mod_namespace = item.module_namespace();
+    let mod_hierarchy = module_hierarchy(args, &mod_namespace, routes);
+
+    quote! {
+        #[path = #base_path_lit]
+        mod #mod_namespace {
+            #mod_hierarchy
+        }
+    }
+}
+
+fn base_path_lit(args: &parse::FolderRouterArgs) -> LitStr {
+    LitStr::new(
+        &parse::path_literal(&args.abs_norm_path()),
+        proc_macro2::Span::call_site(),
+    )
+}
+
+// The route & folder file modules of `routes`
+fn module_hierarchy(
+    args: &parse::FolderRouterArgs,
+    mod_namespace: &syn::Path,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    let mod_str = mod_namespace.to_token_stream().to_string();
+    let mut root = ModuleDir::new(&mod_str);
+    for (_route_path, rel_path) in routes {
+        root.add_to_module_tree(rel_path, routes.route_cfg(rel_path));
+    }
+    for rel_path in routes.folder_files() {
+        root.add_to_module_tree(rel_path, routes.folder_cfg(rel_path));
+    }
+
+    let vis = match args.visibility {
+        parse::Visibility::Pub => quote! { pub },
+        parse::Visibility::Crate => quote! { pub(crate) },
+    };
+    let module_hierarchy = generate_module_hierarchy(&root, &vis);
+
+    // Including the files read by the macro rebuilds the crate when they
+    // change, like the route modules do
+    let data_files = routes
+        .data_files(&args.abs_norm_path())
+        .into_iter()
+        .map(|file| LitStr::new(&parse::path_literal(&file), proc_macro2::Span::call_site()));
+
+    quote! {
+        #module_hierarchy
+        #(const _: &[u8] = include_bytes!(#data_files);)*
+    }
+}
+
+// `#[folder_router(...)] mod api {}` as the module tree with a free `router`
+// fn
+pub fn module_router(
+    errors: &mut TokenStream,
+    args: &parse::FolderRouterArgs,
+    module: &syn::ItemMod,
+    routes: &parse::FolderRouterRoutes,
+) -> TokenStream {
+    if module
+        .content
+        .as_ref()
+        .is_some_and(|(_, items)| !items.is_empty())
+    {
+        return syn::Error::new_spanned(
+            module,
+            "Expected an empty module, e.g. `mod api;` or `mod api {}`",
+        )
+        .to_compile_error();
+    }
+    if let Some(host) = &args.host {
+        let message = format!("The `host` option (\"{host}\") isn't supported on modules");
+        errors.extend(quote! {
+            compile_error!(#message);
+        });
+    }
+
+    let attrs = &module.attrs;
+    let vis = &module.vis;
+    let ident = &module.ident;
+    let state_type = &args.state_type;
+    let base_path_lit = base_path_lit(args);
+    let mod_namespace: syn::Path = syn::parse_quote!(self);
+    let mod_hierarchy = module_hierarchy(args, &mod_namespace, routes);
+    let mut build_router = router_body(errors, args, &mod_namespace, routes, true);
+    let host_param_types = host_param_types(args, routes);
+    let locale_type = locale_type(args);
+    let csrf_token_type = csrf_token_type(args);
+    let config_type = config_type(args);
+
+    let config_fn = args.config.then(|| {
+        let config_fn = quote! {
+            pub fn router_with_config(config: FolderRouterConfig) -> axum::Router<#state_type> {
+                #build_router
+            }
+        };
+        build_router = quote! { router_with_config(FolderRouterConfig::default()) };
+        config_fn
+    });
+
+    quote! {
+        #(#attrs)*
+        #[path = #base_path_lit]
+        #vis mod #ident {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #mod_hierarchy
+            #host_param_types
+            #locale_type
+            #csrf_token_type
+            #config_type
+
+            pub fn router() -> axum::Router<#state_type> {
+                #build_router
+            }
+            #config_fn
+        }
+    }
+}
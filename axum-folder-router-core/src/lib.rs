@@ -0,0 +1,18 @@
+//! Route scanner & code generation shared by the
+//! [axum-folder-router](https://docs.rs/axum-folder-router) macros and the
+//! `cargo folder-router` subcommand, so both resolve routes the same way.
+//!
+//! This crate has no stable API, its items change with the macro.
+#![forbid(unsafe_code)]
+// Items are only public for the two crates using them
+#![allow(
+    clippy::iter_without_into_iter,
+    clippy::into_iter_without_iter,
+    clippy::missing_panics_doc,
+    clippy::must_use_candidate,
+    clippy::return_self_not_must_use
+)]
+
+pub mod generate;
+pub mod parse;
+pub mod table;
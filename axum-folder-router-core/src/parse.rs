@@ -0,0 +1,2577 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+    time::SystemTime,
+};
+
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    parse_file,
+    Attribute,
+    Expr,
+    ExprLit,
+    FnArg,
+    GenericArgument,
+    Ident,
+    Item,
+    ItemFn,
+    Lit,
+    LitBool,
+    LitStr,
+    PathArguments,
+    Result,
+    ReturnType,
+    Token,
+    Type,
+};
+
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct FolderRouterArgs {
+    pub path: String,
+    pub state_type: Ident,
+    pub auto_options: bool,
+    pub auto_head: bool,
+    pub compression: Vec<String>,
+    pub env: Option<String>,
+    pub deprecated: Vec<String>,
+    pub sunset: BTreeMap<String, String>,
+    pub trailing_slash: TrailingSlash,
+    pub case: Case,
+    pub visibility: Visibility,
+    pub param_syntax: ParamSyntax,
+    pub test_state: Option<proc_macro2::TokenStream>,
+    /// Error type all handlers have to return in a `Result<_, E>`
+    pub error: Option<proc_macro2::TokenStream>,
+    pub sitemap: Option<String>,
+    pub sitemap_exclude: Vec<String>,
+    /// Path of the liveness route, with the readiness route below it
+    pub health: Option<String>,
+    /// Header carrying the request ids set & propagated by the router
+    pub request_id: Option<String>,
+    /// Whether routes are traced in spans named after their path
+    pub trace: bool,
+    /// Whether duplicate & trailing slashes are removed before routing
+    pub normalize_path: bool,
+    /// Whether the method of `POST` requests can be overridden before routing
+    pub method_override: bool,
+    /// Whether HTML responses reload the page after restarts in debug builds
+    pub livereload: bool,
+    /// Whether debug builds route every request by the latest hot patch of
+    /// the router
+    pub hot_reload: bool,
+    /// Path prefixes of the routes whose mutating requests require a CSRF
+    /// token
+    pub csrf: Vec<String>,
+    /// JWT validation of the `auth = jwt(...)` option
+    pub auth: Option<JwtAuth>,
+    pub host: Option<String>,
+    pub locales: Vec<String>,
+    /// Whether to generate `into_router_with_config`
+    pub config: bool,
+    /// How the route folder is walked
+    pub scan: ScanOptions,
+}
+
+/// How path parameters are rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamSyntax {
+    /// `/users/{id}` & `/files/{*path}`, as used by axum 0.8
+    Braces,
+    /// `/users/{:id}` & `/files/{*path}`
+    ColonBraces,
+    /// `/users/:id` & `/files/*path`, as used by axum 0.7
+    Colon,
+}
+
+impl Default for ParamSyntax {
+    #[cfg(not(feature = "axum-07"))]
+    fn default() -> Self {
+        Self::Braces
+    }
+
+    #[cfg(feature = "axum-07")]
+    fn default() -> Self {
+        Self::Colon
+    }
+}
+
+/// How folder names map to path segments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    /// `user_settings/` is served at `/user_settings`
+    Preserve,
+    /// `user_settings/` is served at `/user-settings`
+    Kebab,
+}
+
+/// Visibility of the generated route & folder file modules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    /// `pub mod route;`
+    Pub,
+    /// `pub(crate) mod route;`
+    Crate,
+}
+
+/// How paths with a trailing slash are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingSlash {
+    /// `/users/` doesn't match `/users`
+    Strict,
+    /// `/users/` redirects to `/users` with `308 Permanent Redirect`
+    Redirect,
+    /// `/users/` is handled by the same route as `/users`
+    Merge,
+}
+
+impl FolderRouterArgs {
+    pub fn abs_norm_path(&self) -> PathBuf {
+        // Verbatim Windows paths don't treat `/` as a separator
+        let base_path = self.path.replace('/', std::path::MAIN_SEPARATOR_STR);
+
+        let manifest_dir = strip_verbatim_prefix(&Self::get_manifest_dir());
+        let base_dir = Path::new(&manifest_dir).join(&base_path);
+
+        PathBuf::from(strip_verbatim_prefix(&base_dir.to_string_lossy()))
+    }
+
+    // This is a workaround for macrotest behaviour
+    #[cfg(debug_assertions)]
+    fn get_manifest_dir() -> String {
+        use regex::Regex;
+        let dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or("./".to_string());
+        let re = Regex::new(r"^(.+)/target/tests/axum-folder-router/[A-Za-z0-9]{42}$").unwrap();
+
+        if let Some(captures) = re.captures(&dir) {
+            captures.get(1).unwrap().as_str().to_string()
+        } else {
+            dir
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn get_manifest_dir() -> String {
+        std::env::var("CARGO_MANIFEST_DIR").unwrap_or("./".to_string())
+    }
+}
+
+// Removes the `\\?\` prefix of verbatim Windows paths like `\\?\C:\app`
+// or `\\?\UNC\server\share`, which don't allow `/` separators & `..`
+// components
+fn strip_verbatim_prefix(path: &str) -> String {
+    if let Some(share) = path.strip_prefix(r"\\?\UNC\") {
+        return format!(r"\\{share}");
+    }
+
+    path.strip_prefix(r"\\?\")
+        .filter(|path| path.get(1..2) == Some(":"))
+        .unwrap_or(path)
+        .to_string()
+}
+
+/// A path as string literal for `#[path]` & generated code, with `/`
+/// separators on Windows too
+pub fn path_literal(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    if cfg!(windows) {
+        path.replace('\\', "/")
+    } else {
+        path.into_owned()
+    }
+}
+
+impl Parse for FolderRouterArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // Either the positional `"...", AppState` or the named
+        // `path = "...", state = AppState` form
+        let (path_lit, state_type) = if input.peek(Ident) && input.peek2(Token![=]) {
+            parse_named_path_and_state(input)?
+        } else {
+            let path_lit = input.parse::<LitStr>()?;
+            input.parse::<Token![,]>()?;
+            (path_lit, input.parse::<Ident>()?)
+        };
+
+        Self::parse_options(input, &path_lit, state_type)
+    }
+}
+
+impl FolderRouterArgs {
+    /// Parses the optional `key = value` pairs after the positional arguments,
+    /// stopping before a trailing `[...]` list
+    fn parse_options(input: ParseStream, path_lit: &LitStr, state_type: Ident) -> Result<Self> {
+        let mut args = FolderRouterArgs {
+            path: path_lit.value(),
+            state_type,
+            auto_options: false,
+            auto_head: false,
+            compression: Vec::new(),
+            env: None,
+            deprecated: Vec::new(),
+            sunset: BTreeMap::new(),
+            trailing_slash: TrailingSlash::Strict,
+            case: Case::Preserve,
+            visibility: Visibility::Pub,
+            param_syntax: ParamSyntax::default(),
+            test_state: None,
+            error: None,
+            sitemap: None,
+            sitemap_exclude: Vec::new(),
+            health: None,
+            request_id: None,
+            trace: false,
+            normalize_path: false,
+            method_override: false,
+            livereload: false,
+            hot_reload: false,
+            csrf: Vec::new(),
+            auth: None,
+            host: None,
+            locales: Vec::new(),
+            config: false,
+            scan: ScanOptions::default(),
+        };
+        args.parse_config_file(path_lit)?;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() || input.peek(syn::token::Bracket) {
+                break;
+            }
+
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            args.parse_option(&key, input)?;
+        }
+
+        // A sunset date only makes sense for deprecated versions
+        if let Some(version) = args.sunset.keys().find(|v| !args.deprecated.contains(v)) {
+            return Err(syn::Error::new(
+                path_lit.span(),
+                format!("API version `{version}` has a sunset date but isn't deprecated"),
+            ));
+        }
+
+        Ok(args)
+    }
+
+    /// Applies the options of the route folder's `folder_router.toml`, which
+    /// options of the macro invocation override
+    fn parse_config_file(&mut self, path_lit: &LitStr) -> Result<()> {
+        let Ok(content) = fs::read_to_string(self.abs_norm_path().join(OPTIONS_FILE)) else {
+            return Ok(());
+        };
+        let error = |message: &dyn std::fmt::Display| {
+            syn::Error::new(path_lit.span(), format!("{OPTIONS_FILE}: {message}"))
+        };
+        let table = content
+            .parse::<toml::Table>()
+            .map_err(|err| error(&err.message()))?;
+
+        for (key, value) in &table {
+            let key = syn::parse_str::<Ident>(key)
+                .map_err(|_| error(&format!("Unknown folder_router option `{key}`")))?;
+            // Paths are given as strings, e.g. `error = "crate::AppError"`
+            let tokens = match value {
+                toml::Value::String(path) if key == "test_state" || key == "error" => path
+                    .parse()
+                    .map_err(|_| error(&format!("Invalid `{key}` path")))?,
+                value => toml_tokens(value).ok_or_else(|| error(&format!("Invalid `{key}`")))?,
+            };
+
+            let parser = |input: ParseStream| self.parse_option(&key, input);
+            parser.parse2(tokens).map_err(|err| error(&err))?;
+        }
+
+        Ok(())
+    }
+
+    /// Parses the value of the option `key`
+    fn parse_option(&mut self, key: &Ident, input: ParseStream) -> Result<()> {
+        match key.to_string().as_str() {
+            "auto_options" => self.auto_options = input.parse::<LitBool>()?.value,
+            "auto_head" => self.auto_head = input.parse::<LitBool>()?.value,
+            "config" => self.config = input.parse::<LitBool>()?.value,
+            "max_depth" => {
+                self.scan.max_depth = Some(input.parse::<syn::LitInt>()?.base10_parse()?);
+            }
+            "follow_symlinks" => self.scan.follow_symlinks = input.parse::<LitBool>()?.value,
+            "skip_hidden" => self.scan.skip_hidden = input.parse::<LitBool>()?.value,
+            "compression" => self.compression = parse_str_list(input)?,
+            "env" => {
+                self.env = Some(parse_checked(
+                    input,
+                    |env| ENVS.contains(&env),
+                    "Unknown env, expected \"dev\" or \"prod\"",
+                )?);
+            }
+            "deprecated" => self.deprecated = parse_versions(input, key)?,
+            "sunset" => self.sunset = parse_sunset(input)?,
+            "case" => {
+                self.case = parse_choice(input, key, &[
+                    ("preserve", Case::Preserve),
+                    ("kebab", Case::Kebab),
+                ])?;
+            }
+            "visibility" => {
+                self.visibility = parse_choice(input, key, &[
+                    ("pub", Visibility::Pub),
+                    ("crate", Visibility::Crate),
+                ])?;
+            }
+            "param_syntax" => {
+                self.param_syntax = parse_choice(input, key, &[
+                    ("{id}", ParamSyntax::Braces),
+                    ("{:id}", ParamSyntax::ColonBraces),
+                    (":id", ParamSyntax::Colon),
+                ])?;
+            }
+            "trailing_slash" => {
+                self.trailing_slash = parse_choice(input, key, &[
+                    ("strict", TrailingSlash::Strict),
+                    ("redirect", TrailingSlash::Redirect),
+                    ("merge", TrailingSlash::Merge),
+                ])?;
+            }
+            "sitemap" => {
+                let base_url = parse_checked(
+                    input,
+                    |url| url.starts_with("http://") || url.starts_with("https://"),
+                    "Invalid sitemap base URL, expected e.g. \"https://example.com\"",
+                )?;
+                self.sitemap = Some(base_url.trim_end_matches('/').to_string());
+            }
+            "sitemap_exclude" => self.sitemap_exclude = parse_str_list(input)?,
+            "request_id" => self.request_id = parse_request_id(input)?,
+            "trace" => {
+                let enabled = cfg!(feature = "trace");
+                self.trace = parse_feature_flag(key, "trace", enabled, input)?;
+            }
+            "normalize_path" => self.normalize_path = input.parse::<LitBool>()?.value,
+            "method_override" => self.method_override = input.parse::<LitBool>()?.value,
+            "livereload" => {
+                let enabled = cfg!(feature = "livereload");
+                self.livereload = parse_feature_flag(key, "livereload", enabled, input)?;
+            }
+            "hot_reload" => {
+                let enabled = cfg!(feature = "hot-reload");
+                self.hot_reload = parse_feature_flag(key, "hot-reload", enabled, input)?;
+            }
+            "csrf" => {
+                require_feature(key, "csrf", cfg!(feature = "csrf"))?;
+                self.csrf = parse_str_list(input)?;
+            }
+            "auth" => self.auth = Some(parse_auth(key, input)?),
+            "health" => self.health = Some(parse_health(input)?),
+            "host" => {
+                self.host = Some(parse_checked(
+                    input,
+                    |host| !host.is_empty() && !host.contains(['/', ' ']),
+                    "Invalid host, expected e.g. \"api.example.com\"",
+                )?);
+            }
+            "locales" => self.locales = parse_locales(input, key)?,
+            "test_state" => {
+                self.test_state = Some(input.parse::<syn::Path>()?.to_token_stream());
+            }
+            "error" => {
+                self.error = Some(input.parse::<syn::Path>()?.to_token_stream());
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("Unknown folder_router option `{key}`"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Arguments of the `#[folder_router(...)]` helper attribute of
+/// `#[derive(FolderRouter)]`, naming the path & state:
+/// `path = "...", state = AppState`, followed by the options
+pub struct DeriveArgs {
+    pub args: FolderRouterArgs,
+}
+
+impl Parse for DeriveArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let (path_lit, state_type) = parse_named_path_and_state(input)?;
+
+        Ok(Self {
+            args: FolderRouterArgs::parse_options(input, &path_lit, state_type)?,
+        })
+    }
+}
+
+// Parses the named `path = "...", state = AppState` arguments
+fn parse_named_path_and_state(input: ParseStream) -> Result<(LitStr, Ident)> {
+    let path_key = input.parse::<Ident>()?;
+    if path_key != "path" {
+        return Err(syn::Error::new(
+            path_key.span(),
+            "Expected `path = \"...\"` as first argument",
+        ));
+    }
+    input.parse::<Token![=]>()?;
+    let path_lit = input.parse::<LitStr>()?;
+    input.parse::<Token![,]>()?;
+
+    let state_key = input.parse::<Ident>()?;
+    if state_key != "state" {
+        return Err(syn::Error::new(
+            state_key.span(),
+            "Expected `state = ...` as second argument",
+        ));
+    }
+    input.parse::<Token![=]>()?;
+    let state_type = input.parse::<Ident>()?;
+
+    Ok((path_lit, state_type))
+}
+
+/// Arguments of the function-like route table macros: a path, the
+/// `folder_router` options affecting paths & an optional `[...]` list
+pub struct RouteTableArgs {
+    pub args:     FolderRouterArgs,
+    pub expected: Option<syn::ExprArray>,
+}
+
+impl Parse for RouteTableArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let path_lit = input.parse::<LitStr>()?;
+        // The state type doesn't affect the route table
+        let state_type = Ident::new("State", path_lit.span());
+        let args = FolderRouterArgs::parse_options(input, &path_lit, state_type)?;
+
+        let expected = if input.is_empty() {
+            None
+        } else {
+            Some(input.parse::<syn::ExprArray>()?)
+        };
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(Self {
+            args,
+            expected,
+        })
+    }
+}
+
+/// Parses a string literal which has to pass `check`, failing with `message`
+fn parse_checked(
+    input: ParseStream,
+    check: impl Fn(&str) -> bool,
+    message: &str,
+) -> Result<String> {
+    let lit = input.parse::<LitStr>()?;
+    if !check(&lit.value()) {
+        return Err(syn::Error::new(lit.span(), message));
+    }
+    Ok(lit.value())
+}
+
+/// Parses a string literal which has to be one of the given choices
+fn parse_choice<T: Copy>(input: ParseStream, key: &Ident, choices: &[(&str, T)]) -> Result<T> {
+    let lit = input.parse::<LitStr>()?;
+
+    if let Some((_, choice)) = choices.iter().find(|(name, _)| *name == lit.value()) {
+        return Ok(*choice);
+    }
+
+    let expected: Vec<String> = choices
+        .iter()
+        .map(|(name, _)| format!("\"{name}\""))
+        .collect();
+    Err(syn::Error::new(
+        lit.span(),
+        format!("Unknown {key}, expected one of {}", expected.join(", ")),
+    ))
+}
+
+/// Parses the bool of an option requiring the cargo `feature`, `enabled` if
+/// it's enabled
+fn parse_feature_flag(
+    key: &Ident,
+    feature: &str,
+    enabled: bool,
+    input: ParseStream,
+) -> Result<bool> {
+    require_feature(key, feature, enabled)?;
+    Ok(input.parse::<LitBool>()?.value)
+}
+
+/// Fails unless the cargo `feature` required by the option `key` is `enabled`
+fn require_feature(key: &Ident, feature: &str, enabled: bool) -> Result<()> {
+    if enabled {
+        return Ok(());
+    }
+    Err(syn::Error::new(
+        key.span(),
+        format!("The `{key}` option requires the `{feature}` feature of axum-folder-router"),
+    ))
+}
+
+/// Parses the `jwt(...)` of the `auth` option, which requires the `jwt`
+/// feature
+fn parse_auth(key: &Ident, input: ParseStream) -> Result<JwtAuth> {
+    require_feature(key, "jwt", cfg!(feature = "jwt"))?;
+    input.parse()
+}
+
+/// Algorithms of `jsonwebtoken` for the `algorithm` of `auth = jwt(...)`
+pub const JWT_ALGORITHMS: &[&str] = &[
+    "HS256", "HS384", "HS512", "RS256", "RS384", "RS512", "PS256", "PS384", "PS512", "ES256",
+    "ES384", "EdDSA",
+];
+
+/// Arguments of the `auth = jwt(audience = "...", issuer = "...")` option
+#[derive(Debug, Clone)]
+pub struct JwtAuth {
+    /// Path prefixes of the validated routes, all routes if empty
+    pub paths:     Vec<String>,
+    pub audience:  Option<String>,
+    pub issuer:    Option<String>,
+    pub algorithm: &'static str,
+    /// Env var holding the HMAC secret or the PEM encoded public key
+    pub key_env:   String,
+    /// Type the claims are deserialized into, `serde_json::Value` by default
+    pub claims:    proc_macro2::TokenStream,
+}
+
+impl Parse for JwtAuth {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let kind = input.parse::<Ident>()?;
+        if kind != "jwt" {
+            return Err(syn::Error::new(
+                kind.span(),
+                "Unknown auth, expected `jwt(...)`",
+            ));
+        }
+
+        let mut auth = JwtAuth {
+            paths:     Vec::new(),
+            audience:  None,
+            issuer:    None,
+            algorithm: "HS256",
+            key_env:   "JWT_SECRET".to_string(),
+            claims:    quote::quote! { serde_json::Value },
+        };
+        let content;
+        syn::parenthesized!(content in input);
+        while !content.is_empty() {
+            let key = content.parse::<Ident>()?;
+            content.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "paths" => auth.paths = parse_str_list(&content)?,
+                "audience" => auth.audience = Some(content.parse::<LitStr>()?.value()),
+                "issuer" => auth.issuer = Some(content.parse::<LitStr>()?.value()),
+                "algorithm" => {
+                    let choices: Vec<(&str, &'static str)> =
+                        JWT_ALGORITHMS.iter().map(|alg| (*alg, *alg)).collect();
+                    auth.algorithm = parse_choice(&content, &key, &choices)?;
+                }
+                "key_env" => {
+                    auth.key_env = parse_checked(
+                        &content,
+                        |name| !name.is_empty() && !name.contains(['=', '\0']),
+                        "Invalid key_env, expected an env var name like \"JWT_SECRET\"",
+                    )?;
+                }
+                "claims" => auth.claims = content.parse::<syn::Path>()?.to_token_stream(),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("Unknown jwt option `{key}`"),
+                    ));
+                }
+            }
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(auth)
+    }
+}
+
+/// Parses the path of the liveness route like `"/healthz"`, without trailing
+/// slashes
+fn parse_health(input: ParseStream) -> Result<String> {
+    let path = parse_checked(
+        input,
+        |path| path.len() > 1 && path.starts_with('/') && !path.contains(['{', '*']),
+        "Invalid health path, expected e.g. \"/healthz\"",
+    )?;
+    Ok(path.trim_end_matches('/').to_string())
+}
+
+/// Parses `true` or the lowercase name of a request id header like
+/// `"x-correlation-id"`, defaulting to `x-request-id`
+fn parse_request_id(input: ParseStream) -> Result<Option<String>> {
+    if input.peek(LitBool) {
+        let enabled = input.parse::<LitBool>()?.value;
+        return Ok(enabled.then(|| "x-request-id".to_string()));
+    }
+
+    parse_checked(
+        input,
+        |name| {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        },
+        "Invalid request id header, expected a lowercase name like \"x-request-id\"",
+    )
+    .map(Some)
+}
+
+/// Parses a list of API version folders like `["v1", "v2"]`
+fn parse_versions(input: ParseStream, key: &Ident) -> Result<Vec<String>> {
+    let versions = parse_str_list(input)?;
+    if let Some(version) = versions.iter().find(|v| !is_version_dir(v)) {
+        return Err(syn::Error::new(
+            key.span(),
+            format!("Invalid API version `{version}`, expected e.g. \"v1\""),
+        ));
+    }
+    Ok(versions)
+}
+
+/// Parses a list of locales like `["en", "pt-BR"]`
+fn parse_locales(input: ParseStream, key: &Ident) -> Result<Vec<String>> {
+    let locales = parse_str_list(input)?;
+    let is_locale = |locale: &String| {
+        !locale.is_empty()
+            && locale
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if let Some(locale) = locales.iter().find(|locale| !is_locale(locale)) {
+        return Err(syn::Error::new(
+            key.span(),
+            format!("Invalid locale `{locale}`, expected e.g. \"en\" or \"pt-BR\""),
+        ));
+    }
+    Ok(locales)
+}
+
+/// Parses the `{ v1 = "Fri, 01 Jan 2027 00:00:00 GMT" }` map of sunset dates
+// The tokens of a `folder_router.toml` value as they'd be written in the
+// macro invocation, e.g. `["/api"]` for an array or `{ v1 = "..." }` for a
+// table
+fn toml_tokens(value: &toml::Value) -> Option<proc_macro2::TokenStream> {
+    Some(match value {
+        toml::Value::String(string) => quote::quote! { #string },
+        toml::Value::Integer(int) => {
+            let int = proc_macro2::Literal::i64_unsuffixed(*int);
+            quote::quote! { #int }
+        }
+        toml::Value::Boolean(bool) => quote::quote! { #bool },
+        toml::Value::Array(values) => {
+            let values = values.iter().map(toml_tokens).collect::<Option<Vec<_>>>()?;
+            quote::quote! { [#(#values),*] }
+        }
+        toml::Value::Table(table) => {
+            let entries = table
+                .iter()
+                .map(|(key, value)| {
+                    let key = syn::parse_str::<Ident>(key).ok()?;
+                    let value = toml_tokens(value)?;
+                    Some(quote::quote! { #key = #value })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            quote::quote! { { #(#entries),* } }
+        }
+        toml::Value::Float(_) | toml::Value::Datetime(_) => return None,
+    })
+}
+
+fn parse_sunset(input: ParseStream) -> Result<BTreeMap<String, String>> {
+    let content;
+    syn::braced!(content in input);
+
+    let mut sunset = BTreeMap::new();
+    while !content.is_empty() {
+        let version = content.parse::<Ident>()?;
+        content.parse::<Token![=]>()?;
+        let date = content.parse::<LitStr>()?;
+
+        // Header values must be visible ASCII
+        if !date.value().chars().all(|c| (' '..='~').contains(&c)) {
+            return Err(syn::Error::new(
+                date.span(),
+                "Invalid sunset date, expected e.g. \"Fri, 01 Jan 2027 00:00:00 GMT\"",
+            ));
+        }
+        sunset.insert(version.to_string(), date.value());
+
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+
+    Ok(sunset)
+}
+
+/// Whether a folder is an API version folder like `v1`
+pub fn is_version_dir(name: &str) -> bool {
+    name.strip_prefix('v')
+        .is_some_and(|version| !version.is_empty() && version.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Whether a path parameter name like `user_id` can be used in the name of
+/// the static compiling its regex, i.e. is ASCII alphanumeric or `_` & doesn't
+/// start with a digit
+pub fn is_regex_param_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parses either a single string literal or a list like `["a", "b"]`
+fn parse_str_list(input: ParseStream) -> Result<Vec<String>> {
+    if input.peek(LitStr) {
+        return Ok(vec![input.parse::<LitStr>()?.value()]);
+    }
+
+    let content;
+    syn::bracketed!(content in input);
+    let list = content.parse_terminated(syn::parse::ParseBuffer::parse::<LitStr>, Token![,])?;
+
+    Ok(list.iter().map(LitStr::value).collect())
+}
+
+/// Environments of `route.<env>.rs` variants
+pub const ENVS: &[&str] = &["dev", "prod"];
+
+/// Route files, `route.<env>.rs` variants replace `route.rs` in their
+/// environment
+pub const ROUTE_FILES: &[&str] = &["route.rs", "route.dev.rs", "route.prod.rs"];
+
+/// Files besides `route.rs` which apply to the folder they're in (& its
+/// subfolders)
+pub const FOLDER_FILES: &[&str] = &[
+    "action.rs",
+    "api_key.rs",
+    "cors.rs",
+    "fragment.rs",
+    "graphql.rs",
+    "guard.rs",
+    "health.rs",
+    "layout.rs",
+    "method_not_allowed.rs",
+    "mount.rs",
+    "page.rs",
+    "resource.rs",
+    "router.rs",
+    "service.rs",
+    "state.rs",
+];
+
+/// Folder files registering routes, which belong to the router of the
+/// subtree they're in
+const REGISTERING_FILES: &[&str] = &[
+    "action.rs",
+    "fragment.rs",
+    "graphql.rs",
+    "mount.rs",
+    "page.rs",
+    "resource.rs",
+    "router.rs",
+    "service.rs",
+];
+
+/// Marker file containing the name of a cargo feature, which gates the
+/// folder it's in & its subfolders
+pub const FEATURE_FILE: &str = "feature.txt";
+
+/// File containing the regex the path parameter folder it's in has to match
+pub const REGEX_FILE: &str = "regex.txt";
+
+/// File with the layer settings of the folder it's in & its subfolders
+pub const CONFIG_FILE: &str = "config.toml";
+
+/// File in the route folder setting the options of the macro
+pub const OPTIONS_FILE: &str = "folder_router.toml";
+
+/// File in the route folder mapping old paths to the ones they redirect to
+pub const REDIRECTS_FILE: &str = "redirects.toml";
+
+/// Status codes of redirects
+const REDIRECT_STATUSES: &[u16] = &[301, 302, 303, 307, 308];
+
+/// Handler functions of a `resource.rs`, in registration order
+pub const RESOURCE_ACTIONS: &[&str] = &["index", "create", "show", "update", "destroy"];
+
+/// Suffixes of content negotiated handlers like `get__json` & the media
+/// types they respond with
+pub const MEDIA_TYPES: &[(&str, &str)] = &[
+    ("json", "application/json"),
+    ("html", "text/html"),
+    ("xml", "application/xml"),
+    ("text", "text/plain"),
+];
+
+/// HTTP verbs handlers can be named after, in registration order
+pub const HTTP_METHODS: [&str; 10] = [
+    "any", "get", "post", "put", "delete", "patch", "head", "options", "trace", "connect",
+];
+
+/// Folders with this name are served as static assets
+pub const PUBLIC_DIR: &str = "public";
+
+/// Hidden folder which is scanned anyway, as it's used for well-known URIs
+/// like `/.well-known/security.txt`
+const WELL_KNOWN_DIR: &str = ".well-known";
+
+/// Settings from `#[axum_folder_router::...]` attributes on a handler
+#[derive(Debug, Default, Clone)]
+pub struct HandlerAttrs {
+    /// Whether the handler should not be registered
+    pub skip: bool,
+    /// Whether the handler is `#[deprecated]`
+    pub deprecated: bool,
+    /// Request timeout in milliseconds
+    pub timeout: Option<u64>,
+    /// Maximum request body size in bytes
+    pub body_limit: Option<usize>,
+    /// Maximum requests handled at once
+    pub concurrency_limit: Option<usize>,
+    /// Whether responses get an `ETag` & `If-None-Match` is answered with
+    /// `304 Not Modified`
+    pub etag: bool,
+    /// `Cache-Control` header of responses without one
+    pub cache_control: Option<String>,
+    /// Limits & allowed content types of uploads
+    pub upload: Option<Upload>,
+    /// Rate limit per peer IP
+    #[cfg(feature = "rate-limit")]
+    pub rate_limit: Option<RateLimit>,
+    /// Predicates of `#[cfg(...)]` attributes on the handler, as source text
+    /// so they can be cached across expansions
+    pub cfg: Vec<String>,
+}
+
+impl HandlerAttrs {
+    fn from_attributes(attrs: &[Attribute]) -> Self {
+        let mut handler_attrs = Self::default();
+
+        // Invalid arguments are reported by the attribute macros themselves
+        for attr in attrs {
+            if attr.path().is_ident("deprecated") {
+                handler_attrs.deprecated = true;
+                continue;
+            }
+            if attr.path().is_ident("cfg") {
+                if let Ok(list) = attr.meta.require_list() {
+                    handler_attrs.cfg.push(list.tokens.to_string());
+                }
+                continue;
+            }
+
+            match folder_router_attr_name(attr).as_deref() {
+                Some("skip") => handler_attrs.skip = true,
+                Some("etag") => handler_attrs.etag = true,
+                Some("timeout") => {
+                    handler_attrs.timeout = attr
+                        .parse_args::<LitStr>()
+                        .ok()
+                        .and_then(|lit| parse_duration(&lit.value()));
+                }
+                Some("body_limit") => {
+                    handler_attrs.body_limit = attr
+                        .parse_args::<LitStr>()
+                        .ok()
+                        .and_then(|lit| parse_size(&lit.value()));
+                }
+                Some("cache_control") => {
+                    handler_attrs.cache_control = attr
+                        .parse_args::<LitStr>()
+                        .ok()
+                        .map(|lit| lit.value())
+                        .filter(|value| is_header_value(value));
+                }
+                Some("upload") => handler_attrs.upload = attr.parse_args::<Upload>().ok(),
+                Some("concurrency_limit") => {
+                    handler_attrs.concurrency_limit = attr
+                        .parse_args::<syn::LitInt>()
+                        .ok()
+                        .and_then(|lit| lit.base10_parse::<usize>().ok())
+                        .filter(|limit| *limit > 0);
+                }
+                #[cfg(feature = "rate-limit")]
+                Some("rate_limit") => {
+                    handler_attrs.rate_limit = attr.parse_args::<RateLimit>().ok();
+                }
+                _ => {}
+            }
+        }
+
+        handler_attrs
+    }
+}
+
+/// The type of the `pub type State = AdminState;` alias of a `state.rs`, if
+/// it's a plain type like `AdminState` or `crate::admin::AdminState`
+pub fn subtree_state_type(path: &Path) -> Option<Ident> {
+    let file = parse_file(&fs::read_to_string(path).ok()?).ok()?;
+
+    file.items.iter().find_map(|item| {
+        let Item::Type(alias) = item else {
+            return None;
+        };
+        if alias.ident != "State" || !alias.generics.params.is_empty() {
+            return None;
+        }
+        let Type::Path(type_path) = &*alias.ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        segment.arguments.is_none().then(|| segment.ident.clone())
+    })
+}
+
+/// How a `state.rs` provides the state of its subtree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateSource {
+    /// A `pub fn state() -> State`, routing the subtree with its own state
+    Fn,
+    /// A `pub fn from_ref(state: &AppState) -> State`, the body of a generated
+    /// `FromRef` impl deriving the state from the macro's state
+    FromRef,
+    /// Neither, `State` has to implement `FromRef` of the macro's state
+    Existing,
+}
+
+/// How the `state.rs` at `path` provides the state of its subtree
+pub fn subtree_state_source(path: &Path) -> StateSource {
+    let Some(file) = fs::read_to_string(path)
+        .ok()
+        .and_then(|content| parse_file(&content).ok())
+    else {
+        return StateSource::Fn;
+    };
+
+    let defines = |name: &str| {
+        file.items
+            .iter()
+            .any(|item| matches!(item, Item::Fn(f) if f.sig.ident == name))
+    };
+    if defines("state") {
+        StateSource::Fn
+    } else if defines("from_ref") {
+        StateSource::FromRef
+    } else {
+        StateSource::Existing
+    }
+}
+
+/// Whether the file at `path` defines a `pub const` named `name`, like the
+/// `HEADER` of an `api_key.rs`
+pub fn defines_const(path: &Path, name: &str) -> bool {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| parse_file(&content).ok())
+        .is_some_and(|file| {
+            file.items.iter().any(|item| {
+                matches!(item, Item::Const(item) if item.ident == name && is_visible(&item.vis))
+            })
+        })
+}
+
+/// Arguments of the `#[upload(limit = "10MB", file_limit = "2MB", accept =
+/// ["image/*"])]` attribute
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upload {
+    /// Maximum request body size in bytes
+    pub limit: usize,
+    /// Maximum size of each file of `multipart/form-data` requests in bytes
+    pub file_limit: Option<usize>,
+    /// Lowercase content types of the request or its files like `image/png`
+    /// or `image/*`, any type if empty
+    pub accept: Vec<String>,
+}
+
+impl Parse for Upload {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // axum's default body limit
+        let mut upload = Self {
+            limit: 2 * 1024 * 1024,
+            file_limit: None,
+            accept: Vec::new(),
+        };
+
+        while !input.is_empty() {
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            match key.to_string().as_str() {
+                "limit" | "file_limit" => {
+                    let value = input.parse::<LitStr>()?;
+                    let Some(size) = parse_size(&value.value()) else {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "Invalid size, expected e.g. \"512B\", \"64KB\", \"10MB\" or \"1GB\"",
+                        ));
+                    };
+                    if key == "limit" {
+                        upload.limit = size;
+                    } else {
+                        upload.file_limit = Some(size);
+                    }
+                }
+                "accept" => {
+                    let span = input.span();
+                    upload.accept = parse_str_list(input)?
+                        .into_iter()
+                        .map(|content_type| content_type.to_ascii_lowercase())
+                        .collect();
+                    if let Some(invalid) = upload.accept.iter().find(|content_type| {
+                        content_type.split_once('/').is_none_or(|(kind, subtype)| {
+                            kind.is_empty() || kind == "*" || subtype.is_empty()
+                        })
+                    }) {
+                        return Err(syn::Error::new(
+                            span,
+                            format!(
+                                "Invalid content type `{invalid}`, expected e.g. \"image/png\" or \
+                                 \"image/*\""
+                            ),
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "Unknown upload argument `{key}`, expected `limit`, `file_limit` or \
+                             `accept`"
+                        ),
+                    ));
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(upload)
+    }
+}
+
+/// Arguments of the `#[rate_limit(per_second = 5, burst = 10)]` attribute
+#[cfg(feature = "rate-limit")]
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    /// Requests replenished per second
+    pub per_second: u64,
+    /// Maximum requests allowed at once, defaults to `per_second`
+    pub burst: u32,
+}
+
+#[cfg(feature = "rate-limit")]
+impl Parse for RateLimit {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut per_second = None;
+        let mut burst = None;
+
+        while !input.is_empty() {
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let value = input.parse::<syn::LitInt>()?;
+
+            match key.to_string().as_str() {
+                "per_second" => {
+                    let rate = value.base10_parse::<u64>()?;
+                    // The replenish period is a whole number of nanoseconds
+                    if rate > 1_000_000_000 {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "Rate limits must be at most 1000000000 per second",
+                        ));
+                    }
+                    per_second = Some(rate);
+                }
+                "burst" => burst = Some(value.base10_parse::<u32>()?),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "Unknown rate_limit argument `{key}`, expected `per_second` or `burst`"
+                        ),
+                    ));
+                }
+            }
+            if (per_second == Some(0)) || (burst == Some(0)) {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "Rate limits must be at least 1",
+                ));
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let Some(per_second) = per_second else {
+            return Err(input.error("Missing `per_second` argument"));
+        };
+
+        Ok(Self {
+            per_second,
+            burst: burst.unwrap_or(u32::try_from(per_second).unwrap_or(u32::MAX)),
+        })
+    }
+}
+
+/// Returns the name of `#[axum_folder_router::name]` or
+/// `#[folder_router::name]` attributes
+fn folder_router_attr_name(attr: &Attribute) -> Option<String> {
+    let segments = &attr.path().segments;
+    if segments.len() != 2 {
+        return None;
+    }
+
+    let krate = segments[0].ident.to_string();
+    if krate == "axum_folder_router" || krate == "folder_router" {
+        Some(segments[1].ident.to_string())
+    } else {
+        None
+    }
+}
+
+/// Whether `value` is a non-empty header value of visible ASCII characters,
+/// spaces & tabs
+pub fn is_header_value(value: &str) -> bool {
+    !value.trim().is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_graphic() || c == ' ' || c == '\t')
+}
+
+/// Parses durations like `500ms`, `5s`, `1m` or `1h` into milliseconds
+pub fn parse_duration(duration: &str) -> Option<u64> {
+    let duration = duration.trim();
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(duration.len());
+    let (value, unit) = duration.split_at(split);
+    let value: u64 = value.parse().ok()?;
+
+    let factor = match unit.trim() {
+        "ms" => 1,
+        "s" => 1000,
+        "m" => 60 * 1000,
+        "h" => 60 * 60 * 1000,
+        _ => return None,
+    };
+
+    value.checked_mul(factor)
+}
+
+/// Parses sizes like `512B`, `64KB`, `10MB` or `1GB` into bytes, using
+/// multiples of 1024
+pub fn parse_size(size: &str) -> Option<usize> {
+    let size = size.trim();
+    let split = size
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(size.len());
+    let (value, unit) = size.split_at(split);
+    let value: usize = value.parse().ok()?;
+
+    let factor = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "KB" | "KIB" => 1024,
+        "MB" | "MIB" => 1024 * 1024,
+        "GB" | "GIB" => 1024 * 1024 * 1024,
+        _ => return None,
+    };
+
+    value.checked_mul(factor)
+}
+
+/// Handlers found in a `route.rs` file
+#[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct RouteHandlers {
+    /// HTTP verb functions, in registration order
+    pub methods: Vec<&'static str>,
+    /// Attribute settings of the HTTP verb functions
+    pub attrs: BTreeMap<&'static str, HandlerAttrs>,
+    /// Type names extracted by the `Path<...>` argument of the HTTP verb
+    /// functions, one per path parameter for tuples
+    pub path_types: BTreeMap<&'static str, Vec<String>>,
+    /// Whether a `method_not_allowed` fallback fn is defined
+    pub method_not_allowed: bool,
+    /// Whether a `middleware` fn is defined, wrapping the route
+    pub middleware: bool,
+    /// Whether a `Guard` extractor is defined, rejecting requests before the
+    /// route's handlers run
+    pub guard: bool,
+    /// Whether a `method_router` fn is defined, replacing the HTTP verb
+    /// functions
+    pub method_router: bool,
+    /// Path from a `ROUTE_PATH` const, replacing the folder based path
+    pub route_path: Option<String>,
+    /// Content negotiated variants like `get__json` of the HTTP verbs
+    pub variants: BTreeMap<&'static str, Variants>,
+    /// Names of variants with a suffix not in `MEDIA_TYPES`
+    pub unknown_variants: Vec<String>,
+    /// Type names of the errors returned in a `Result<_, E>` by the HTTP verb
+    /// functions & their variants, `None` for other return types
+    pub error_types: BTreeMap<String, Option<String>>,
+    /// Functions which aren't registered as handlers & why, e.g.
+    /// `("get", "isn't `pub`")`
+    pub rejected: Vec<(String, &'static str)>,
+    /// Names of all `pub async fn`s, whether they're handlers or not
+    pub pub_async_fns: Vec<String>,
+    /// Functions named like handlers which can't be used as one & why, e.g.
+    /// `("get", "can't be generic")`
+    pub unsupported: Vec<(String, &'static str)>,
+    /// `State<...>` arguments of the HTTP verb functions
+    pub state_types: BTreeMap<&'static str, StateExtractor>,
+    /// `NAME`, `TAGS` & `DESCRIPTION` consts describing the route
+    pub metadata: RouteMetadata,
+}
+
+/// Metadata consts of a `route.rs`, e.g. `pub const NAME: &str = "users";`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RouteMetadata {
+    /// `pub const NAME: &str`
+    pub name: Option<String>,
+    /// `pub const TAGS: &[&str]`
+    pub tags: Vec<String>,
+    /// `pub const DESCRIPTION: &str`
+    pub description: Option<String>,
+    /// `pub const DEPRECATED: bool`
+    pub deprecated: bool,
+    /// `pub const SUNSET: &str`, the HTTP date of a deprecated route's removal
+    pub sunset: Option<String>,
+    /// `pub const DEPRECATION_LINK: &str`, e.g. the URL of a migration guide
+    pub deprecation_link: Option<String>,
+}
+
+impl RouteMetadata {
+    // The values of the metadata consts among `items`, ignoring other consts
+    fn from_items(items: &[Item]) -> Self {
+        let mut metadata = RouteMetadata::default();
+
+        for item in items {
+            let Item::Const(const_item) = item else {
+                continue;
+            };
+            if !is_visible(&const_item.vis) {
+                continue;
+            }
+
+            // `&["users", "admin"]` or `["users", "admin"]`
+            let expr = match &*const_item.expr {
+                Expr::Reference(reference) => &*reference.expr,
+                expr => expr,
+            };
+            match const_item.ident.to_string().as_str() {
+                "NAME" => metadata.name = str_lit(expr),
+                "DESCRIPTION" => metadata.description = str_lit(expr),
+                "DEPRECATED" => {
+                    metadata.deprecated = matches!(expr, Expr::Lit(ExprLit {
+                        lit: Lit::Bool(lit),
+                        ..
+                    }) if lit.value);
+                }
+                "SUNSET" => metadata.sunset = str_lit(expr),
+                "DEPRECATION_LINK" => metadata.deprecation_link = str_lit(expr),
+                "TAGS" => {
+                    if let Expr::Array(array) = expr {
+                        metadata.tags = array.elems.iter().filter_map(str_lit).collect();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        metadata
+    }
+}
+
+// The value of a string literal expression
+fn str_lit(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit),
+            ..
+        }) => Some(lit.value()),
+        _ => None,
+    }
+}
+
+/// A `State<T>` argument of a handler
+#[derive(Debug, Clone)]
+pub struct StateExtractor {
+    /// The extracted type `T` as source text
+    pub ty:    String,
+    /// Position of the argument
+    pub index: usize,
+    /// Number of arguments of the handler
+    pub arity: usize,
+}
+
+// A `pub async fn` of a route file & what's known about its signature
+struct HandlerFn {
+    name: String,
+    attrs: HandlerAttrs,
+    path_types: Option<Vec<String>>,
+    error_type: Option<String>,
+    state: Option<StateExtractor>,
+}
+
+impl RouteHandlers {
+    // Registers `handler` as the handler of `method`
+    fn add_method(&mut self, method: &'static str, handler: HandlerFn) {
+        if let Some(variants) = self.variants.get_mut(method) {
+            variants.fallback = true;
+        }
+        self.methods.push(method);
+        self.attrs.insert(method, handler.attrs);
+        if let Some(path_types) = handler.path_types {
+            self.path_types.insert(method, path_types);
+        }
+        if let Some(state) = handler.state {
+            self.state_types.insert(method, state);
+        }
+        self.error_types
+            .insert(method.to_string(), handler.error_type);
+    }
+
+    /// Whether the function `name` is a content type variant, including
+    /// unknown ones, the `method_not_allowed` fallback or the `middleware`
+    fn is_variant_or_fallback(&self, name: &str) -> bool {
+        name == "method_not_allowed"
+            || name == "middleware"
+            || self.unknown_variants.iter().any(|variant| variant == name)
+            || name
+                .split_once("__")
+                .is_some_and(|(method, _)| self.variants.contains_key(method))
+    }
+}
+
+/// Content negotiated variants of an HTTP verb, e.g. `get__json` & `get__html`
+#[derive(Debug, Default, Clone)]
+pub struct Variants {
+    /// Suffixes like `json`, in declaration order
+    pub suffixes: Vec<String>,
+    /// Whether the plain handler like `get` is defined, answering requests
+    /// accepting none of the variants
+    pub fallback: bool,
+}
+
+/// Modification time & size of a file, invalidating its cached parse results
+type FileStamp = (Option<SystemTime>, u64);
+
+fn file_stamp(path: &Path) -> Option<FileStamp> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok(), metadata.len()))
+}
+
+/// Parse results of unchanged files, reused by later expansions in the same
+/// compiler or rust-analyzer process, e.g. of `folder_router_table!` & the
+/// router of the same folder. Nothing is persisted, every build parses each
+/// file at least once. Only plain data is cached, as tokens & spans are only
+/// valid during the expansion which created them.
+///
+/// There's deliberately no cache on disk: proc macros only get an `OUT_DIR`
+/// if the crate has a build script, so there's no target dir to reliably
+/// write to, & reading a cached entry costs about as much as parsing the
+/// small files it would replace.
+static HANDLERS_CACHE: FileCache<Arc<RouteHandlers>> = Mutex::new(BTreeMap::new());
+static ACTIONS_CACHE: FileCache<Arc<[&'static str]>> = Mutex::new(BTreeMap::new());
+static GUARDS_CACHE: FileCache<bool> = Mutex::new(BTreeMap::new());
+static ASYNC_FNS_CACHE: FileCache<Arc<[String]>> = Mutex::new(BTreeMap::new());
+
+type FileCache<T> = Mutex<BTreeMap<PathBuf, (FileStamp, T)>>;
+
+/// Looks up the result of `parse` for the file at `path` in `cache`,
+/// parsing it again if it changed
+fn cached<T: Clone>(cache: &FileCache<T>, path: &Path, parse: impl FnOnce(&Path) -> T) -> T {
+    let Some(stamp) = file_stamp(path) else {
+        return parse(path);
+    };
+    // A poisoned cache only means a previous expansion panicked
+    let mut cache = cache.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some((cached_stamp, value)) = cache.get(path) {
+        if *cached_stamp == stamp {
+            return value.clone();
+        }
+    }
+
+    let value = parse(path);
+    cache.insert(path.to_path_buf(), (stamp, value.clone()));
+    value
+}
+
+/// Parses the file at the specified location and returns its handler
+/// functions
+pub fn handlers_for_route(route_path: &Path) -> Arc<RouteHandlers> {
+    cached(&HANDLERS_CACHE, route_path, |path| {
+        Arc::new(parse_route_handlers(path))
+    })
+}
+
+fn parse_route_handlers(route_path: &Path) -> RouteHandlers {
+    // Read the file content
+    let Ok(file_content) = fs::read_to_string(route_path) else {
+        return RouteHandlers::default();
+    };
+
+    // Parse the file content into a syn syntax tree
+    let Ok(file) = parse_file(&file_content) else {
+        return RouteHandlers::default();
+    };
+
+    // Define HTTP methods we're looking for
+    let allowed_methods = HTTP_METHODS;
+    let mut found_methods = Vec::new();
+    let mut method_router = false;
+    let mut rejected = Vec::new();
+    let mut pub_async_fns = Vec::new();
+    let mut unsupported = Vec::new();
+
+    // Collect all pub & async fn's
+    for item in &file.items {
+        if let Item::Fn(fn_item) = item {
+            let fn_name = fn_item.sig.ident.to_string();
+            let is_public = is_visible(&fn_item.vis);
+            let is_async = fn_item.sig.asyncness.is_some();
+
+            let attrs = HandlerAttrs::from_attributes(&fn_item.attrs);
+            if is_public && is_async {
+                pub_async_fns.push(fn_name.clone());
+            }
+
+            match (
+                is_public,
+                is_async,
+                unsupported_signature(fn_item, &fn_name),
+            ) {
+                _ if attrs.skip => rejected.push((fn_name, "is skipped")),
+                (true, true, Some(reason)) => unsupported.push((fn_name, reason)),
+                (true, true, None) => found_methods.push(HandlerFn {
+                    path_types: path_extractor_types(fn_item),
+                    error_type: result_error_type(fn_item),
+                    state: state_extractor(fn_item),
+                    name: fn_name,
+                    attrs,
+                }),
+                (true, false, _) if fn_name == "method_router" => method_router = true,
+                (true, false, _) => rejected.push((fn_name, "isn't `async`")),
+                (false, ..) => rejected.push((fn_name, "isn't `pub`")),
+            }
+        }
+    }
+
+    let mut handlers = RouteHandlers {
+        method_not_allowed: found_methods
+            .iter()
+            .any(|found| found.name == "method_not_allowed"),
+        middleware: found_methods.iter().any(|found| found.name == "middleware"),
+        guard: file.items.iter().any(is_guard_type),
+        method_router,
+        route_path: file.items.iter().find_map(route_path_const),
+        metadata: RouteMetadata::from_items(&file.items),
+        pub_async_fns,
+        unsupported,
+        ..Default::default()
+    };
+
+    // Content negotiated variants, e.g. `get__json` & `get__html`
+    for HandlerFn {
+        name,
+        error_type,
+        ..
+    } in &found_methods
+    {
+        let Some((method, suffix)) = name.split_once("__") else {
+            continue;
+        };
+        let Some(method) = allowed_methods.iter().find(|m| **m == method) else {
+            continue;
+        };
+
+        if MEDIA_TYPES.iter().any(|(known, _)| *known == suffix) {
+            handlers
+                .variants
+                .entry(method)
+                .or_default()
+                .suffixes
+                .push(suffix.to_string());
+            handlers
+                .error_types
+                .insert(name.clone(), error_type.clone());
+        } else {
+            handlers.unknown_variants.push(name.clone());
+        }
+    }
+
+    // Iterate through methods to ensure consistent order
+    for method in allowed_methods {
+        if let Some(pos) = found_methods.iter().position(|found| found.name == method) {
+            handlers.add_method(method, found_methods.remove(pos));
+        } else if handlers.variants.contains_key(method) {
+            handlers.methods.push(method);
+            handlers.attrs.insert(method, HandlerAttrs::default());
+        }
+    }
+
+    // Whatever is left is neither an HTTP verb nor one of its variants
+    rejected.extend(
+        found_methods
+            .into_iter()
+            .map(|found| found.name)
+            .filter(|name| !handlers.is_variant_or_fallback(name))
+            .map(|name| (name, "isn't named after an HTTP method")),
+    );
+    handlers.rejected = rejected;
+
+    handlers
+}
+
+// The value of a `ROUTE_PATH` string const
+fn route_path_const(item: &Item) -> Option<String> {
+    match item {
+        Item::Const(const_item) if const_item.ident == "ROUTE_PATH" => str_lit(&const_item.expr),
+        _ => None,
+    }
+}
+
+// Whether a function is visible to the generated router, i.e. `pub` or
+// `pub(crate)`
+fn is_visible(vis: &syn::Visibility) -> bool {
+    match vis {
+        syn::Visibility::Public(_) => true,
+        syn::Visibility::Restricted(restricted) => {
+            restricted.in_token.is_none() && restricted.path.is_ident("crate")
+        }
+        syn::Visibility::Inherited => false,
+    }
+}
+
+// Why a `pub async fn` named like a handler can't be one, as axum handlers
+// are plain functions without generics or a `self` receiver
+fn unsupported_signature(fn_item: &ItemFn, name: &str) -> Option<&'static str> {
+    let method = name.split_once("__").map_or(name, |(method, _)| method);
+    if !HTTP_METHODS.contains(&method) && name != "method_not_allowed" {
+        return None;
+    }
+
+    if fn_item.sig.receiver().is_some() {
+        Some("can't take `self`")
+    } else if fn_item
+        .sig
+        .generics
+        .params
+        .iter()
+        .any(|param| !matches!(param, syn::GenericParam::Lifetime(_)))
+    {
+        Some("can't be generic")
+    } else {
+        None
+    }
+}
+
+/// Finds a `Path<...>` argument of a handler and returns the names of the
+/// types it extracts, e.g. `["u32"]` for `Path<u32>` or `["String", "Uuid"]`
+/// for `Path<(String, uuid::Uuid)>`
+fn path_extractor_types(fn_item: &ItemFn) -> Option<Vec<String>> {
+    fn type_name(ty: &Type) -> String {
+        match ty {
+            Type::Path(type_path) => type_path
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+                .unwrap_or_default(),
+            _ => ty.to_token_stream().to_string(),
+        }
+    }
+
+    fn_item.sig.inputs.iter().find_map(|input| {
+        let FnArg::Typed(pat_type) = input else {
+            return None;
+        };
+        let Type::Path(type_path) = &*pat_type.ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        if segment.ident != "Path" {
+            return None;
+        }
+        let PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        let Some(GenericArgument::Type(inner)) = args.args.first() else {
+            return None;
+        };
+
+        Some(match inner {
+            Type::Tuple(tuple) => tuple.elems.iter().map(type_name).collect(),
+            ty => vec![type_name(ty)],
+        })
+    })
+}
+
+/// Finds a `State<T>` argument of a handler, e.g. `Db` for `State<Db>`
+fn state_extractor(fn_item: &ItemFn) -> Option<StateExtractor> {
+    let arity = fn_item.sig.inputs.len();
+
+    fn_item
+        .sig
+        .inputs
+        .iter()
+        .enumerate()
+        .find_map(|(index, input)| {
+            let FnArg::Typed(pat_type) = input else {
+                return None;
+            };
+            let Type::Path(type_path) = &*pat_type.ty else {
+                return None;
+            };
+            let segment = type_path.path.segments.last()?;
+            if segment.ident != "State" {
+                return None;
+            }
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            let Some(GenericArgument::Type(inner)) = args.args.first() else {
+                return None;
+            };
+
+            Some(StateExtractor {
+                ty: inner.to_token_stream().to_string(),
+                index,
+                arity,
+            })
+        })
+}
+
+/// The name of the error type of a handler returning a `Result<_, E>`, e.g.
+/// `AppError` for `Result<Json<User>, crate::AppError>`
+fn result_error_type(fn_item: &ItemFn) -> Option<String> {
+    let ReturnType::Type(_, ty) = &fn_item.sig.output else {
+        return None;
+    };
+    let Type::Path(type_path) = &**ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let Some(GenericArgument::Type(Type::Path(error))) = args.args.iter().nth(1) else {
+        return None;
+    };
+
+    error
+        .path
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+}
+
+/// Whether the `guard.rs` at the specified location defines a `Guard`
+/// extractor instead of a `guard` middleware fn
+pub fn is_extractor_guard(guard_path: &Path) -> bool {
+    cached(&GUARDS_CACHE, guard_path, |path| {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| parse_file(&content).ok())
+            .is_some_and(|file| file.items.iter().any(is_guard_type))
+    })
+}
+
+// Whether `item` is a visible `Guard` struct, enum or type alias
+fn is_guard_type(item: &Item) -> bool {
+    let (ident, vis) = match item {
+        Item::Struct(item) => (&item.ident, &item.vis),
+        Item::Enum(item) => (&item.ident, &item.vis),
+        Item::Type(item) => (&item.ident, &item.vis),
+        _ => return false,
+    };
+    ident == "Guard" && is_visible(vis)
+}
+
+/// Parses the `resource.rs` at the specified location and returns its
+/// defined actions
+pub fn actions_for_resource(resource_path: &Path) -> Arc<[&'static str]> {
+    cached(&ACTIONS_CACHE, resource_path, |path| {
+        parse_resource_actions(path).into()
+    })
+}
+
+fn parse_resource_actions(resource_path: &Path) -> Vec<&'static str> {
+    let Ok(file_content) = fs::read_to_string(resource_path) else {
+        return Vec::new();
+    };
+    let Ok(file) = parse_file(&file_content) else {
+        return Vec::new();
+    };
+
+    let found: Vec<String> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Fn(fn_item)
+                if is_visible(&fn_item.vis)
+                    && fn_item.sig.asyncness.is_some()
+                    && !HandlerAttrs::from_attributes(&fn_item.attrs).skip =>
+            {
+                Some(fn_item.sig.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect();
+
+    RESOURCE_ACTIONS
+        .iter()
+        .copied()
+        .filter(|action| found.iter().any(|name| name == action))
+        .collect()
+}
+
+/// Parses the file at the specified location and returns the names of its
+/// `pub async fn`s, e.g. the fragments of a fragment.rs
+pub fn async_fns_for_file(file_path: &Path) -> Arc<[String]> {
+    cached(&ASYNC_FNS_CACHE, file_path, |path| {
+        parse_async_fns(path).into()
+    })
+}
+
+fn parse_async_fns(file_path: &Path) -> Vec<String> {
+    let Ok(file_content) = fs::read_to_string(file_path) else {
+        return Vec::new();
+    };
+    let Ok(file) = parse_file(&file_content) else {
+        return Vec::new();
+    };
+
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Fn(fn_item)
+                if is_visible(&fn_item.vis)
+                    && fn_item.sig.asyncness.is_some()
+                    && !HandlerAttrs::from_attributes(&fn_item.attrs).skip =>
+            {
+                Some(fn_item.sig.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// How the route folder is walked
+#[derive(Debug, Clone, Copy)]
+pub struct ScanOptions {
+    /// Whether symlinked files & folders are scanned
+    pub follow_symlinks: bool,
+    /// Whether files & folders starting with a `.` are skipped, except
+    /// `.well-known`
+    pub skip_hidden: bool,
+    /// How many folder levels below the route folder are scanned at most
+    pub max_depth: Option<usize>,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            follow_symlinks: true,
+            skip_hidden: true,
+            max_depth: None,
+        }
+    }
+}
+
+// Calls `visit` with every entry below `dir` & whether it's a folder,
+// descending into the folders it returns `true` for. A symlinked folder
+// pointing to one of its own ancestors is skipped, so cycles can't make
+// the scan loop forever
+fn walk_dir(
+    dir: &Path,
+    options: ScanOptions,
+    ancestors: &mut Vec<PathBuf>,
+    visit: &mut dyn FnMut(&Path, bool) -> bool,
+) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(std::result::Result::ok) {
+        let path = entry.path();
+
+        let is_symlink = entry.file_type().is_ok_and(|ty| ty.is_symlink());
+        if is_symlink && !options.follow_symlinks {
+            continue;
+        }
+
+        // e.g. `.git`, `.direnv` or editor swap files like `.route.rs.swp`
+        let name = entry.file_name();
+        let is_hidden = name.to_string_lossy().starts_with('.') && name != WELL_KNOWN_DIR;
+        if is_hidden && options.skip_hidden {
+            continue;
+        }
+
+        if !path.is_dir() {
+            visit(&path, false);
+            continue;
+        }
+
+        let Ok(canonical) = path.canonicalize() else {
+            continue;
+        };
+        // The route folder itself is the first ancestor at depth 0
+        let too_deep = options.max_depth.is_some_and(|max| ancestors.len() > max);
+        if too_deep || ancestors.contains(&canonical) || !visit(&path, true) {
+            continue;
+        }
+
+        ancestors.push(canonical);
+        walk_dir(&path, options, ancestors, visit);
+        ancestors.pop();
+    }
+}
+
+// Walks `dir` starting with it as the only ancestor
+fn walk(dir: &Path, options: ScanOptions, visit: &mut dyn FnMut(&Path, bool) -> bool) {
+    let mut ancestors = dir.canonicalize().into_iter().collect();
+    walk_dir(dir, options, &mut ancestors, visit);
+}
+
+// Collect files with one of the given names recursively
+pub fn collect_files(
+    base_dir: &Path,
+    options: ScanOptions,
+    file_names: &[&str],
+) -> Vec<(PathBuf, PathBuf)> {
+    let mut files = Vec::new();
+    walk(base_dir, options, &mut |path, is_dir| {
+        if is_dir {
+            // Static assets may contain anything, including route.rs files
+            return path.file_name().unwrap_or_default() != PUBLIC_DIR;
+        }
+
+        if file_names
+            .iter()
+            .any(|name| path.file_name().unwrap_or_default() == *name)
+        {
+            if let Ok(rel_dir) = path.strip_prefix(base_dir) {
+                files.push((path.to_path_buf(), rel_dir.to_path_buf()));
+            }
+        }
+        false
+    });
+    files.sort_by_cached_key(|(_, rel_path)| specificity_key(rel_path));
+    files
+}
+
+// Orders a folder's own files before static segments before parameters
+// before catch-alls, then by name, keeping the registrations independent of
+// the file system's order
+fn specificity_key(rel_path: &Path) -> Vec<(u8, String)> {
+    let file_index = rel_path.components().count().saturating_sub(1);
+    rel_path
+        .components()
+        .enumerate()
+        .map(|(i, component)| {
+            let segment = component.as_os_str().to_string_lossy();
+            let rank = if segment.starts_with("[...") {
+                3
+            } else if segment.starts_with('[') {
+                2
+            } else {
+                u8::from(i < file_index)
+            };
+            (rank, segment.into_owned())
+        })
+        .collect()
+}
+
+// Reads the cargo feature names of all `feature.txt` files, keyed by their
+// folder
+fn collect_folder_features(
+    errors: &mut proc_macro2::TokenStream,
+    base_dir: &Path,
+    options: ScanOptions,
+) -> BTreeMap<PathBuf, String> {
+    let mut features = BTreeMap::new();
+
+    for (path, rel_path) in collect_files(base_dir, options, &[FEATURE_FILE]) {
+        let feature = fs::read_to_string(&path).unwrap_or_default();
+        let feature = feature.trim();
+        if feature.is_empty() || feature.contains(char::is_whitespace) {
+            let file = crate::generate::route_path_display(&rel_path);
+            let message = format!("{file} has to contain a single cargo feature name");
+            errors.extend(quote::quote! {
+                compile_error!(#message);
+            });
+            continue;
+        }
+
+        let dir = rel_path.parent().unwrap_or(Path::new("")).to_path_buf();
+        features.insert(dir, feature.to_string());
+    }
+
+    features
+}
+
+// Collect the regexes of the `regex.txt` files in path parameter folders like
+// `[sku]`, reporting invalid ones
+fn collect_param_regexes(
+    errors: &mut proc_macro2::TokenStream,
+    base_dir: &Path,
+    options: ScanOptions,
+) -> BTreeMap<PathBuf, String> {
+    let mut regexes = BTreeMap::new();
+
+    for (path, rel_path) in collect_files(base_dir, options, &[REGEX_FILE]) {
+        let file = crate::generate::route_path_display(&rel_path);
+        let dir = rel_path.parent().unwrap_or(Path::new("")).to_path_buf();
+        let regex = fs::read_to_string(&path).unwrap_or_default();
+        let regex = regex.trim();
+
+        // Catch-all parameters can't be constrained
+        let name = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix('[')?.strip_suffix(']'))
+            .filter(|param| !param.starts_with("..."))
+            .map(|param| param.split_once('@').map_or(param, |(name, _)| name));
+        let message = match name {
+            None => Some(format!(
+                "{file} has to be in a path parameter folder like `[sku]`"
+            )),
+            Some(name) if !is_regex_param_name(name) => Some(format!(
+                "Invalid name of regex path parameter `{name}` in {file}, expected letters, \
+                 digits & `_` not starting with a digit"
+            )),
+            Some(name) => regex::Regex::new(regex)
+                .err()
+                .map(|err| format!("Invalid regex for path parameter `{name}` in {file}: {err}")),
+        };
+        if let Some(message) = message {
+            errors.extend(quote::quote! {
+                compile_error!(#message);
+            });
+            continue;
+        }
+
+        regexes.insert(dir, regex.to_string());
+    }
+
+    regexes
+}
+
+// Collect public dirs recursively, without descending into them
+pub fn collect_public_dirs(base_dir: &Path, options: ScanOptions) -> Vec<(PathBuf, PathBuf)> {
+    let mut dirs = Vec::new();
+    walk(base_dir, options, &mut |path, is_dir| {
+        if !is_dir {
+            return false;
+        }
+
+        if path.file_name().unwrap_or_default() != PUBLIC_DIR {
+            return true;
+        }
+
+        if let Ok(rel_dir) = path.strip_prefix(base_dir) {
+            dirs.push((path.to_path_buf(), rel_dir.to_path_buf()));
+        }
+        false
+    });
+    dirs.sort_by_cached_key(|(_, rel_path)| specificity_key(rel_path));
+    dirs
+}
+
+pub struct FolderRouterItem {
+    item: syn::ItemStruct,
+}
+
+impl FolderRouterItem {
+    pub fn module_namespace(&self) -> syn::Path {
+        syn::parse_str(&format!(
+            "__folder_router__{}",
+            self.item
+                .ident
+                .to_string()
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .map(|c| c.to_ascii_lowercase())
+                .collect::<String>(),
+        ))
+        .unwrap()
+    }
+
+    pub fn struct_name(&self) -> syn::Ident {
+        self.item.ident.clone()
+    }
+
+    pub fn generics(&self) -> &syn::Generics {
+        &self.item.generics
+    }
+
+    /// Whether the struct has fields other than `PhantomData` markers
+    pub fn has_fields(&self) -> bool {
+        self.item.fields.iter().any(|field| match &field.ty {
+            Type::Path(type_path) => type_path
+                .path
+                .segments
+                .last()
+                .is_none_or(|segment| segment.ident != "PhantomData"),
+            _ => true,
+        })
+    }
+
+    /// The struct's attribute named `name`
+    pub fn attr(&self, name: &str) -> Option<&Attribute> {
+        self.item
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident(name))
+    }
+
+    /// Whether the struct derives `name`, e.g. `Debug`
+    pub fn derives(&self, name: &str) -> bool {
+        self.item.attrs.iter().any(|attr| {
+            let mut derived = false;
+            if attr.path().is_ident("derive") {
+                let _ = attr.parse_nested_meta(|meta| {
+                    derived |= meta.path.is_ident(name);
+                    Ok(())
+                });
+            }
+            derived
+        })
+    }
+}
+
+impl Parse for FolderRouterItem {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let item: syn::ItemStruct = input.parse()?;
+
+        Ok(Self {
+            item,
+        })
+    }
+}
+
+impl ToTokens for FolderRouterItem {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.item.to_tokens(tokens);
+    }
+}
+
+/// Layer settings from the `config.toml` files of a folder & its parents,
+/// with the closest file's setting winning
+#[derive(Debug, Clone, Default)]
+pub struct FolderConfig {
+    /// Request timeout in milliseconds
+    pub timeout: Option<u64>,
+    /// Maximum request body size in bytes
+    pub body_limit: Option<usize>,
+    /// Overrides whether the `compression` option applies
+    pub compression: Option<bool>,
+    /// Path of a `from_fn` middleware like `crate::auth::require_login`
+    pub guard: Option<String>,
+    /// Whether `GET` responses get an `ETag` & `If-None-Match` is answered
+    /// with `304 Not Modified`
+    pub etag: Option<bool>,
+}
+
+impl FolderConfig {
+    // Takes the settings of `other` over the ones of `self`
+    fn merge(&mut self, other: &Self) {
+        self.timeout = other.timeout.or(self.timeout);
+        self.body_limit = other.body_limit.or(self.body_limit);
+        self.compression = other.compression.or(self.compression);
+        self.guard = other.guard.clone().or(self.guard.take());
+        self.etag = other.etag.or(self.etag);
+    }
+}
+
+// Reads the `config.toml` of every folder, e.g. `timeout = "5s"`,
+// `body_limit = "10MB"`, `compression = true`,
+// `guard = "crate::auth::require_login"` or `etag = true`
+fn collect_folder_configs(
+    errors: &mut proc_macro2::TokenStream,
+    base_dir: &Path,
+    options: ScanOptions,
+) -> BTreeMap<PathBuf, FolderConfig> {
+    let mut configs = BTreeMap::new();
+
+    for (path, rel_path) in collect_files(base_dir, options, &[CONFIG_FILE]) {
+        let file = crate::generate::route_path_display(&rel_path);
+        let mut report = |message: String| {
+            let message = format!("{file}: {message}");
+            errors.extend(quote::quote! {
+                compile_error!(#message);
+            });
+        };
+        let table = match fs::read_to_string(&path)
+            .unwrap_or_default()
+            .parse::<toml::Table>()
+        {
+            Ok(table) => table,
+            Err(err) => {
+                report(err.message().to_string());
+                continue;
+            }
+        };
+
+        let mut config = FolderConfig::default();
+        for (key, value) in &table {
+            match key.as_str() {
+                "timeout" => match value.as_str().and_then(parse_duration) {
+                    Some(timeout) => config.timeout = Some(timeout),
+                    None => report("`timeout` has to be a duration like \"5s\"".to_string()),
+                },
+                "body_limit" => match value.as_str().and_then(parse_size) {
+                    Some(body_limit) => config.body_limit = Some(body_limit),
+                    None => report("`body_limit` has to be a size like \"10MB\"".to_string()),
+                },
+                "compression" => match value.as_bool() {
+                    Some(compression) => config.compression = Some(compression),
+                    None => report("`compression` has to be `true` or `false`".to_string()),
+                },
+                "guard" => match value
+                    .as_str()
+                    .filter(|guard| syn::parse_str::<syn::Path>(guard).is_ok())
+                {
+                    Some(guard) => config.guard = Some(guard.to_string()),
+                    None => report(
+                        "`guard` has to be the path of a middleware fn like \
+                         \"crate::auth::require_login\""
+                            .to_string(),
+                    ),
+                },
+                "etag" => match value.as_bool() {
+                    Some(etag) => config.etag = Some(etag),
+                    None => report("`etag` has to be `true` or `false`".to_string()),
+                },
+                _ => report(format!(
+                    "Unknown setting `{key}`, expected one of timeout, body_limit, compression, \
+                     guard, etag"
+                )),
+            }
+        }
+
+        let dir = rel_path.parent().unwrap_or(Path::new("")).to_path_buf();
+        configs.insert(dir, config);
+    }
+
+    configs
+}
+
+/// A redirect from the `redirects.toml` of the route folder
+#[derive(Debug, Clone)]
+pub struct Redirect {
+    /// Path which is redirected, e.g. `/blog/{slug}`
+    pub from:   String,
+    /// Path or URL redirected to, with the parameters of `from` filled in
+    pub to:     String,
+    pub status: u16,
+}
+
+// Reads the `redirects.toml` of the route folder, e.g.
+// `"/about-us" = "/about"` or `"/blog/{slug}" = { to = "/posts/{slug}", status
+// = 301 }`
+fn collect_redirects(errors: &mut proc_macro2::TokenStream, base_dir: &Path) -> Vec<Redirect> {
+    let Ok(content) = fs::read_to_string(base_dir.join(REDIRECTS_FILE)) else {
+        return Vec::new();
+    };
+    let mut report = |message: String| {
+        let message = format!("{REDIRECTS_FILE}: {message}");
+        errors.extend(quote::quote! {
+            compile_error!(#message);
+        });
+    };
+    let table = match content.parse::<toml::Table>() {
+        Ok(table) => table,
+        Err(err) => {
+            report(err.message().to_string());
+            return Vec::new();
+        }
+    };
+
+    let mut redirects = Vec::new();
+    for (from, value) in table {
+        let (to, status) = match &value {
+            toml::Value::String(to) => (Some(to.as_str()), Some(308)),
+            toml::Value::Table(redirect) => (
+                redirect.get("to").and_then(toml::Value::as_str),
+                redirect
+                    .get("status")
+                    .map_or(Some(308), toml::Value::as_integer),
+            ),
+            _ => (None, None),
+        };
+
+        match (to, status) {
+            _ if !from.starts_with('/') => report(format!("`{from}` has to start with a `/`")),
+            (Some(to), Some(status))
+                if REDIRECT_STATUSES
+                    .iter()
+                    .any(|code| i64::from(*code) == status) =>
+            {
+                redirects.push(Redirect {
+                    to: to.to_string(),
+                    status: u16::try_from(status).unwrap(),
+                    from,
+                });
+            }
+            (Some(_), _) => report(format!(
+                "`{from}` has an invalid status, expected one of 301, 302, 303, 307 or 308"
+            )),
+            (None, _) => report(format!(
+                "`{from}` has to map to a path or a table like `{{ to = \"/new\", status = 301 }}`"
+            )),
+        }
+    }
+
+    redirects
+}
+
+pub struct FolderRouterRoutes {
+    routes: Vec<(PathBuf, PathBuf)>,
+    route_cfgs: BTreeMap<PathBuf, proc_macro2::TokenStream>,
+    /// Cargo features of the folders containing a `feature.txt`
+    folder_features: BTreeMap<PathBuf, String>,
+    /// Regexes of the path parameter folders containing a `regex.txt`
+    param_regexes: BTreeMap<PathBuf, String>,
+    /// Settings of the folders containing a `config.toml`
+    folder_configs: BTreeMap<PathBuf, FolderConfig>,
+    folder_files: Vec<(PathBuf, PathBuf)>,
+    public_dirs: Vec<(PathBuf, PathBuf)>,
+    redirects: Vec<Redirect>,
+}
+
+impl FolderRouterRoutes {
+    pub fn parse_from_path(
+        errors: &mut proc_macro2::TokenStream,
+        path: &Path,
+        args: &FolderRouterArgs,
+    ) -> Self {
+        let options = args.scan;
+        let (routes, route_cfgs) = select_route_files(
+            &collect_files(path, options, ROUTE_FILES),
+            args.env.as_deref(),
+        );
+        let folder_files = collect_files(path, options, FOLDER_FILES);
+        let public_dirs = collect_public_dirs(path, options);
+        let folder_features = collect_folder_features(errors, path, options);
+        let param_regexes = collect_param_regexes(errors, path, options);
+        let folder_configs = collect_folder_configs(errors, path, options);
+        let redirects = collect_redirects(errors, path);
+        let path = path.to_str().unwrap();
+
+        let has_resources_or_pages = folder_files.iter().any(|(_, rel_path)| {
+            [
+                "resource.rs",
+                "page.rs",
+                "action.rs",
+                "fragment.rs",
+                "graphql.rs",
+            ]
+            .iter()
+            .any(|name| rel_path.ends_with(name))
+        });
+
+        if routes.is_empty() && !has_resources_or_pages && redirects.is_empty() {
+            errors.extend(quote::quote! {
+                compile_error!(concat!("No route.rs files found in the specified directory: '",
+                    #path,
+                    "'. Make sure the path is correct and contains route.rs files."
+                ));
+            });
+        }
+
+        Self {
+            routes,
+            route_cfgs,
+            folder_features,
+            param_regexes,
+            folder_configs,
+            folder_files,
+            public_dirs,
+            redirects,
+        }
+    }
+
+    /// The `#[cfg(...)]` predicate under which the route file at `rel_path`
+    /// is used, if it's an environment variant or in a feature gated folder
+    pub fn route_cfg(&self, rel_path: &Path) -> Option<proc_macro2::TokenStream> {
+        let predicates: Vec<_> = self
+            .route_cfgs
+            .get(rel_path)
+            .cloned()
+            .into_iter()
+            .chain(self.folder_cfg(rel_path))
+            .collect();
+
+        match predicates.as_slice() {
+            [] => None,
+            [predicate] => Some(predicate.clone()),
+            predicates => Some(quote::quote! { all(#(#predicates),*) }),
+        }
+    }
+
+    /// The `#[cfg(...)]` predicate of the `feature.txt` files in the folders
+    /// containing `rel_path`
+    pub fn folder_cfg(&self, rel_path: &Path) -> Option<proc_macro2::TokenStream> {
+        let mut features: Vec<&String> = rel_path
+            .ancestors()
+            .skip(1)
+            .filter_map(|dir| self.folder_features.get(dir))
+            .collect();
+        features.reverse();
+
+        match features.as_slice() {
+            [] => None,
+            [feature] => Some(quote::quote! { feature = #feature }),
+            features => Some(quote::quote! { all(#(feature = #features),*) }),
+        }
+    }
+
+    /// The regex of the `regex.txt` in the path parameter folder `dir`, e.g.
+    /// `items/[sku]`
+    pub fn param_regex(&self, dir: &Path) -> Option<&str> {
+        self.param_regexes.get(dir).map(String::as_str)
+    }
+
+    /// The files in `base_dir` the macro reads besides the route modules, so
+    /// rustc doesn't track them on its own
+    pub fn data_files(&self, base_dir: &Path) -> Vec<PathBuf> {
+        let folder_files = self
+            .folder_features
+            .keys()
+            .map(|dir| dir.join(FEATURE_FILE))
+            .chain(self.param_regexes.keys().map(|dir| dir.join(REGEX_FILE)))
+            .chain(self.folder_configs.keys().map(|dir| dir.join(CONFIG_FILE)));
+
+        [PathBuf::from(OPTIONS_FILE), PathBuf::from(REDIRECTS_FILE)]
+            .into_iter()
+            .filter(|file| base_dir.join(file).is_file())
+            .chain(folder_files)
+            .map(|file| base_dir.join(file))
+            .collect()
+    }
+
+    /// The settings of the `config.toml` files in the folders containing
+    /// `rel_path`
+    pub fn folder_config(&self, rel_path: &Path) -> FolderConfig {
+        let mut configs: Vec<&FolderConfig> = rel_path
+            .ancestors()
+            .skip(1)
+            .filter_map(|dir| self.folder_configs.get(dir))
+            .collect();
+        configs.reverse();
+
+        let mut config = FolderConfig::default();
+        for folder_config in configs {
+            config.merge(folder_config);
+        }
+        config
+    }
+
+    /// All absolute & relative paths of public dirs, e.g. `assets/public`
+    pub fn public_dirs(&self) -> impl Iterator<Item = (&Path, &Path)> {
+        self.public_dirs
+            .iter()
+            .map(|(path, rel_path)| (path.as_path(), rel_path.as_path()))
+    }
+
+    /// The redirects of the route folder's `redirects.toml`
+    pub fn redirects(&self) -> &[Redirect] {
+        &self.redirects
+    }
+
+    /// All relative paths of folder files, e.g. `users/method_not_allowed.rs`
+    pub fn folder_files(&self) -> impl Iterator<Item = &Path> {
+        self.folder_files
+            .iter()
+            .map(|(_, rel_path)| rel_path.as_path())
+    }
+
+    /// The folders containing a `state.rs`, whose subtrees are routed with
+    /// their own state
+    pub fn state_dirs(&self) -> Vec<&Path> {
+        self.folder_files()
+            .filter(|file| file.file_name().unwrap_or_default() == "state.rs")
+            .map(|file| file.parent().unwrap_or(Path::new("")))
+            .collect()
+    }
+
+    /// The folders of `base_dir` containing a `state.rs` with a `state` fn,
+    /// whose subtrees are routed by a router of their own
+    pub fn routed_state_dirs(&self, base_dir: &Path) -> Vec<&Path> {
+        self.state_dirs()
+            .into_iter()
+            .filter(|dir| {
+                subtree_state_source(&base_dir.join(dir).join("state.rs")) == StateSource::Fn
+            })
+            .collect()
+    }
+
+    /// The routes outside the subtrees routed with their own state
+    pub fn without_subtrees(&self, base_dir: &Path) -> Self {
+        let state_dirs: Vec<PathBuf> = self
+            .routed_state_dirs(base_dir)
+            .into_iter()
+            .map(Path::to_path_buf)
+            .collect();
+        let mut routes =
+            self.filtered(|rel_path| !state_dirs.iter().any(|dir| rel_path.starts_with(dir)));
+        routes.redirects.clone_from(&self.redirects);
+        routes
+    }
+
+    /// The routes in the subtree of the `state.rs` in `dir`
+    pub fn subtree(&self, dir: &Path) -> Self {
+        self.filtered(|rel_path| rel_path.starts_with(dir))
+    }
+
+    // The routes, public dirs & registering folder files `keep` returns
+    // `true` for, with all other folder files still applying their layers
+    fn filtered(&self, keep: impl Fn(&Path) -> bool) -> Self {
+        let keep_entry = |(_, rel_path): &&(PathBuf, PathBuf)| keep(rel_path);
+        let keep_file = |(_, rel_path): &&(PathBuf, PathBuf)| {
+            let name = rel_path.file_name().unwrap_or_default();
+            !REGISTERING_FILES.iter().any(|file| name == *file) || keep(rel_path)
+        };
+
+        Self {
+            routes: self.routes.iter().filter(keep_entry).cloned().collect(),
+            route_cfgs: self.route_cfgs.clone(),
+            folder_features: self.folder_features.clone(),
+            param_regexes: self.param_regexes.clone(),
+            folder_configs: self.folder_configs.clone(),
+            folder_files: self
+                .folder_files
+                .iter()
+                .filter(keep_file)
+                .cloned()
+                .collect(),
+            public_dirs: self
+                .public_dirs
+                .iter()
+                .filter(keep_entry)
+                .cloned()
+                .collect(),
+            redirects: Vec::new(),
+        }
+    }
+
+    /// Finds all folder files with the given name applying to the route at
+    /// `rel_path`, ordered from the root folder to the route's own folder
+    pub fn folder_files_for(&self, rel_path: &Path, file_name: &str) -> Vec<&Path> {
+        let mut files: Vec<&Path> = rel_path
+            .ancestors()
+            .skip(1)
+            .map(|dir| dir.join(file_name))
+            .filter_map(|candidate| self.folder_files().find(|file| *file == candidate))
+            .collect();
+        files.reverse();
+        files
+    }
+
+    /// Finds the folder file with the given name closest to the route at
+    /// `rel_path`, searching its own folder first and then its parents
+    pub fn nearest_folder_file(&self, rel_path: &Path, file_name: &str) -> Option<&Path> {
+        self.folder_files_for(rel_path, file_name).pop()
+    }
+}
+
+/// Picks the route file of every folder for `env`, or, without an `env`
+/// option, selects `route.dev.rs` in debug and `route.prod.rs` in release
+/// builds using `cfg(debug_assertions)`
+fn select_route_files(
+    files: &[(PathBuf, PathBuf)],
+    env: Option<&str>,
+) -> (
+    Vec<(PathBuf, PathBuf)>,
+    BTreeMap<PathBuf, proc_macro2::TokenStream>,
+) {
+    let mut routes = Vec::new();
+    let mut route_cfgs = BTreeMap::new();
+
+    for (path, rel_path) in files {
+        let dir = rel_path.parent().unwrap_or(Path::new(""));
+        let exists = |name: &str| files.iter().any(|(_, file)| *file == dir.join(name));
+        let file_name = rel_path.file_name().unwrap_or_default().to_string_lossy();
+
+        let cfg = match env {
+            Some(env) if file_name == "route.rs" => {
+                if exists(&format!("route.{env}.rs")) {
+                    continue;
+                }
+                None
+            }
+            Some(env) => {
+                if file_name != format!("route.{env}.rs") {
+                    continue;
+                }
+                None
+            }
+            None => match (
+                file_name.as_ref(),
+                exists("route.dev.rs"),
+                exists("route.prod.rs"),
+            ) {
+                ("route.dev.rs", ..) | ("route.rs", false, true) => {
+                    Some(quote::quote! { debug_assertions })
+                }
+                ("route.prod.rs", ..) | ("route.rs", true, false) => {
+                    Some(quote::quote! { not(debug_assertions) })
+                }
+                // Both variants replace route.rs
+                ("route.rs", true, true) => continue,
+                _ => None,
+            },
+        };
+
+        if let Some(cfg) = cfg {
+            route_cfgs.insert(rel_path.clone(), cfg);
+        }
+        routes.push((path.clone(), rel_path.clone()));
+    }
+
+    (routes, route_cfgs)
+}
+
+/// Borrows the absolute & relative path of every selected route file
+fn route_paths(route: &(PathBuf, PathBuf)) -> (&Path, &Path) {
+    (route.0.as_path(), route.1.as_path())
+}
+
+impl<'a> IntoIterator for &'a FolderRouterRoutes {
+    type IntoIter = std::iter::Map<
+        std::slice::Iter<'a, (PathBuf, PathBuf)>,
+        fn(&'a (PathBuf, PathBuf)) -> (&'a Path, &'a Path),
+    >;
+    type Item = (&'a Path, &'a Path);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.routes.iter().map(route_paths)
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{generate, parse};
+
+/// What kind of folder file a route is registered from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteKind {
+    /// A `route.rs` file or one of its environment variants
+    Route,
+    /// A `resource.rs` file
+    Resource,
+    /// A `page.rs` file
+    Page,
+    /// An `action.rs` file
+    Action,
+    /// A function of a `fragment.rs` file
+    Fragment,
+    /// A `graphql.rs` file
+    Graphql,
+    /// A `service.rs` file
+    Service,
+    /// A `router.rs` file
+    Router,
+    /// A `mount.rs` file
+    Mount,
+    /// A `public` folder
+    Public,
+    /// An entry of the `redirects.toml`
+    Redirect,
+}
+
+/// A route as it's registered by `#[folder_router(...)]`
+#[derive(Debug, Clone)]
+pub struct RouteEntry {
+    pub path:     String,
+    /// Lowercase method names, `None` if the route accepts any method
+    pub methods:  Option<Vec<&'static str>>,
+    /// Relative path of the file the route is registered from
+    pub rel_path: PathBuf,
+    pub kind:     RouteKind,
+}
+
+/// All routes of `routes` found in `base_dir`, sorted by path
+pub fn route_table(
+    args: &parse::FolderRouterArgs,
+    base_dir: &Path,
+    routes: &parse::FolderRouterRoutes,
+) -> Vec<RouteEntry> {
+    let mut entries = Vec::new();
+
+    for (route_path, rel_path) in routes {
+        let handlers = parse::handlers_for_route(route_path);
+        let (axum_path, _) = generate::path_to_module_path(args, rel_path);
+
+        entries.push(RouteEntry {
+            path:     handlers.route_path.clone().unwrap_or(axum_path),
+            methods:  (!handlers.method_router).then(|| handlers.methods.clone()),
+            rel_path: rel_path.to_path_buf(),
+            kind:     RouteKind::Route,
+        });
+    }
+
+    for file in routes.folder_files() {
+        let dir = file.parent().unwrap_or(Path::new(""));
+
+        match file.file_name().unwrap_or_default().to_str() {
+            Some("resource.rs") => {
+                let actions = parse::actions_for_resource(&base_dir.join(file));
+                for (path, action_methods) in generate::resource_routes(args, dir, &actions) {
+                    if !action_methods.is_empty() {
+                        entries.push(RouteEntry {
+                            path,
+                            methods: Some(action_methods.into_iter().map(|(_, m)| m).collect()),
+                            rel_path: file.to_path_buf(),
+                            kind: RouteKind::Resource,
+                        });
+                    }
+                }
+            }
+            Some("page.rs") => entries.push(RouteEntry {
+                path:     generate::path_to_module_path(args, dir).0,
+                methods:  Some(vec!["get"]),
+                rel_path: file.to_path_buf(),
+                kind:     RouteKind::Page,
+            }),
+            Some("action.rs") => entries.push(RouteEntry {
+                path:     generate::path_to_module_path(args, dir).0,
+                methods:  Some(vec!["post"]),
+                rel_path: file.to_path_buf(),
+                kind:     RouteKind::Action,
+            }),
+            Some("fragment.rs") => {
+                let fragments = parse::async_fns_for_file(&base_dir.join(file));
+                for (path, _) in generate::fragment_routes(args, dir, &fragments) {
+                    entries.push(RouteEntry {
+                        path,
+                        methods: Some(vec!["get"]),
+                        rel_path: file.to_path_buf(),
+                        kind: RouteKind::Fragment,
+                    });
+                }
+            }
+            Some("graphql.rs") => {
+                let fns = parse::async_fns_for_file(&base_dir.join(file));
+                let methods = generate::graphql_methods(&fns);
+                if !methods.is_empty() {
+                    entries.push(RouteEntry {
+                        path:     generate::path_to_module_path(args, dir).0,
+                        methods:  Some(methods.into_iter().map(|(_, m)| m).collect()),
+                        rel_path: file.to_path_buf(),
+                        kind:     RouteKind::Graphql,
+                    });
+                }
+            }
+            Some(name @ ("router.rs" | "mount.rs" | "service.rs")) => entries.push(RouteEntry {
+                path:     generate::path_to_module_path(args, dir).0,
+                methods:  None,
+                rel_path: file.to_path_buf(),
+                kind:     match name {
+                    "router.rs" => RouteKind::Router,
+                    "mount.rs" => RouteKind::Mount,
+                    _ => RouteKind::Service,
+                },
+            }),
+            _ => {}
+        }
+    }
+
+    for (_, rel_path) in routes.public_dirs() {
+        entries.push(RouteEntry {
+            path:     generate::path_to_module_path(args, rel_path).0,
+            methods:  Some(vec!["get"]),
+            rel_path: rel_path.to_path_buf(),
+            kind:     RouteKind::Public,
+        });
+    }
+
+    for redirect in routes.redirects() {
+        entries.push(RouteEntry {
+            path:     generate::redirect_path(args, &redirect.from),
+            methods:  None,
+            rel_path: PathBuf::from(parse::REDIRECTS_FILE),
+            kind:     RouteKind::Redirect,
+        });
+    }
+
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    entries
+}
+
+impl parse::FolderRouterRoutes {
+    /// The `#[cfg(...)]` predicate under which `entry` is registered
+    pub fn entry_cfg(&self, entry: &RouteEntry) -> Option<proc_macro2::TokenStream> {
+        if entry.kind == RouteKind::Route {
+            self.route_cfg(&entry.rel_path)
+        } else {
+            self.folder_cfg(&entry.rel_path)
+        }
+    }
+}
+
+impl RouteEntry {
+    /// The metadata consts of the route's file, empty unless it's a
+    /// `route.rs`
+    pub fn metadata(&self, base_dir: &Path) -> parse::RouteMetadata {
+        if self.kind == RouteKind::Route {
+            parse::handlers_for_route(&base_dir.join(&self.rel_path))
+                .metadata
+                .clone()
+        } else {
+            parse::RouteMetadata::default()
+        }
+    }
+
+    /// The methods of the route deprecated by a `DEPRECATED` const or
+    /// `#[deprecated]` handlers
+    pub fn deprecated_methods(&self, base_dir: &Path) -> Vec<&'static str> {
+        if self.kind != RouteKind::Route {
+            return Vec::new();
+        }
+
+        let handlers = parse::handlers_for_route(&base_dir.join(&self.rel_path));
+        handlers
+            .methods
+            .iter()
+            .copied()
+            .filter(|method| {
+                handlers.metadata.deprecated
+                    || handlers
+                        .attrs
+                        .get(method)
+                        .is_some_and(|attrs| attrs.deprecated)
+            })
+            .collect()
+    }
+
+    /// The route's file for display, with a trailing `/` for public folders
+    pub fn file(&self) -> String {
+        let mut file = generate::route_path_display(&self.rel_path);
+        if self.kind == RouteKind::Public {
+            file.push('/');
+        }
+        file
+    }
+}
+
+/// Every method & route pair, with `*` for routes accepting any method,
+/// skipping routes without handlers
+pub fn route_methods(entries: &[RouteEntry]) -> Vec<(String, &RouteEntry)> {
+    let mut route_methods: Vec<(String, &RouteEntry)> = Vec::new();
+
+    for entry in entries {
+        let Some(methods) = &entry.methods else {
+            route_methods.push(("*".to_string(), entry));
+            continue;
+        };
+
+        for method in methods {
+            let method = method.to_uppercase();
+            if !route_methods
+                .iter()
+                .any(|(m, e)| *m == method && e.path == entry.path)
+            {
+                route_methods.push((method, entry));
+            }
+        }
+    }
+
+    route_methods
+}
+
+/// One `"GET /users"` line per method & route
+pub fn route_lines(entries: &[RouteEntry]) -> Vec<String> {
+    route_methods(entries)
+        .into_iter()
+        .map(|(method, entry)| format!("{method} {}", entry.path))
+        .collect()
+}
+
+/// One `GET     /users` line per method & route, with the paths aligned
+pub fn render_routes(entries: &[RouteEntry]) -> String {
+    let route_methods = route_methods(entries);
+    let method_width = route_methods
+        .iter()
+        .map(|(method, _)| method.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut rendered = String::new();
+    for (method, entry) in &route_methods {
+        writeln!(rendered, "{method:method_width$}  {}", entry.path).unwrap();
+    }
+    rendered
+}
@@ -0,0 +1,62 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use axum_folder_router_core::parse::handlers_for_route;
+
+fn route_file(name: &str, content: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("axum-folder-router-core-{name}"));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("route.rs");
+    fs::write(&file, content).unwrap();
+    file
+}
+
+// Rewrites `file` keeping its modification time
+fn write_unstamped(file: &Path, content: &str) {
+    let modified = fs::metadata(file).unwrap().modified().unwrap();
+    fs::write(file, content).unwrap();
+    fs::File::options()
+        .append(true)
+        .open(file)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+}
+
+#[test]
+fn unchanged_files_are_not_parsed_again() {
+    let file = route_file("cache-hit", "pub async fn get() {}");
+    assert_eq!(handlers_for_route(&file).methods, ["get"]);
+
+    // Same size & modification time, so the cached handlers are returned
+    write_unstamped(&file, "pub async fn put() {}");
+    assert_eq!(handlers_for_route(&file).methods, ["get"]);
+}
+
+#[test]
+fn files_changing_size_are_parsed_again() {
+    let file = route_file("cache-size", "pub async fn get() {}");
+    assert_eq!(handlers_for_route(&file).methods, ["get"]);
+
+    write_unstamped(&file, "pub async fn post() {}");
+    assert_eq!(handlers_for_route(&file).methods, ["post"]);
+}
+
+#[test]
+fn modified_files_are_parsed_again() {
+    let file = route_file("cache-modified", "pub async fn get() {}");
+    assert_eq!(handlers_for_route(&file).methods, ["get"]);
+
+    fs::write(&file, "pub async fn put() {}").unwrap();
+    fs::File::options()
+        .append(true)
+        .open(&file)
+        .unwrap()
+        .set_modified(SystemTime::UNIX_EPOCH)
+        .unwrap();
+    assert_eq!(handlers_for_route(&file).methods, ["put"]);
+}
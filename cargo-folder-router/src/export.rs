@@ -0,0 +1,585 @@
+use std::{fmt::Write, fs, io, path::Path};
+
+use axum_folder_router_core::{generate, parse, table};
+
+use crate::list;
+
+/// File formats routes can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// `.http` request file of the VS Code REST Client & the `JetBrains` HTTP
+    /// Client
+    Http,
+    /// Postman collection, also importable by Insomnia
+    Postman,
+    /// TypeScript module with a URL builder per route
+    Ts,
+    /// `OpenAPI` 3.1 document, describing operations with the `route.md`
+    /// next to their route file
+    Openapi,
+}
+
+impl Format {
+    /// The default file name of an export
+    #[must_use]
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Format::Http => "requests.http",
+            Format::Postman => "postman_collection.json",
+            Format::Ts => "routes.ts",
+            Format::Openapi => "openapi.json",
+        }
+    }
+}
+
+/// Scans `dir` like `#[folder_router(dir, State, options)]` & renders its
+/// routes in the given `format`, with requests sent to `base_url`
+///
+/// # Errors
+///
+/// Fails for invalid `options` or if `dir` isn't a directory.
+pub fn export_routes(
+    dir: &Path,
+    options: &str,
+    format: Format,
+    base_url: &str,
+) -> io::Result<String> {
+    let entries = list::scan(dir, options)?;
+
+    Ok(match format {
+        Format::Http => render_http(&entries, base_url),
+        Format::Postman => render_postman(&entries, &collection_name(dir), base_url),
+        Format::Ts => render_ts(&entries),
+        Format::Openapi => render_openapi(dir, &entries, &collection_name(dir), base_url),
+    })
+}
+
+// A path parameter like `{id}`, `{:id}`, `:id`, `{*path}` or `*path`
+fn param_name(segment: &str) -> Option<&str> {
+    let name = segment.trim_matches(['{', '}', ':', '*']);
+    (name != segment).then_some(name)
+}
+
+// Replaces the path parameters of `path` with `render(name)`
+fn render_path(path: &str, render: impl Fn(&str) -> String) -> String {
+    path.split('/')
+        .map(|segment| param_name(segment).map_or_else(|| segment.to_string(), &render))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// Every request as `(method, route)`, sending a GET to routes accepting any
+// method
+fn requests(entries: &[table::RouteEntry]) -> Vec<(String, &table::RouteEntry)> {
+    let mut requests: Vec<(String, &table::RouteEntry)> = Vec::new();
+
+    for (method, entry) in table::route_methods(entries) {
+        let method = match method.as_str() {
+            "*" | "ANY" => "GET".to_string(),
+            _ => method,
+        };
+        if !requests
+            .iter()
+            .any(|(m, e)| *m == method && e.path == entry.path)
+        {
+            requests.push((method, entry));
+        }
+    }
+
+    requests
+}
+
+fn render_http(entries: &[table::RouteEntry], base_url: &str) -> String {
+    let requests = requests(entries);
+    let mut http = format!("@baseUrl = {base_url}\n");
+
+    // Placeholder values of all path parameters, shared by all requests
+    let mut params = Vec::new();
+    for (_, entry) in &requests {
+        for name in entry.path.split('/').filter_map(param_name) {
+            if !params.contains(&name) {
+                params.push(name);
+            }
+        }
+    }
+    for name in params {
+        writeln!(http, "@{name} = 1").unwrap();
+    }
+
+    for (method, entry) in &requests {
+        let path = &entry.path;
+        let url = render_path(path, |name| format!("{{{{{name}}}}}"));
+        write!(
+            http,
+            "\n### {method} {path}\n{method} {{{{baseUrl}}}}{url}\n"
+        )
+        .unwrap();
+    }
+
+    http
+}
+
+// The routes directory's name, e.g. `api` for `src/api`
+fn collection_name(dir: &Path) -> String {
+    dir.canonicalize()
+        .ok()
+        .and_then(|dir| {
+            dir.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| "api".to_string())
+}
+
+// Minimal JSON value, objects keep their field order
+enum Json {
+    Bool(bool),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+    // Object with keys only known at runtime, e.g. paths
+    Map(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn str(value: impl Into<String>) -> Self {
+        Json::Str(value.into())
+    }
+
+    fn render(&self, json: &mut String, indent: usize) {
+        let pad = "  ".repeat(indent + 1);
+        match self {
+            Json::Bool(value) => write!(json, "{value}").unwrap(),
+            Json::Str(value) => {
+                json.push('"');
+                for c in value.chars() {
+                    match c {
+                        '"' => json.push_str("\\\""),
+                        '\\' => json.push_str("\\\\"),
+                        '\n' => json.push_str("\\n"),
+                        c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+                        c => json.push(c),
+                    }
+                }
+                json.push('"');
+            }
+            Json::Array(items) if items.is_empty() => json.push_str("[]"),
+            Json::Array(items) => {
+                json.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    json.push_str(&pad);
+                    item.render(json, indent + 1);
+                    json.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                write!(json, "{}]", "  ".repeat(indent)).unwrap();
+            }
+            Json::Object(fields) => {
+                let fields: Vec<_> = fields.iter().map(|(key, value)| (*key, value)).collect();
+                Json::render_fields(&fields, json, indent);
+            }
+            Json::Map(fields) => {
+                let fields: Vec<_> = fields
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value))
+                    .collect();
+                Json::render_fields(&fields, json, indent);
+            }
+        }
+    }
+
+    fn render_fields(fields: &[(&str, &Json)], json: &mut String, indent: usize) {
+        if fields.is_empty() {
+            json.push_str("{}");
+            return;
+        }
+
+        let pad = "  ".repeat(indent + 1);
+        json.push_str("{\n");
+        for (i, (key, value)) in fields.iter().enumerate() {
+            json.push_str(&pad);
+            Json::str(*key).render(json, indent);
+            json.push_str(": ");
+            value.render(json, indent + 1);
+            json.push_str(if i + 1 < fields.len() { ",\n" } else { "\n" });
+        }
+        write!(json, "{}}}", "  ".repeat(indent)).unwrap();
+    }
+}
+
+// A Postman folder, e.g. `users` containing the requests of `users/route.rs`
+#[derive(Default)]
+struct Folder {
+    name:     String,
+    folders:  Vec<Folder>,
+    requests: Vec<Json>,
+}
+
+impl Folder {
+    fn insert(&mut self, dirs: &[String], request: Json) {
+        let Some((dir, dirs)) = dirs.split_first() else {
+            self.requests.push(request);
+            return;
+        };
+
+        let index = if let Some(index) = self.folders.iter().position(|f| f.name == *dir) {
+            index
+        } else {
+            self.folders.push(Folder {
+                name: dir.clone(),
+                ..Folder::default()
+            });
+            self.folders.len() - 1
+        };
+        self.folders[index].insert(dirs, request);
+    }
+
+    fn into_items(self) -> Json {
+        let folders = self.folders.into_iter().map(|folder| {
+            Json::Object(vec![
+                ("name", Json::str(folder.name.clone())),
+                ("item", folder.into_items()),
+            ])
+        });
+        Json::Array(folders.chain(self.requests).collect())
+    }
+}
+
+fn postman_request(method: &str, entry: &table::RouteEntry) -> Json {
+    let path = render_path(&entry.path, |name| format!(":{name}"));
+    let variables = entry
+        .path
+        .split('/')
+        .filter_map(param_name)
+        .map(|name| Json::Object(vec![("key", Json::str(name)), ("value", Json::str("1"))]))
+        .collect();
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(Json::str)
+        .collect();
+
+    Json::Object(vec![
+        ("name", Json::str(format!("{method} {}", entry.path))),
+        (
+            "request",
+            Json::Object(vec![
+                ("method", Json::str(method)),
+                (
+                    "url",
+                    Json::Object(vec![
+                        ("raw", Json::str(format!("{{{{baseUrl}}}}{path}"))),
+                        ("host", Json::Array(vec![Json::str("{{baseUrl}}")])),
+                        ("path", Json::Array(segments)),
+                        ("variable", Json::Array(variables)),
+                    ]),
+                ),
+            ]),
+        ),
+    ])
+}
+
+fn render_postman(entries: &[table::RouteEntry], name: &str, base_url: &str) -> String {
+    // Requests are grouped by the folders of their route files
+    let mut root = Folder::default();
+    for (method, entry) in requests(entries) {
+        let dirs: Vec<String> = entry
+            .rel_path
+            .parent()
+            .into_iter()
+            .flat_map(Path::components)
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        root.insert(&dirs, postman_request(&method, entry));
+    }
+
+    let collection = Json::Object(vec![
+        (
+            "info",
+            Json::Object(vec![
+                ("name", Json::str(name)),
+                (
+                    "schema",
+                    Json::str(
+                        "https://schema.getpostman.com/json/collection/v2.1.0/collection.json",
+                    ),
+                ),
+            ]),
+        ),
+        ("item", root.into_items()),
+        (
+            "variable",
+            Json::Array(vec![Json::Object(vec![
+                ("key", Json::str("baseUrl")),
+                ("value", Json::str(base_url)),
+            ])]),
+        ),
+    ]);
+
+    let mut json = String::new();
+    collection.render(&mut json, 0);
+    json.push('\n');
+    json
+}
+
+// The description of every method in a `route.md`, i.e. the text before the
+// first `## GET` style heading followed by the method's section
+fn route_docs(markdown: &str) -> Vec<(String, String)> {
+    let mut intro = String::new();
+    let mut sections: Vec<(String, String)> = Vec::new();
+    for line in markdown.lines() {
+        let method = line
+            .strip_prefix("## ")
+            .map(str::trim)
+            .filter(|heading| parse::HTTP_METHODS.contains(&heading.to_lowercase().as_str()));
+        if let Some(method) = method {
+            sections.push((method.to_lowercase(), String::new()));
+        } else if let Some((_, section)) = sections.last_mut() {
+            writeln!(section, "{line}").unwrap();
+        } else {
+            writeln!(intro, "{line}").unwrap();
+        }
+    }
+
+    let intro = intro.trim();
+    let mut docs = vec![(String::new(), intro.to_string())];
+    docs.extend(sections.into_iter().map(|(method, section)| {
+        let section = section.trim();
+        let description = match (intro.is_empty(), section.is_empty()) {
+            (true, _) => section.to_string(),
+            (false, true) => intro.to_string(),
+            (false, false) => format!("{intro}\n\n{section}"),
+        };
+        (method, description)
+    }));
+    docs
+}
+
+// JSON schema type of a typed path parameter, e.g. `integer` for `[id@u32]`
+fn schema_type(ty: Option<&str>) -> &'static str {
+    match ty.map(|ty| ty.rsplit("::").next().unwrap_or(ty)) {
+        Some(
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "isize",
+        ) => "integer",
+        Some("f32" | "f64") => "number",
+        Some("bool") => "boolean",
+        _ => "string",
+    }
+}
+
+fn openapi_operation(
+    method: &str,
+    entry: &table::RouteEntry,
+    metadata: &parse::RouteMetadata,
+    description: &str,
+    deprecated: bool,
+) -> Json {
+    let typed_params = generate::route_params(&entry.rel_path);
+    let parameters = entry
+        .path
+        .split('/')
+        .filter_map(param_name)
+        .map(|name| {
+            let ty = typed_params
+                .iter()
+                .find(|typed| typed.name == name)
+                .and_then(|typed| typed.ty.as_deref());
+            Json::Object(vec![
+                ("name", Json::str(name)),
+                ("in", Json::str("path")),
+                ("required", Json::Bool(true)),
+                (
+                    "schema",
+                    Json::Object(vec![("type", Json::str(schema_type(ty)))]),
+                ),
+            ])
+        })
+        .collect();
+
+    let mut operation = vec![("summary", Json::str(format!("{method} {}", entry.path)))];
+    // e.g. `get_users` for `pub const NAME: &str = "users";`
+    if let Some(name) = &metadata.name {
+        operation.push((
+            "operationId",
+            Json::str(format!("{}_{name}", method.to_lowercase())),
+        ));
+    }
+    if !metadata.tags.is_empty() {
+        let tags = metadata.tags.iter().map(Json::str).collect();
+        operation.push(("tags", Json::Array(tags)));
+    }
+    // A route.md takes precedence over the `DESCRIPTION` const
+    let description = if description.is_empty() {
+        metadata.description.as_deref().unwrap_or_default()
+    } else {
+        description
+    };
+    if !description.is_empty() {
+        operation.push(("description", Json::str(description)));
+    }
+    if deprecated {
+        operation.push(("deprecated", Json::Bool(true)));
+    }
+    operation.extend([
+        ("parameters", Json::Array(parameters)),
+        (
+            "responses",
+            Json::Object(vec![(
+                "default",
+                Json::Object(vec![("description", Json::str("Response"))]),
+            )]),
+        ),
+    ]);
+    Json::Object(operation)
+}
+
+fn render_openapi(dir: &Path, entries: &[table::RouteEntry], name: &str, base_url: &str) -> String {
+    let mut paths: Vec<(String, Json)> = Vec::new();
+    for (method, entry) in requests(entries) {
+        let markdown = fs::read_to_string(dir.join(&entry.rel_path).with_file_name("route.md"));
+        let docs = route_docs(&markdown.unwrap_or_default());
+        let method = method.to_lowercase();
+        let description = docs
+            .iter()
+            .find(|(doc_method, _)| *doc_method == method)
+            .unwrap_or(&docs[0]);
+        let metadata = entry.metadata(dir);
+        let deprecated = entry.deprecated_methods(dir).contains(&method.as_str());
+        let operation = openapi_operation(
+            &method.to_uppercase(),
+            entry,
+            &metadata,
+            &description.1,
+            deprecated,
+        );
+
+        // Parameters are always written as `{id}`, catch-alls included
+        let path = render_path(&entry.path, |name| format!("{{{name}}}"));
+        if let Some((_, Json::Map(operations))) = paths.iter_mut().find(|(p, _)| *p == path) {
+            operations.push((method, operation));
+        } else {
+            paths.push((path, Json::Map(vec![(method, operation)])));
+        }
+    }
+
+    let document = Json::Object(vec![
+        ("openapi", Json::str("3.1.0")),
+        (
+            "info",
+            Json::Object(vec![
+                ("title", Json::str(name)),
+                ("version", Json::str("1.0.0")),
+            ]),
+        ),
+        (
+            "servers",
+            Json::Array(vec![Json::Object(vec![("url", Json::str(base_url))])]),
+        ),
+        ("paths", Json::Map(paths)),
+    ]);
+
+    let mut json = String::new();
+    document.render(&mut json, 0);
+    json.push('\n');
+    json
+}
+
+// Route name in camelCase, e.g. `usersId` for `/users/{id}` & `root` for `/`
+fn route_name(path: &str) -> String {
+    let mut name = String::new();
+    for word in path
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            if name.is_empty() {
+                name.push(first.to_ascii_lowercase());
+            } else {
+                name.push(first.to_ascii_uppercase());
+            }
+            name.extend(chars);
+        }
+    }
+
+    if name.is_empty() {
+        "root".to_string()
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+// TypeScript type of a typed path parameter, e.g. `number` for `[id@u32]`
+fn ts_type(ty: Option<&str>) -> &'static str {
+    match ty.map(|ty| ty.rsplit("::").next().unwrap_or(ty)) {
+        Some(
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "isize" | "f32" | "f64",
+        ) => "number",
+        Some("bool") => "boolean",
+        _ => "string",
+    }
+}
+
+fn render_ts(entries: &[table::RouteEntry]) -> String {
+    let mut ts = String::from(
+        "// Generated by `cargo folder-router export --format ts`, don't edit by hand\n\nexport \
+         const routes = {\n",
+    );
+
+    let mut paths = Vec::new();
+    let mut names = Vec::new();
+    for (_, entry) in table::route_methods(entries) {
+        if paths.contains(&entry.path) {
+            continue;
+        }
+        paths.push(entry.path.clone());
+
+        let mut name = route_name(&entry.path);
+        if names.contains(&name) {
+            name = format!("{name}{}", names.len());
+        }
+        names.push(name.clone());
+
+        let typed_params = generate::route_params(&entry.rel_path);
+        let params: Vec<_> = entry
+            .path
+            .split('/')
+            .filter_map(param_name)
+            .map(|param| {
+                let ty = typed_params
+                    .iter()
+                    .find(|typed| typed.name == param)
+                    .and_then(|typed| typed.ty.as_deref());
+                format!("{param}: {}", ts_type(ty))
+            })
+            .collect();
+
+        // Catch-all parameters keep their slashes
+        let url = render_path(&entry.path, |param| {
+            let catch_all = entry.path.contains(&format!("*{param}"));
+            let encode = if catch_all {
+                "encodeURI"
+            } else {
+                "encodeURIComponent"
+            };
+            format!("${{{encode}(String(params.{param}))}}")
+        });
+        let url = if params.is_empty() {
+            format!("() => {:?}", entry.path)
+        } else {
+            format!("(params: {{ {} }}) => `{url}`", params.join("; "))
+        };
+
+        write!(
+            ts,
+            "  {name}: {{\n    path: {:?},\n    url: {url},\n  }},\n",
+            entry.path
+        )
+        .unwrap();
+    }
+
+    ts.push_str("} as const;\n");
+    ts
+}
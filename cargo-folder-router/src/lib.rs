@@ -0,0 +1,13 @@
+//! Library of the `cargo folder-router` subcommand, for tools creating or
+//! inspecting [axum-folder-router](https://docs.rs/axum-folder-router) routes.
+//!
+//! Routes are resolved by the same code as the `#[folder_router(...)]` macro,
+//! so scaffolded routes are always picked up as expected.
+#![forbid(unsafe_code)]
+
+pub mod export;
+pub mod list;
+mod scaffold;
+pub mod watch;
+
+pub use scaffold::{render_route, scaffold_route};
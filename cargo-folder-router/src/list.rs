@@ -0,0 +1,134 @@
+use std::{collections::BTreeSet, fmt::Write, io, path::Path};
+
+use axum_folder_router_core::{parse, table};
+
+/// A route as it would be registered by `#[folder_router(...)]`
+pub struct RouteRow {
+    pub path:    String,
+    pub methods: String,
+    /// From a `pub const NAME: &str` in the route file
+    pub name:    Option<String>,
+    pub file:    String,
+}
+
+/// Scans `dir` like `#[folder_router(dir, State, options)]` & returns its
+/// routes, sorted by path
+///
+/// # Errors
+///
+/// Fails for invalid `options` or if `dir` isn't a directory.
+pub fn route_table(dir: &Path, options: &str) -> io::Result<Vec<RouteRow>> {
+    let rows = scan(dir, options)?
+        .into_iter()
+        .map(|entry| {
+            let file = entry.file();
+            let name = entry.metadata(dir).name;
+            let deprecated = entry.deprecated_methods(dir);
+            // Files without handlers are listed, as they're the usual suspects
+            let methods = match entry.methods {
+                None => "*".to_string(),
+                Some(methods) if methods.is_empty() => "(none)".to_string(),
+                Some(methods) => format_methods(methods.into_iter(), &deprecated),
+            };
+            RouteRow {
+                path: entry.path,
+                methods,
+                name,
+                file,
+            }
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+// Scans `dir` like the macro with the given `options`
+pub(crate) fn scan(dir: &Path, options: &str) -> io::Result<Vec<table::RouteEntry>> {
+    let dir_lit = format!("{:?}", dir.to_string_lossy());
+    let macro_args = if options.is_empty() {
+        format!("{dir_lit}, State")
+    } else {
+        format!("{dir_lit}, State, {options}")
+    };
+    let args = syn::parse_str::<parse::FolderRouterArgs>(&macro_args)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err.to_string()))?;
+
+    // Relative to the working dir, unlike the macro's CARGO_MANIFEST_DIR
+    let base_dir = dir.to_path_buf();
+    if !base_dir.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} is not a directory", dir.display()),
+        ));
+    }
+
+    let mut errors = proc_macro2::TokenStream::new();
+    let routes = parse::FolderRouterRoutes::parse_from_path(&mut errors, &base_dir, &args);
+    Ok(table::route_table(&args, &base_dir, &routes))
+}
+
+// e.g. `GET (deprecated), POST`, without duplicates
+fn format_methods<'a>(methods: impl Iterator<Item = &'a str>, deprecated: &[&str]) -> String {
+    let mut seen = BTreeSet::new();
+    methods
+        .filter(|method| seen.insert(*method))
+        .map(|method| {
+            if deprecated.contains(&method) {
+                format!("{} (deprecated)", method.to_uppercase())
+            } else {
+                method.to_uppercase()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Renders the rows as a table with aligned columns, with a `NAME` column if
+/// any route is named
+#[must_use]
+pub fn render_table(rows: &[RouteRow]) -> String {
+    let path_width = rows
+        .iter()
+        .map(|row| row.path.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let methods_width = rows
+        .iter()
+        .map(|row| row.methods.len())
+        .max()
+        .unwrap_or(0)
+        .max(7);
+    let name_width = rows
+        .iter()
+        .filter_map(|row| row.name.as_ref())
+        .map(String::len)
+        .max()
+        .map(|width| width.max(4));
+
+    let name_column =
+        |name: &str| name_width.map_or(String::new(), |width| format!("{name:width$}  "));
+
+    let mut table = String::new();
+    writeln!(
+        table,
+        "{:path_width$}  {:methods_width$}  {}FILE",
+        "PATH",
+        "METHODS",
+        name_column("NAME")
+    )
+    .unwrap();
+    for row in rows {
+        writeln!(
+            table,
+            "{:path_width$}  {:methods_width$}  {}{}",
+            row.path,
+            row.methods,
+            name_column(row.name.as_deref().unwrap_or("-")),
+            row.file
+        )
+        .unwrap();
+    }
+
+    table
+}
@@ -0,0 +1,159 @@
+//! `cargo folder-router`, the companion cargo subcommand of
+//! [axum-folder-router](https://docs.rs/axum-folder-router).
+#![forbid(unsafe_code)]
+
+use std::{
+    env,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
+    time::Duration,
+};
+
+use cargo_folder_router::{export, list, scaffold_route, watch};
+use clap::{Parser, Subcommand};
+
+// Cargo passes the subcommand name as the first argument
+#[derive(Parser)]
+#[command(name = "cargo", bin_name = "cargo")]
+enum Cargo {
+    FolderRouter(FolderRouter),
+}
+
+#[derive(clap::Args)]
+#[command(version, about = "Companion cargo subcommand of axum-folder-router")]
+struct FolderRouter {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Creates a route folder with a templated route.rs
+    New {
+        /// Route folder relative to the routes directory, e.g. `users/[id]`
+        route:   PathBuf,
+        /// HTTP methods to generate handlers for
+        #[arg(long, value_delimiter = ',', default_value = "get")]
+        methods: Vec<String>,
+        /// Routes directory, as passed to `#[folder_router(...)]`
+        #[arg(long, default_value = "src/api")]
+        dir:     PathBuf,
+    },
+    /// Prints the routes the macro generates for a routes directory
+    List {
+        /// Routes directory, as passed to `#[folder_router(...)]`
+        #[arg(default_value = "src/api")]
+        dir:     PathBuf,
+        /// Options as passed to `#[folder_router(...)]`, e.g. `case = "kebab"`
+        #[arg(long, default_value = "")]
+        options: String,
+    },
+    /// Writes the routes of a routes directory to a file, e.g. `requests.http`
+    Export {
+        /// Routes directory, as passed to `#[folder_router(...)]`
+        #[arg(default_value = "src/api")]
+        dir: PathBuf,
+        /// Options as passed to `#[folder_router(...)]`, e.g. `case = "kebab"`
+        #[arg(long, default_value = "")]
+        options: String,
+        #[arg(long, value_enum, default_value = "http")]
+        format: export::Format,
+        /// Base URL requests are sent to
+        #[arg(long, default_value = "http://localhost:3000")]
+        base_url: String,
+        /// Output file, defaults to e.g. `requests.http` depending on the
+        /// format
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Rebuilds the crate when route files or folders are added or removed,
+    /// which cargo doesn't notice on stable
+    Watch {
+        /// Routes directory, as passed to `#[folder_router(...)]`
+        #[arg(default_value = "src/api")]
+        dir: PathBuf,
+        /// File touched on changes, e.g. the one invoking the macro
+        #[arg(long, default_value = "src/main.rs")]
+        touch: PathBuf,
+        /// Cargo subcommand run & waited for after touching, e.g. `check`
+        #[arg(long)]
+        run: Option<String>,
+        /// Milliseconds between the scans of the routes directory
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+}
+
+fn main() -> ExitCode {
+    let Cargo::FolderRouter(args) = Cargo::parse();
+
+    let result = match args.command {
+        Command::New {
+            route,
+            methods,
+            dir,
+        } => {
+            let methods: Vec<&str> = methods.iter().map(String::as_str).collect();
+            scaffold_route(&dir, &route, &methods)
+                .map(|file| println!("Created {}", file.display()))
+        }
+        Command::List {
+            dir,
+            options,
+        } => list::route_table(&dir, &options).map(|rows| print!("{}", list::render_table(&rows))),
+        Command::Export {
+            dir,
+            options,
+            format,
+            base_url,
+            output,
+        } => {
+            let output = output.unwrap_or_else(|| PathBuf::from(format.file_name()));
+            export::export_routes(&dir, &options, format, &base_url)
+                .and_then(|content| fs::write(&output, content))
+                .map(|()| println!("Wrote {}", output.display()))
+        }
+        Command::Watch {
+            dir,
+            touch,
+            run,
+            interval,
+        } => {
+            println!("Watching {}", dir.display());
+            watch::watch(&dir, Duration::from_millis(interval), |changes| {
+                rebuild(changes, &touch, run.as_deref())
+            })
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Reports the `changes` & touches `touch`, running `cargo <run>` if given
+fn rebuild(changes: &watch::Changes, touch: &Path, run: Option<&str>) -> io::Result<()> {
+    for path in &changes.added {
+        println!("Added {}", path.display());
+    }
+    for path in &changes.removed {
+        println!("Removed {}", path.display());
+    }
+    watch::touch(touch)?;
+
+    if let Some(run) = run {
+        // Cargo sets `CARGO` for its subcommands, a failing build is reported
+        // by it & fixed by the next change
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        process::Command::new(cargo)
+            .args(run.split_whitespace())
+            .status()?;
+    }
+    Ok(())
+}
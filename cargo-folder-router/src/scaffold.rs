@@ -0,0 +1,135 @@
+use std::{
+    fmt::Write,
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
+
+use axum_folder_router_core::{generate, parse};
+
+/// HTTP methods handlers can be generated for, in registration order
+const METHODS: &[&str] = &[
+    "any", "get", "post", "put", "delete", "patch", "head", "options", "trace", "connect",
+];
+
+/// Creates the folder of `route` below the routes directory `dir`, with a
+/// `route.rs` containing a handler for each of `methods`.
+///
+/// Existing `route.rs` files are never overwritten.
+///
+/// # Errors
+///
+/// Fails for invalid routes or methods (see [`render_route`]), if the
+/// `route.rs` already exists or it can't be written.
+pub fn scaffold_route(dir: &Path, route: &Path, methods: &[&str]) -> io::Result<PathBuf> {
+    let content = render_route(route, methods)?;
+
+    let folder = dir.join(route);
+    let file = folder.join("route.rs");
+    if file.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", file.display()),
+        ));
+    }
+
+    fs::create_dir_all(&folder)?;
+    fs::write(&file, content)?;
+
+    Ok(file)
+}
+
+/// Renders the `route.rs` of the route folder `route`, e.g. `users/[id@u32]`,
+/// with a handler for each of `methods`.
+///
+/// Path parameters are extracted with the types declared in their folder
+/// names, as checked by the macro.
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// let route_rs = cargo_folder_router::render_route(Path::new("users/[id@u32]"), &["get"])?;
+/// assert!(route_rs.contains("pub async fn get(Path(id): Path<u32>)"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Fails for unknown HTTP methods or folder names which can't be used as
+/// module names.
+#[allow(clippy::missing_panics_doc)]
+pub fn render_route(route: &Path, methods: &[&str]) -> io::Result<String> {
+    if let Some(method) = methods.iter().find(|m| !METHODS.contains(m)) {
+        return Err(invalid_input(format!("Unknown HTTP method `{method}`")));
+    }
+
+    // Keep the same order as the generated router
+    let methods: Vec<&str> = METHODS
+        .iter()
+        .copied()
+        .filter(|method| methods.contains(method))
+        .collect();
+    if methods.is_empty() {
+        return Err(invalid_input(
+            "At least one HTTP method is required".to_string(),
+        ));
+    }
+
+    // Resolve the route exactly like #[folder_router(...)] with its defaults
+    let args = syn::parse_str::<parse::FolderRouterArgs>("\"\", State").unwrap();
+    let (axum_path, mod_path) = generate::path_to_module_path(&args, &route.join("route.rs"));
+    if let Some(module) = mod_path
+        .iter()
+        .find(|module| syn::parse_str::<syn::Ident>(module).is_err())
+    {
+        return Err(invalid_input(format!(
+            "{} can't be used as a route folder, as `{module}` isn't a valid module name",
+            route.display()
+        )));
+    }
+
+    let params = generate::route_params(route);
+    let ty = |param: &generate::RouteParam| param.ty.clone().unwrap_or("String".to_string());
+
+    // e.g. `Path(id): Path<u32>` or `Path((id, sku)): Path<(u32, String)>`
+    let extractor = match params.as_slice() {
+        [] => String::new(),
+        [param] => format!("Path({}): Path<{}>", param.name, ty(param)),
+        params => {
+            let names: Vec<_> = params.iter().map(|p| p.name.clone()).collect();
+            let types: Vec<_> = params.iter().map(ty).collect();
+            format!("Path(({})): Path<({})>", names.join(", "), types.join(", "))
+        }
+    };
+
+    let mut content = if params.is_empty() {
+        "use axum::response::IntoResponse;\n".to_string()
+    } else {
+        "use axum::{extract::Path, response::IntoResponse};\n".to_string()
+    };
+
+    for method in methods {
+        // `{*path}` catch-alls are interpolated like any other parameter
+        let response = if params.is_empty() {
+            format!("\"{} {axum_path}\"", method.to_uppercase())
+        } else {
+            format!(
+                "format!(\"{} {}\")",
+                method.to_uppercase(),
+                axum_path.replace("{*", "{")
+            )
+        };
+
+        write!(
+            content,
+            "\npub async fn {method}({extractor}) -> impl IntoResponse {{\n    {response}\n}}\n"
+        )
+        .unwrap();
+    }
+
+    Ok(content)
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
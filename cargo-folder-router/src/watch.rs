@@ -0,0 +1,105 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// The files & folders added to or removed from a routes directory between
+/// two [`snapshot`]s, relative to it
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    pub added:   Vec<PathBuf>,
+    pub removed: Vec<PathBuf>,
+}
+
+impl Changes {
+    /// The changes from the `old` to the `new` snapshot
+    #[must_use]
+    pub fn between(old: &BTreeSet<PathBuf>, new: &BTreeSet<PathBuf>) -> Self {
+        Self {
+            added:   new.difference(old).cloned().collect(),
+            removed: old.difference(new).cloned().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// All files & folders below `dir`, relative to it.
+///
+/// Only their names are compared, as edits of existing route files already
+/// rebuild the crate. Like the macro, symlinked folders are followed unless
+/// they link to one of their ancestors.
+///
+/// # Errors
+///
+/// Fails if `dir` can't be read.
+pub fn snapshot(dir: &Path) -> io::Result<BTreeSet<PathBuf>> {
+    let mut paths = BTreeSet::new();
+    // Each folder with the canonical paths of itself & its ancestors
+    let mut dirs = vec![(dir.to_path_buf(), vec![dir.canonicalize()?])];
+
+    while let Some((current, ancestors)) = dirs.pop() {
+        for entry in fs::read_dir(&current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                if let Ok(canonical) = path.canonicalize() {
+                    if !ancestors.contains(&canonical) {
+                        let mut ancestors = ancestors.clone();
+                        ancestors.push(canonical);
+                        dirs.push((path.clone(), ancestors));
+                    }
+                }
+            }
+            paths.insert(path.strip_prefix(dir).unwrap_or(&path).to_path_buf());
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Sets the modification time of `file` to now, so cargo recompiles the
+/// crate it belongs to & the macro rescans its routes
+///
+/// # Errors
+///
+/// Fails if `file` doesn't exist or can't be written.
+pub fn touch(file: &Path) -> io::Result<()> {
+    fs::File::options()
+        .append(true)
+        .open(file)?
+        .set_modified(SystemTime::now())
+}
+
+/// Polls `dir` every `interval`, calling `on_change` whenever files or
+/// folders were added or removed.
+///
+/// Runs until `dir` can't be read or `on_change` fails.
+///
+/// # Errors
+///
+/// Fails if `dir` can't be read or with the error of `on_change`.
+pub fn watch(
+    dir: &Path,
+    interval: Duration,
+    mut on_change: impl FnMut(&Changes) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut last = snapshot(dir)?;
+
+    loop {
+        thread::sleep(interval);
+
+        let current = snapshot(dir)?;
+        let changes = Changes::between(&last, &current);
+        if !changes.is_empty() {
+            on_change(&changes)?;
+        }
+        last = current;
+    }
+}
@@ -0,0 +1,113 @@
+use std::{fs, process::Command};
+
+#[test]
+fn export_http_requests() {
+    let output_file = std::env::temp_dir().join("cargo-folder-router-export.http");
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .args(["folder-router", "export", "--format", "http", "--output"])
+        .arg(&output_file)
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../examples/advanced/api"
+        ))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let http = fs::read_to_string(&output_file).unwrap();
+    assert!(http.starts_with("@baseUrl = http://localhost:3000\n@id = 1\n"));
+    assert!(http.contains("\n### DELETE /users/{id}\nDELETE {{baseUrl}}/users/{{id}}\n"));
+}
+
+#[test]
+fn export_postman_collection() {
+    let output_file = std::env::temp_dir().join("cargo-folder-router-export.json");
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .args(["folder-router", "export", "--format", "postman", "--output"])
+        .arg(&output_file)
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../examples/versioned/api"
+        ))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json = fs::read_to_string(&output_file).unwrap();
+    assert!(json.starts_with("{\n  \"info\": {\n    \"name\": \"api\",\n"));
+    // Requests are grouped by folder, e.g. v2 > users
+    assert!(json.contains(
+        "\
+      \"name\": \"v2\",
+      \"item\": [
+        {
+          \"name\": \"user_settings\","
+    ));
+    assert!(json.contains("\"raw\": \"{{baseUrl}}/v2/users\""));
+}
+
+#[test]
+fn export_typescript_route_map() {
+    let output_file = std::env::temp_dir().join("cargo-folder-router-export.ts");
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .args(["folder-router", "export", "--format", "ts", "--output"])
+        .arg(&output_file)
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../examples/advanced/api"
+        ))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let ts = fs::read_to_string(&output_file).unwrap();
+    assert!(ts.contains(
+        r#"  ordersIdItemsSku: {
+    path: "/orders/{id}/items/{sku}",
+    url: (params: { id: number; sku: string }) => `/orders/${encodeURIComponent(String(params.id))}/items/${encodeURIComponent(String(params.sku))}`,
+  },
+"#
+    ));
+    assert!(ts.contains("    url: () => \"/users\",\n"));
+}
+
+#[test]
+fn export_openapi_document() {
+    let output_file = std::env::temp_dir().join("cargo-folder-router-openapi.json");
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .args(["folder-router", "export", "--format", "openapi", "--output"])
+        .arg(&output_file)
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../examples/advanced/api"
+        ))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json = fs::read_to_string(&output_file).unwrap();
+    assert!(json.starts_with("{\n  \"openapi\": \"3.1.0\",\n"));
+    // Described by the route.md next to the route.rs
+    assert!(json.contains(
+        "\
+    \"/orders/{id}\": {
+      \"get\": {
+        \"summary\": \"GET /orders/{id}\",
+        \"operationId\": \"get_order\",
+        \"tags\": [
+          \"orders\"
+        ],
+        \"description\": \"An order of the current customer.\\n\\nResponds with the order's \
+         number.\",
+        \"parameters\": [
+          {
+            \"name\": \"id\",
+            \"in\": \"path\",
+            \"required\": true,
+            \"schema\": {
+              \"type\": \"integer\"
+            }
+          }
+        ],"
+    ));
+}
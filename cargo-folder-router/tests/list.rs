@@ -0,0 +1,145 @@
+use std::process::Command;
+
+#[test]
+fn list_routes() {
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .args(["folder-router", "list", "--options", "case = \"kebab\""])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../examples/versioned/api"
+        ))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "\
+PATH               METHODS  FILE
+/v1/users          GET      v1/users/route.rs
+/v2/user-settings  GET      v2/user_settings/route.rs
+/v2/users          GET      v2/users/route.rs
+"
+    );
+}
+
+#[test]
+fn list_routes_up_to_max_depth() {
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .args(["folder-router", "list", "--options", "max_depth = 1"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../examples/advanced/api"
+        ))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "\
+PATH               METHODS                  FILE
+/                  GET, POST                route.rs
+/articles          GET, POST                articles/resource.rs
+/articles/{id}     GET, PUT, PATCH, DELETE  articles/resource.rs
+/files             GET, POST                files/route.rs
+/fixtures          POST                     fixtures/route.dev.rs
+/legacy/users.php  GET                      legacy/route.rs
+/metrics           *                        metrics/router.rs
+/ping              ANY, GET                 ping/route.rs
+/public            GET                      public/
+/readme            *                        readme/service.rs
+/search            *                        search/route.rs
+/status            GET                      status/route.rs
+/users             GET, POST                users/route.rs
+"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn list_routes_through_symlinks() {
+    use std::{fs, os::unix::fs::symlink};
+
+    let dir = std::env::temp_dir().join("cargo-folder-router-symlinks");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("users")).unwrap();
+    fs::write(
+        dir.join("users/route.rs"),
+        "pub async fn get() -> &'static str { \"users\" }\n",
+    )
+    .unwrap();
+    // A second mount of the same folder & a cycle back to the root
+    symlink(dir.join("users"), dir.join("members")).unwrap();
+    symlink(&dir, dir.join("users/loop")).unwrap();
+
+    let list = |options: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+            .args(["folder-router", "list", "--options", options])
+            .arg(&dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    assert_eq!(
+        list("follow_symlinks = true"),
+        "\
+PATH      METHODS  FILE
+/members  GET      members/route.rs
+/users    GET      users/route.rs
+"
+    );
+    assert_eq!(
+        list("follow_symlinks = false"),
+        "\
+PATH    METHODS  FILE
+/users  GET      users/route.rs
+"
+    );
+}
+
+#[test]
+fn list_routes_with_names() {
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .args(["folder-router", "list"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../examples/advanced/api/orders"
+        ))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "\
+PATH               METHODS  NAME   FILE
+/{id}              GET      order  [id@u32]/route.rs
+/{id}/items/{sku}  GET      -      [id@u32]/items/[sku]/route.rs
+"
+    );
+}
+
+#[test]
+fn list_deprecated_routes() {
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .args(["folder-router", "list"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../tests/expand/deprecated"
+        ))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "\
+PATH     METHODS                 FILE
+/        GET (deprecated), POST  route.rs
+/legacy  GET (deprecated)        legacy/route.rs
+"
+    );
+}
@@ -0,0 +1,70 @@
+use std::{fs, path::PathBuf, process::Command};
+
+fn routes_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("cargo-folder-router-{name}"));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn cargo_folder_router(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_cargo-folder-router"))
+        .arg("folder-router")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn new_route_with_params() {
+    let dir = routes_dir("new");
+    let output = cargo_folder_router(&[
+        "new",
+        "orders/[id@u32]/items/[sku]",
+        "--methods",
+        "put,get",
+        "--dir",
+        dir.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+
+    let content = fs::read_to_string(dir.join("orders/[id@u32]/items/[sku]/route.rs")).unwrap();
+    assert_eq!(
+        content,
+        r#"use axum::{extract::Path, response::IntoResponse};
+
+pub async fn get(Path((id, sku)): Path<(u32, String)>) -> impl IntoResponse {
+    format!("GET /orders/{id}/items/{sku}")
+}
+
+pub async fn put(Path((id, sku)): Path<(u32, String)>) -> impl IntoResponse {
+    format!("PUT /orders/{id}/items/{sku}")
+}
+"#
+    );
+
+    // Existing routes are never overwritten
+    let output = cargo_folder_router(&[
+        "new",
+        "orders/[id@u32]/items/[sku]",
+        "--dir",
+        dir.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn new_route_rejects_unknown_methods() {
+    let dir = routes_dir("unknown-method");
+    let output = cargo_folder_router(&[
+        "new",
+        "users",
+        "--methods",
+        "fetch",
+        "--dir",
+        dir.to_str().unwrap(),
+    ]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown HTTP method `fetch`"));
+    assert!(!dir.exists());
+}
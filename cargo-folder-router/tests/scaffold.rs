@@ -0,0 +1,30 @@
+use std::{fs, path::Path};
+
+use cargo_folder_router::{list::route_table, render_route, scaffold_route};
+
+#[test]
+fn scaffolded_routes_round_trip() {
+    let dir = std::env::temp_dir().join("cargo-folder-router-round-trip");
+    let _ = fs::remove_dir_all(&dir);
+
+    scaffold_route(&dir, Path::new("(admin)/files/[...path]"), &[
+        "get", "delete",
+    ])
+    .unwrap();
+
+    let rows = route_table(&dir, "").unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].path, "/files/{*path}");
+    assert_eq!(rows[0].methods, "GET, DELETE");
+    assert_eq!(rows[0].file, "(admin)/files/[...path]/route.rs");
+}
+
+#[test]
+fn invalid_module_names_are_rejected() {
+    let err = render_route(Path::new("2fa"), &["get"]).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "2fa can't be used as a route folder, as `2fa` isn't a valid module name"
+    );
+}
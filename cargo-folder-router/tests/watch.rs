@@ -0,0 +1,77 @@
+use std::{fs, path::PathBuf, time::SystemTime};
+
+use cargo_folder_router::watch::{snapshot, touch, Changes};
+
+fn routes_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("cargo-folder-router-{name}"));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn added_and_removed_routes() {
+    let dir = routes_dir("watch");
+    fs::create_dir_all(dir.join("users")).unwrap();
+    fs::write(dir.join("users/route.rs"), "").unwrap();
+    let before = snapshot(&dir).unwrap();
+
+    // Edits aren't changes of the directory
+    fs::write(dir.join("users/route.rs"), "pub async fn get() {}").unwrap();
+    assert!(Changes::between(&before, &snapshot(&dir).unwrap()).is_empty());
+
+    fs::create_dir_all(dir.join("orders/[id]")).unwrap();
+    fs::write(dir.join("orders/[id]/route.rs"), "").unwrap();
+    fs::remove_file(dir.join("users/route.rs")).unwrap();
+
+    assert_eq!(
+        Changes::between(&before, &snapshot(&dir).unwrap()),
+        Changes {
+            added:   vec![
+                PathBuf::from("orders"),
+                PathBuf::from("orders/[id]"),
+                PathBuf::from("orders/[id]/route.rs"),
+            ],
+            removed: vec![PathBuf::from("users/route.rs")],
+        }
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_cycles_are_not_followed() {
+    let dir = routes_dir("watch-symlink-cycle");
+    fs::create_dir_all(dir.join("users")).unwrap();
+    fs::write(dir.join("users/route.rs"), "").unwrap();
+    std::os::unix::fs::symlink(&dir, dir.join("users/loop")).unwrap();
+
+    assert_eq!(
+        snapshot(&dir).unwrap(),
+        [
+            PathBuf::from("users"),
+            PathBuf::from("users/loop"),
+            PathBuf::from("users/route.rs"),
+        ]
+        .into()
+    );
+}
+
+#[test]
+fn touch_updates_modification_time() {
+    let dir = routes_dir("touch");
+    let file = dir.join("main.rs");
+    fs::write(&file, "fn main() {}").unwrap();
+    fs::File::options()
+        .append(true)
+        .open(&file)
+        .unwrap()
+        .set_modified(SystemTime::UNIX_EPOCH)
+        .unwrap();
+
+    touch(&file).unwrap();
+
+    let modified = fs::metadata(&file).unwrap().modified().unwrap();
+    assert!(modified > SystemTime::UNIX_EPOCH);
+    assert_eq!(fs::read_to_string(&file).unwrap(), "fn main() {}");
+    assert!(touch(&dir.join("missing.rs")).is_err());
+}
@@ -0,0 +1,5 @@
+use axum::response::{Html, IntoResponse};
+
+pub async fn get() -> impl IntoResponse {
+    Html("<h1>Your Account</h1>").into_response()
+}
@@ -0,0 +1,21 @@
+use axum::{extract::Path, response::IntoResponse};
+
+pub async fn index() -> impl IntoResponse {
+    "All articles"
+}
+
+pub async fn create() -> impl IntoResponse {
+    "Article created"
+}
+
+pub async fn show(Path(id): Path<String>) -> impl IntoResponse {
+    format!("Article {id}")
+}
+
+pub async fn update(Path(id): Path<String>) -> impl IntoResponse {
+    format!("Article {id} updated")
+}
+
+pub async fn destroy(Path(id): Path<String>) -> impl IntoResponse {
+    format!("Article {id} deleted")
+}
@@ -4,6 +4,7 @@ pub async fn get() -> impl IntoResponse {
     Html("<h1>Hello World!</h1>").into_response()
 }
 
+#[axum_folder_router::body_limit("10MB")]
 pub async fn post() -> impl IntoResponse {
 "Posted successfully".into_response()
 }
@@ -0,0 +1,6 @@
+use axum::response::IntoResponse;
+
+// Only part of debug builds, see route.dev.rs / route.prod.rs variants
+pub async fn post() -> impl IntoResponse {
+    "Fixtures loaded"
+}
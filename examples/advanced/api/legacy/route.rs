@@ -0,0 +1,8 @@
+use axum::response::{IntoResponse, Redirect};
+
+// Overrides the folder based path "/legacy"
+pub const ROUTE_PATH: &str = "/legacy/users.php";
+
+pub async fn get() -> impl IntoResponse {
+    Redirect::permanent("/users")
+}
@@ -0,0 +1,6 @@
+use axum::{http::StatusCode, response::IntoResponse};
+
+// Used for unsupported methods of all routes in this folder & its subfolders
+pub async fn method_not_allowed() -> impl IntoResponse {
+    (StatusCode::METHOD_NOT_ALLOWED, "Method not allowed")
+}
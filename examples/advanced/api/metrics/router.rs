@@ -0,0 +1,11 @@
+use axum::{routing::get, Router};
+
+// Nested at "/metrics", generic so it works with any state
+pub fn router<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/", get(|| async { "requests_total 42" }))
+        .route("/health", get(|| async { "OK" }))
+}
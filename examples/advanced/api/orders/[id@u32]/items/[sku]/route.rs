@@ -0,0 +1,6 @@
+use axum::{extract::Path, response::IntoResponse};
+
+// SKUs not matching the folder's regex are answered with 404
+pub async fn get(Path((id, sku)): Path<(u32, String)>) -> impl IntoResponse {
+    format!("Item {sku} of order #{id}")
+}
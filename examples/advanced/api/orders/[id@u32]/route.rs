@@ -0,0 +1,9 @@
+use axum::{extract::Path, response::IntoResponse};
+
+pub const NAME: &str = "order";
+pub const TAGS: &[&str] = &["orders"];
+
+// Non numeric ids are rejected before reaching the handler
+pub async fn get(Path(id): Path<u32>) -> impl IntoResponse {
+    format!("Order #{id}")
+}
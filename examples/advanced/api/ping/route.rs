@@ -1,6 +1,7 @@
 use axum::response::Html;
 use axum::response::IntoResponse;
 
+#[axum_folder_router::timeout("5s")]
 pub async fn get() -> impl IntoResponse {
     Html("<h1>GET Pong!</h1>").into_response()
 }
@@ -0,0 +1,6 @@
+use tower_http::services::ServeFile;
+
+// Nested at "/readme", relative to the current working directory
+pub fn service() -> ServeFile {
+    ServeFile::new("README.md")
+}
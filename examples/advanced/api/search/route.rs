@@ -0,0 +1,11 @@
+use axum::routing::{on, MethodFilter, MethodRouter};
+
+// Used instead of scanning for HTTP method handlers
+pub fn method_router<S>() -> MethodRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    on(MethodFilter::GET.or(MethodFilter::POST), || async {
+        "Search results"
+    })
+}
@@ -0,0 +1,15 @@
+#![allow(non_snake_case)]
+
+use axum::{
+    response::{Html, IntoResponse},
+    Json,
+};
+
+// GET requests are answered by the variant matching their Accept header
+pub async fn get__json() -> impl IntoResponse {
+    Json(["ok"])
+}
+
+pub async fn get__html() -> impl IntoResponse {
+    Html("<p>ok</p>")
+}
@@ -3,3 +3,9 @@ use axum::{extract::Path, response::IntoResponse};
 pub async fn get(Path(id): Path<String>) -> impl IntoResponse {
     format!("User ID: {id}")
 }
+
+// Only registered in debug builds
+#[cfg(debug_assertions)]
+pub async fn delete(Path(id): Path<String>) -> impl IntoResponse {
+    format!("Deleted user {id}")
+}
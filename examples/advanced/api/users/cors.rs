@@ -0,0 +1,9 @@
+use axum::http::Method;
+use tower_http::cors::{Any, CorsLayer};
+
+// Applied to all routes in this folder & its subfolders
+pub fn cors() -> CorsLayer {
+    CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST])
+        .allow_origin(Any)
+}
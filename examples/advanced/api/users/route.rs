@@ -7,3 +7,9 @@ pub async fn get() -> impl IntoResponse {
 pub async fn post() -> impl IntoResponse {
     "Posted successfully".into_response()
 }
+
+// Not registered, e.g. while it's still being worked on
+#[axum_folder_router::skip]
+pub async fn delete() -> impl IntoResponse {
+    "Deleted successfully".into_response()
+}
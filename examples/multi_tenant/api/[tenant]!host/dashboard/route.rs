@@ -0,0 +1,7 @@
+use axum::{response::IntoResponse, Extension};
+
+use crate::Tenant;
+
+pub async fn get(Extension(Tenant(tenant)): Extension<Tenant>) -> impl IntoResponse {
+    format!("Dashboard of {tenant}")
+}
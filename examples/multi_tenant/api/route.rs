@@ -0,0 +1,5 @@
+use axum::response::{Html, IntoResponse};
+
+pub async fn get() -> impl IntoResponse {
+    Html("<h1>Pick a tenant, e.g. acme.localhost:3000/dashboard</h1>")
+}
@@ -0,0 +1,19 @@
+use axum::Router;
+use axum_folder_router::folder_router;
+
+#[derive(Clone, Debug)]
+struct AppState;
+
+// Routes below `[tenant]!host` are served at `{tenant}.localhost:3000`
+#[folder_router("examples/multi_tenant/api", AppState)]
+struct MyFolderRouter();
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let app: Router<()> = MyFolderRouter::into_router().with_state(AppState);
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+    println!("Listening on http://acme.localhost:3000/dashboard");
+    axum::serve(listener, app).await?;
+    Ok(())
+}
@@ -0,0 +1,5 @@
+use axum::response::IntoResponse;
+
+pub async fn get() -> impl IntoResponse {
+    "alice, bob"
+}
@@ -0,0 +1,5 @@
+use axum::{response::IntoResponse, Json};
+
+pub async fn get() -> impl IntoResponse {
+    Json(["alice", "bob"])
+}
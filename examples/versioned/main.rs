@@ -0,0 +1,31 @@
+use axum::Router;
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+// Responses of /v1 routes carry `Deprecation` & `Sunset` headers
+#[folder_router(
+    "./examples/versioned/api",
+    AppState,
+    deprecated = ["v1"],
+    sunset = { v1 = "Fri, 01 Jan 2027 00:00:00 GMT" }
+)]
+struct MyFolderRouter();
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // Create app state
+    let app_state = AppState;
+
+    // Use the init fn generated above
+    let folder_router: Router<AppState> = MyFolderRouter::into_router();
+
+    // Build the router and provide the state
+    let app: Router<()> = folder_router.with_state(app_state);
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+    println!("Listening on http://{}", listener.local_addr()?);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
@@ -24,6 +24,27 @@ caching, which may cause new ```route.rs``` files to be ignored.
   Enables use of unstable [`track_path`](https://doc.rust-lang.org/beta/unstable-book/library-features/track-path.html) feature to [avoid cache issues](#avoiding-cache-issues).
 * **debug** -
   Adds some debug logging
+* **rate-limit** -
+  Enables the ```#[rate_limit(...)]``` handler attribute
+* **trace** -
+  Enables the [```trace```](crate#options) option wrapping routes in a ```TraceLayer```
+* **livereload** -
+  Enables the [```livereload```](crate#options) option reloading pages of debug builds after a restart
+* **hot-reload** -
+  Enables the [```hot_reload```](crate#options) option serving ```subsecond``` hot patches in debug builds
+* **jwt** -
+  Enables the ```auth = jwt(...)``` option, see [JWT Authentication](crate#jwt-authentication)
+* **csrf** -
+  Enables the [```csrf```](crate#options) option, see [CSRF Protection](crate#csrf-protection)
+* **sessions** -
+  Makes ```(session)``` route groups require a session, see [Session Groups](crate#session-groups)
+* **axum-07** -
+  Generates axum 0.7 path syntax like ```:id``` & ```*path``` parameters for projects still using axum 0.7,
+  see [```param_syntax```](crate#options).
+  Routes, path & regex parameters, ```guard.rs``` files, redirects & ```merge_all!``` are tested against axum 0.7,
+  other options may use APIs only available in axum 0.8
+* **test-helpers** -
+  Generates a ```#[cfg(test)]``` module smoke testing every route, see [Generated Tests](crate#generated-tests)
 
 # Basic Usage
 
@@ -36,6 +57,84 @@ creates an Axum router based on the file structure:
 /*!
 ```
 
+If the state is available right away, ```into_router_with_state``` provides it in one call:
+```rust,no_run
+# use axum::Router;
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# struct AppState;
+# #[folder_router("./examples/simple/api", AppState)]
+# struct MyFolderRouter();
+let app: Router = MyFolderRouter::into_router_with_state(AppState);
+```
+
+If the struct has fields, it's converted into the state using ```From```
+and ```into_router``` takes ```self``` instead, returning a ```Router<()>```.
+A struct which is the state itself works without any conversion:
+```rust,no_run
+# use axum::Router;
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# struct Db;
+#[folder_router("./examples/simple/api", AppState)]
+#[derive(Clone)]
+struct AppState {
+    db: Db,
+}
+
+# fn main() {
+let app: Router = AppState { db: Db }.into_router();
+# }
+```
+
+The path & state can also be passed as named arguments, which reads better as options accumulate:
+```rust
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# struct AppState;
+#[folder_router(path = "./examples/simple/api", state = AppState, auto_head = true)]
+struct MyFolderRouter();
+# fn main() {}
+```
+
+[```#[derive(FolderRouter)]```](derive@FolderRouter) is an alternative to the attribute,
+e.g. for combining it with other derives, taking the path & state as named arguments:
+```rust
+# use axum_folder_router::FolderRouter;
+# #[derive(Clone)]
+# struct AppState;
+#[derive(FolderRouter)]
+#[folder_router(path = "./examples/simple/api", state = AppState)]
+struct MyFolderRouter();
+# fn main() {}
+```
+
+The attribute also works on an empty module, generating the route modules inside it
+& a free ```router()``` function instead of the struct's functions:
+```rust
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# struct AppState;
+#[folder_router("./examples/simple/api", AppState)]
+mod api {}
+
+# fn main() {
+let router: axum::Router<AppState> = api::router();
+# }
+```
+Items of the parent module, like the state, are imported into it.
+```mod api;``` works as well, but requires nightly's ```proc_macro_hygiene``` feature.
+Modules don't support the ```host``` option & get no [Generated Tests](crate#generated-tests).
+
+For quick ```main```s & examples, [```router!```](macro@router) expands to the router inline:
+```rust
+# #[derive(Clone)]
+# struct AppState;
+# fn main() {
+let router: axum::Router<AppState> = axum_folder_router::router!("./examples/simple/api", AppState);
+# }
+```
+
 ## Folder Structure
 
 The macro converts your file structure into routes:
@@ -82,6 +181,62 @@ The macro supports all standard HTTP methods as defined in RFC9110.
 And additionally
 - ```any```, which matches all methods
 
+Handlers have to be plain ```pub async fn```s without generics or a ```self``` receiver,
+which is reported otherwise. A ```route.rs``` without a single handler is reported
+at compile time, naming each of its functions & why it was rejected, e.g.
+```text
+No handlers found in users/route.rs: `get` isn't `pub`, `fetch` isn't named after an HTTP method
+```
+
+## Path Overrides
+
+A ```ROUTE_PATH``` const in a ```route.rs``` replaces the path derived from its folder,
+e.g. for legacy URLs which don't fit your folder structure:
+```rust
+pub const ROUTE_PATH: &str = "/legacy/users";
+```
+
+## Redirects
+
+A ```redirects.toml``` in the route folder maps old paths to the paths or URLs they redirect to,
+with a ```308 Permanent Redirect``` by default or the given ```status```, one of 301, 302, 303, 307 & 308.
+Path parameters of the old path are filled into the new one:
+```toml
+"/about-us" = "/about"
+"/blog/{slug}" = { to = "/posts/{slug}", status = 301 }
+"/docs/{*path}" = { to = "https://docs.example.com/{path}", status = 302 }
+```
+The parameters are percent-encoded again, e.g. ```/blog/a%20b``` redirects to ```/posts/a%20b```,
+with the slashes of catch-all parameters kept.
+Redirects answer requests of any method and are listed like routes, e.g. by ```cargo folder-router list```.
+
+## Route Metadata
+
+```NAME```, ```TAGS``` & ```DESCRIPTION``` consts in a ```route.rs``` describe the route to tools,
+e.g. ```cargo folder-router list``` shows its name & ```cargo folder-router export --format openapi```
+describes its operations with them:
+```rust
+pub const NAME: &str = "order";
+pub const TAGS: &[&str] = &["orders"];
+pub const DESCRIPTION: &str = "An order of the current customer.";
+```
+
+## Custom Method Routers
+
+For combinations the HTTP method functions can't express, a ```route.rs``` can
+export a ```method_router``` function instead. Its
+[```MethodRouter```](https://docs.rs/axum/latest/axum/routing/method_routing/struct.MethodRouter.html)
+is used as is and all other handlers in the file are ignored.
+```rust
+use axum::routing::{on, MethodFilter, MethodRouter};
+
+# #[derive(Clone)]
+# struct AppState;
+pub fn method_router() -> MethodRouter<AppState> {
+    on(MethodFilter::GET.or(MethodFilter::POST), || async { "Hello World!" })
+}
+```
+
 ## Path Parameters
 
 Dynamic path segments are defined using brackets:
@@ -101,6 +256,32 @@ pub async fn get(Path(id): Path<String>) -> impl IntoResponse {
 }
 ```
 
+Folders & parameters can be named after Rust keywords, e.g. ```match/[type]/route.rs``` serves ```/match/{type}```.
+Non-ASCII folder names are percent-encoded in the path, as requests carry them encoded,
+e.g. ```über/route.rs``` serves ```/%C3%BCber```.
+
+### Typed Parameters
+
+A type can be declared after the parameter name with ```@```. Handlers extracting the parameter
+as a different type are reported at compile time:
+```text
+src/api/orders/[id@u32]/route.rs   -> "/orders/{id}", requires e.g. Path<u32>
+```
+Handlers extracting structs are not checked.
+
+### Regex Parameters
+
+A ```regex.txt``` in a parameter folder holds a regex the parameter has to match, as most
+characters of regexes aren't allowed in folder names on Windows.
+Requests whose parameter doesn't fully match it are answered with ```404 Not Found```
+before the handler runs (requires ```regex``` in your dependencies):
+```text
+src/api/items/[sku]/regex.txt   -> [A-Z]{3}-[0-9]+
+src/api/items/[sku]/route.rs    -> "/items/{sku}", matches "/items/ABC-12"
+```
+Surrounding whitespace of the regex is ignored. The names of regex parameters can only contain
+letters, digits & ```_``` and can't start with a digit.
+
 ## Catch-all Parameters
 
 Use the spread syntax for catch-all segments:
@@ -118,6 +299,15 @@ pub async fn get(Path(path): Path<String>) -> impl IntoResponse {
 }
 ```
 
+## Route Order
+
+Routes are registered by specificity instead of the file system's order: within a folder,
+its own route comes first, then static segments, then parameters and then catch-alls, e.g.
+```text
+/users, /users/new, /users/{id}, /users/{*rest}
+```
+Folders of the same kind are ordered by name, so the generated code is the same on every platform.
+
 ## State Extraction
 
 The state type provided to the macro is available in all route handlers:
@@ -136,10 +326,854 @@ pub async fn get(State(state): State<AppState>) -> impl IntoResponse {
 }
 ```
 
+A handler extracting a ```State<T>``` which the state type doesn't implement ```FromRef``` for
+is reported naming its file & function, e.g.
+```text
+`get` in users/route.rs extracts `State<Db>`, which can't be extracted from `AppState`
+```
+
+### Generic State
+
+A generic struct can be routed with one of its type parameters as the state, e.g. for
+libraries shipping routes for their users' state. Its ```where``` clause is copied to the
+generated impls, so handlers can extract the parts they need with ```FromRef```:
+```rust
+# use std::marker::PhantomData;
+# use axum::{extract::FromRef, Router};
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# pub struct Db;
+#[folder_router("./examples/simple/api", S)]
+pub struct LibraryRouter<S>(PhantomData<S>)
+where
+    Db: FromRef<S>;
+
+# #[derive(Clone)]
+# struct AppState { db: Db }
+# impl FromRef<AppState> for Db {
+#     fn from_ref(state: &AppState) -> Db { state.db.clone() }
+# }
+# fn main() {
+let app: Router = LibraryRouter::into_router_with_state(AppState { db: Db });
+# }
+```
+No [Generated Tests](crate#generated-tests) are emitted for generic structs.
+
+### Subtree State
+
+A ```state.rs``` gives the routes of its folder & subfolders their own state.
+They're routed by a separate router provided with the state returned by its ```state``` fn,
+while all other routes keep the macro's state:
+```rust,ignore
+pub type State = crate::AdminState;
+
+pub fn state() -> State {
+    crate::AdminState::connect()
+}
+```
+Handlers in the subtree extract ```State<AdminState>``` or types implementing ```FromRef<AdminState>```.
+
+A subtree state that's part of the macro's state is derived with a ```from_ref``` fn instead,
+generating its ```FromRef<AppState>``` impl so the subtree stays in the macro's router:
+```rust,ignore
+pub type State = crate::AdminState;
+
+pub fn from_ref(state: &crate::AppState) -> State {
+    state.admin.clone()
+}
+```
+Without either fn the ```State``` is checked to implement ```FromRef``` of the macro's state.
+```state.rs``` files can't be nested or placed in the route root.
+
+## Resources
+
+A ```resource.rs``` defining any of ```index```, ```create```, ```show```, ```update``` & ```destroy```
+expands into a collection & a member route, like [axum-extra's ```Resource```](https://docs.rs/axum-extra/latest/axum_extra/routing/struct.Resource.html):
+```text
+src/api/articles/resource.rs
+  index    -> GET    "/articles"
+  create   -> POST   "/articles"
+  show     -> GET    "/articles/{id}"
+  update   -> PUT    "/articles/{id}" & PATCH "/articles/{id}"
+  destroy  -> DELETE "/articles/{id}"
+```
+A folder can't contain both a ```route.rs``` & a ```resource.rs```.
+
+## Static Assets
+
+Folders named ```public``` are served as static files at their path using
+[```ServeDir```](https://docs.rs/tower-http/latest/tower_http/services/struct.ServeDir.html)
+(requires ```tower-http``` with the ```fs``` feature).
+Files inside them, including ```route.rs```, are not scanned for routes.
+```text
+src/api/public/logo.png   -> "/public/logo.png"
+```
+The absolute path of the folder is embedded at compile time, so it needs to
+be available at the same location at runtime.
+
+## Services
+
+A ```service.rs``` file exporting a ```service``` function nests the returned
+[```Service```](https://docs.rs/tower/latest/tower/trait.Service.html) at its folder's path
+using [```nest_service```](https://docs.rs/axum/latest/axum/struct.Router.html#method.nest_service),
+e.g. for serving files from elsewhere or mounting other tower services.
+A ```service.rs``` in the route root is used as fallback service.
+```rust
+use tower_http::services::ServeDir;
+
+pub fn service() -> ServeDir {
+    ServeDir::new("assets")
+}
+```
+
+## Nested Routers
+
+A ```router.rs``` file exporting a ```router``` function nests the returned
+```Router``` at its folder's path using
+[```nest```](https://docs.rs/axum/latest/axum/struct.Router.html#method.nest),
+e.g. for mounting routers of other crates.
+A ```router.rs``` in the route root is merged instead.
+```rust
+use axum::{routing::get, Router};
+
+# #[derive(Clone)]
+# struct AppState;
+pub fn router() -> Router<AppState> {
+    Router::new().route("/metrics", get(|| async { "metrics" }))
+}
+```
+
+A ```mount.rs``` file naming another folder router struct as ```Mount``` nests its
+```into_router()``` at its folder's path the same way, e.g. for composing route trees owned by
+different teams or crates. The mounted router has to use the same state & have no fields:
+```rust,ignore
+pub type Mount = billing::BillingRouter;
+```
+
+Folder routers serving paths side by side are combined by [```merge_all!```](macro@merge_all),
+which checks at compile time that they don't register the same method at the same path.
+
+## Route Groups
+
+Folders in parentheses group routes without adding a segment to their path:
+```text
+src/api/(auth)/account/route.rs   -> "/account"
+```
+
+A method & path registered by more than one file, e.g. by a route group and a folder
+of the same name, a path override or a nested router, is reported at compile time
+naming both files, instead of axum panicking when the router is built.
+
+### Session Groups
+
+With the ```sessions``` feature a ```(session)``` group requires a
+[```tower-sessions```](https://docs.rs/tower-sessions) session for all routes beneath it,
+answering requests whose session holds no data with ```401 Unauthorized```. This covers the
+user id [```axum-login```](https://docs.rs/axum-login) stores on login, so logging in
+unlocks the group:
+```text
+src/api/(session)/account/route.rs   -> "/account", requires a session
+```
+Handlers beneath it can rely on the ```Session``` extractor to be populated:
+```rust,ignore
+use tower_sessions::Session;
+
+pub async fn get(session: Session) -> String {
+    let name: Option<String> = session.get("name").await.unwrap();
+    format!("Hello {}!", name.unwrap_or_default())
+}
+```
+The ```SessionManagerLayer``` has to be added to the router, e.g. by the ```AuthManagerLayer``` of
+```axum-login```. Sessions are checked before the guards of the route's folders.
+Without the ```sessions``` feature a ```(session)``` group is a compile error, instead of
+serving its routes to anyone.
+
+## Subdomains
+
+A top-level ```[tenant]!host``` folder matches the first label of the ```Host``` header
+instead of a path segment, e.g. ```acme``` for ```acme.example.com```.
+Its routes are only served for hosts with a subdomain, relative to the [```host```](crate#options)
+option's host if given, and answer ```404 Not Found``` otherwise:
+```text
+src/api/[tenant]!host/dashboard/route.rs   -> "/dashboard" at "{tenant}.example.com"
+```
+A subdomain is a single label, ignoring the port: ```eu.acme.example.com``` has none, with or without ```host = "example.com"```.
+Without the ```host``` option only hosts like ```acme.example.com``` or ```acme.localhost``` have one,
+so set it for hosts with more labels like ```acme.example.co.uk```.
+The subdomain is passed to the handlers as an extension of a generated ```Tenant``` struct,
+named after the folder & defined next to the router struct:
+```rust,ignore
+use axum::{response::IntoResponse, Extension};
+
+use crate::Tenant;
+
+pub async fn get(Extension(Tenant(tenant)): Extension<Tenant>) -> impl IntoResponse {
+    format!("Dashboard of {tenant}")
+}
+```
+
+## Environment Specific Routes
+
+A ```route.dev.rs``` or ```route.prod.rs``` replaces the ```route.rs``` of its folder
+in debug or release builds respectively, using ```cfg(debug_assertions)```.
+Debug only endpoints like fixtures or fault injection never ship this way:
+```text
+src/api/fixtures/route.dev.rs   -> "/fixtures" (debug builds only)
+```
+The [```env```](crate#options) option selects one environment regardless of the build profile instead.
+
+## Feature Gated Folders
+
+A ```feature.txt``` containing the name of a cargo feature of your crate gates its folder
+& all subfolders. Their modules & registrations are wrapped in ```#[cfg(feature = "...")]```,
+so e.g. enterprise only routes can be shipped from the same source:
+```text
+src/api/enterprise/feature.txt          -> "enterprise"
+src/api/enterprise/reports/route.rs     -> "/enterprise/reports" (with the enterprise feature only)
+```
+Nested ```feature.txt``` files require all of their features.
+
+## API Versions
+
+Folders named like ```v1```, ```v2```, ... are API version folders.
+Versions listed in the [```deprecated```](crate#options) option add a ```Deprecation: true``` header
+to the responses of their routes, and a ```Sunset``` header if a date is set in ```sunset```:
+```rust
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# struct AppState;
+#[folder_router(
+    "./examples/versioned/api",
+    AppState,
+    deprecated = ["v1"],
+    sunset = { v1 = "Fri, 01 Jan 2027 00:00:00 GMT" }
+)]
+struct MyFolderRouter();
+# fn main() {}
+```
+
+### Deprecated Routes
+
+A ```DEPRECATED``` const in a ```route.rs``` adds the same ```Deprecation: true``` header to the responses
+of the route, along with a ```Sunset``` header for a ```SUNSET``` date & a ```Link``` header for a ```DEPRECATION_LINK```.
+Handlers marked ```#[deprecated]``` add the ```Deprecation``` header to their own responses only:
+```rust
+pub const DEPRECATED: bool = true;
+pub const SUNSET: &str = "Fri, 01 Jan 2027 00:00:00 GMT";
+pub const DEPRECATION_LINK: &str = "https://example.com/migrating";
+
+pub async fn get() -> &'static str {
+    "Legacy"
+}
+```
+Deprecated routes are flagged by ```cargo folder-router list``` & ```cargo folder-router export --format openapi```.
+
+## Guards
+
+A ```guard.rs``` file exporting a ```guard``` middleware function runs before all
+routes in its folder & subfolders, using
+[```from_fn```](https://docs.rs/axum/latest/axum/middleware/fn.from_fn.html).
+Guards of parent folders run first. Together with route groups this makes e.g.
+"everything in ```(auth)/``` requires login" part of your folder structure.
+Routers, services & ```public``` folders nested in the folder are guarded as a whole,
+like their CORS layer & authentication below.
+```rust
+use axum::{
+  extract::Request,
+  http::{header, StatusCode},
+  middleware::Next,
+  response::{IntoResponse, Response}
+};
+
+pub async fn guard(request: Request, next: Next) -> Response {
+    if request.headers().contains_key(header::AUTHORIZATION) {
+        next.run(request).await
+    } else {
+        StatusCode::UNAUTHORIZED.into_response()
+    }
+}
+```
+
+Instead of a function a ```guard.rs``` can export a ```Guard``` extractor implementing
+```FromRequestParts```, applied with
+[```from_extractor```](https://docs.rs/axum/latest/axum/middleware/fn.from_extractor.html).
+Requests it rejects never reach the handlers. A ```Guard``` in a ```route.rs``` only applies to that route.
+Extractors needing the state can't be used, as it's only provided after the router is built.
+```rust
+use axum::{
+  extract::FromRequestParts,
+  http::{header, request::Parts, StatusCode}
+};
+
+pub struct Guard;
+
+impl<S: Sync> FromRequestParts<S> for Guard {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if parts.headers.contains_key(header::AUTHORIZATION) {
+            Ok(Self)
+        } else {
+            Err(StatusCode::UNAUTHORIZED)
+        }
+    }
+}
+```
+
+### API Keys
+
+An ```api_key.rs``` file requires an API key for all routes in its folder & subfolders, answering
+requests without one or with a rejected one with ```401 Unauthorized```. It exports a
+```validate``` function checking the key with the state & optionally the ```HEADER``` it's read
+from, ```x-api-key``` by default. The closest ```api_key.rs``` is used.
+```rust,ignore
+use crate::AppState;
+
+pub const HEADER: &str = "x-admin-key";
+
+pub async fn validate(state: &AppState, key: &str) -> bool {
+    state.admin_keys.contains(key)
+}
+```
+Keys are checked before the guards of the route's folders. As they're validated with the state,
+which mounted services & routers don't get, an ```api_key.rs``` folder can't contain
+```router.rs```, ```service.rs```, ```mount.rs``` or ```public``` folders.
+
+### JWT Authentication
+
+With the ```jwt``` feature the ```auth``` option validates the bearer tokens of all routes below
+the given path prefixes (all routes without ```paths```) with
+[```jsonwebtoken```](https://docs.rs/jsonwebtoken), answering requests without a valid token with
+```401 Unauthorized```:
+```rust,ignore
+#[folder_router(
+    "./src/api",
+    AppState,
+    auth = jwt(audience = "my-api", issuer = "https://auth.example.com", paths = ["/admin", "/billing"])
+)]
+struct MyFolderRouter();
+```
+The claims of valid tokens are inserted as an extension, deserialized into ```serde_json::Value```
+or the type given as ```claims = crate::Claims```:
+```rust,ignore
+use axum::Extension;
+
+use crate::Claims;
+
+pub async fn get(Extension(claims): Extension<Claims>) -> String {
+    format!("Hello {}!", claims.sub)
+}
+```
+Tokens are checked before the guards of the route's folders, which can read the claims too.
+Nested routers & services are checked as a whole if any of the ```paths``` is at or below them.
+The ```algorithm``` defaults to ```"HS256"```, with the secret read from the ```JWT_SECRET``` env var
+when the router is built by ```into_router```, which panics if it isn't set or isn't a valid key,
+instead of failing on the first request. Another env var can be given as
+```key_env = "..."```, holding the PEM encoded public key for the ```RS*```, ```PS*```, ```ES*``` &
+```EdDSA``` algorithms. The [Generated Tests](crate#generated-tests) build the router too, so their
+```test_state``` function has to set the key first.
+
+### CSRF Protection
+
+With the ```csrf``` feature the ```csrf``` option protects the routes below the given path prefixes
+from cross-site request forgery by double submit. Requests without a ```csrf_token``` cookie are
+issued a token of 256 bits from the OS's random number generator, read by
+[```getrandom```](https://docs.rs/getrandom), in a ```Secure``` & ```SameSite=Strict``` cookie.
+Requests with methods other than ```GET```, ```HEAD``` & ```OPTIONS``` have to submit it in the
+```x-csrf-token``` header or the ```_csrf``` field of URL encoded forms, and are answered with
+```403 Forbidden``` otherwise. Cookies not holding a token in the issued format, e.g. planted by a
+sibling subdomain, are ignored & replaced by a new token. Forms are read up to the ```DefaultBodyLimit``` of the route, e.g. the
+```body_limit``` of its folders' ```config.toml```:
+```rust,ignore
+#[folder_router("./src/api", AppState, csrf = ["/account", "/admin"])]
+struct MyFolderRouter();
+```
+The token is passed to the handlers as an extension of a generated ```CsrfToken``` struct,
+defined next to the router struct, for rendering it into forms:
+```rust,ignore
+use axum::{response::Html, Extension};
+
+use crate::CsrfToken;
+
+pub async fn page(Extension(CsrfToken(token)): Extension<CsrfToken>) -> Html<String> {
+    Html(format!(r#"<form method="post"><input type="hidden" name="_csrf" value="{token}"></form>"#))
+}
+```
+
+### Route Middleware
+
+A ```middleware``` function in a ```route.rs``` wraps just that route, inside the guards of its folders:
+```rust
+use axum::{extract::Request, middleware::Next, response::Response};
+
+pub async fn middleware(request: Request, next: Next) -> Response {
+    let mut response = next.run(request).await;
+    response.headers_mut().insert("x-route", "users".parse().unwrap());
+    response
+}
+
+pub async fn get() -> &'static str {
+    "Users"
+}
+```
+
+## Pages
+
+A ```page.rs``` exporting a ```page``` function is registered as the ```GET``` handler of its folder,
+keeping HTML pages apart from the API handlers of a ```route.rs``` in the same folder:
+```text
+src/api/contact/page.rs    -> GET "/contact"
+src/api/contact/route.rs   -> POST "/contact"
+```
+```rust
+use axum::response::Html;
+
+pub async fn page() -> Html<&'static str> {
+    Html("<h1>Contact</h1>")
+}
+```
+A ```route.rs``` next to it can't define a ```get``` handler as well.
+
+An ```action.rs``` exporting an ```action``` function handles the ```POST``` requests of its folder,
+e.g. the submissions of the page's forms:
+```text
+src/api/contact/page.rs    -> GET "/contact"
+src/api/contact/action.rs  -> POST "/contact"
+```
+```rust
+use axum::{response::Redirect, Form};
+
+pub async fn action(Form(form): Form<Vec<(String, String)>>) -> Redirect {
+    Redirect::to("/contact")
+}
+```
+[Layouts](crate#layouts) only wrap HTML responses, so they wrap pages but leave JSON APIs alone.
+
+### Fragments
+
+Every ```pub async fn``` of a ```fragment.rs``` is registered as a ```GET``` handler below
+the ```/fragments``` path of its folder, so partials swapped in by e.g. htmx can live next to their page.
+Fragments aren't wrapped in [layouts](crate#layouts):
+```text
+src/api/contact/page.rs      -> GET "/contact"
+src/api/contact/fragment.rs  -> GET "/contact/fragments/form"
+```
+```rust
+use axum::response::Html;
+
+pub async fn form() -> Html<&'static str> {
+    Html("<form hx-post=\"/contact\"><input name=\"email\"></form>")
+}
+```
+
+## GraphQL
+
+A ```graphql.rs``` mounts a GraphQL endpoint of e.g. async-graphql or juniper on the path of its folder,
+with its ```playground``` function answering ```GET``` & its ```execute``` function answering ```POST``` requests.
+Either of them may be left out, and neither is wrapped in [layouts](crate#layouts):
+```text
+src/api/graphql/graphql.rs  -> GET & POST "/graphql"
+```
+```rust,ignore
+use async_graphql::http::GraphiQLSource;
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use axum::{extract::State, response::Html};
+
+pub async fn playground() -> Html<String> {
+    Html(GraphiQLSource::build().endpoint("/graphql").finish())
+}
+
+pub async fn execute(State(state): State<AppState>, request: GraphQLRequest) -> GraphQLResponse {
+    state.schema.execute(request.into_inner()).await.into()
+}
+```
+
+## Layouts
+
+A ```layout.rs``` exporting a ```layout``` function wraps the HTML responses of all routes
+in its folder & subfolders, i.e. responses with a ```text/html``` content type.
+Layouts of subfolders are applied first, so nested layouts compose outward:
+```rust
+pub async fn layout(content: String) -> String {
+    format!("<html><body>{content}</body></html>")
+}
+```
+```text
+src/api/layout.rs          -> <html><body>...</body></html>
+src/api/docs/layout.rs     -> <nav>Docs</nav><main>...</main>
+src/api/docs/route.rs      -> <html><body><nav>Docs</nav><main><h1>Docs</h1></main></body></html>
+```
+
+## Health Checks
+
+The [```health```](crate#options) option adds a liveness route answering ```200 OK``` & a readiness route below it:
+```text
+health = "/healthz"        -> GET "/healthz" & GET "/healthz/ready"
+```
+A ```health.rs``` in the route folder exporting a ```ready``` handler answers the readiness checks instead,
+e.g. with a ```503 Service Unavailable``` while the database is unreachable:
+```rust
+use axum::{extract::State, http::StatusCode};
+
+# #[derive(Clone)]
+# struct AppState { db_connected: bool }
+pub async fn ready(State(state): State<AppState>) -> StatusCode {
+    if state.db_connected {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
+```
+
+## Method Not Allowed
+
+By default axum responds with an empty ```405 Method Not Allowed``` to requests
+with a method a route doesn't handle.
+To customize this response, define a ```method_not_allowed``` handler, either
+* in a ```route.rs```, applying to just that route, or
+* in a ```method_not_allowed.rs``` file, applying to all routes in its folder & subfolders.
+  The closest ```method_not_allowed.rs``` is used.
+```rust
+use axum::{
+  http::StatusCode,
+  response::IntoResponse
+};
+
+pub async fn method_not_allowed() -> impl IntoResponse {
+    (StatusCode::METHOD_NOT_ALLOWED, "Method not allowed")
+}
+```
+
+## CORS
+
+A ```cors.rs``` file exporting a ```cors``` function applies its
+[```CorsLayer```](https://docs.rs/tower-http/latest/tower_http/cors/struct.CorsLayer.html)
+to all routes in its folder & subfolders. The closest ```cors.rs``` is used,
+so subtrees can have different CORS policies.
+```rust
+use tower_http::cors::{Any, CorsLayer};
+
+pub fn cors() -> CorsLayer {
+    CorsLayer::new().allow_origin(Any)
+}
+```
+
+## Folder Configuration
+
+A ```config.toml``` sets layers for all routes in its folder & subfolders,
+keeping the policies of a subtree in one place:
+```toml
+timeout = "5s"                       # 408 Request Timeout if a route takes longer
+body_limit = "10MB"                  # limits request bodies
+compression = true                   # overrides the compression option
+guard = "crate::auth::require_login" # an axum::middleware::from_fn middleware
+etag = true                          # like #[etag] on every handler
+```
+Each setting of a nested ```config.toml``` replaces the one of its parent folders.
+[Handler Attributes](crate#handler-attributes) apply within these layers, so ```#[body_limit(...)]```
+overrides a folder's ```body_limit```. ```timeout``` requires ```tower-http``` with the ```timeout``` feature
+& ```compression``` one of its ```compression-*``` features.
+
+## Handler Attributes
+
+Handlers can be configured with attributes:
+* [```#[skip]```](macro@skip) - doesn't register the function as a handler
+* [```#[timeout("5s")]```](macro@timeout) - responds with ```408 Request Timeout```
+  if the handler takes too long (requires ```tower-http``` with the ```timeout``` feature)
+* [```#[body_limit("10MB")]```](macro@body_limit) - limits the request body size
+  of the handler, overriding axum's default limit of 2MB
+* [```#[concurrency_limit(4)]```](macro@concurrency_limit) - makes requests wait while the handler
+  is already handling that many (requires ```tower``` with the ```limit``` feature)
+* [```#[upload(limit = "10MB", accept = ["image/png"])]```](macro@upload) - limits uploads
+  & their files and rejects unexpected content types
+* [```#[cache_control("public, max-age=300")]```](macro@cache_control) - sets the ```Cache-Control```
+  header of the handler's responses
+* [```#[etag]```](macro@etag) - answers ```GET``` requests with a matching ```If-None-Match```
+  with ```304 Not Modified```
+* ```#[rate_limit(per_second = 5, burst = 10)]``` - rate limits
+  the handler per peer IP (requires the ```rate-limit``` feature & ```tower_governor```, and
+  serving the app with ```into_make_service_with_connect_info::<SocketAddr>()```)
+
+```rust
+#[axum_folder_router::timeout("5s")]
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
+```
+
+### Conditional Handlers
+
+```#[cfg(...)]``` attributes on a handler are copied to its registration,
+so the method disappears from the route when the condition is false:
+```rust
+#[cfg(debug_assertions)]
+pub async fn delete() -> &'static str {
+    "Only available in debug builds"
+}
+```
+
+### Content Negotiation
+
+A method followed by ```__``` and a content type, e.g. ```get__json``` & ```get__html```,
+answers the requests of that method whose ```Accept``` header prefers the content type.
+The known content types are ```json```, ```html```, ```xml``` & ```text```.
+A plain ```get``` handler answers all other requests, without one they get ```406 Not Acceptable```.
+```rust
+#![allow(non_snake_case)]
+# use axum::{response::Html, Json};
+
+pub async fn get__json() -> Json<&'static str> {
+    Json("Hello World!")
+}
+
+pub async fn get__html() -> Html<&'static str> {
+    Html("<p>Hello World!</p>")
+}
+```
+Handler attributes aren't supported on content type variants.
+
+## Options
+
+Additional options can be passed as `key = value` pairs after the state type:
+```rust
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# struct AppState;
+#[folder_router("./examples/simple/api", AppState, auto_options = true)]
+struct MyFolderRouter();
+# fn main() {}
+```
+
+* **`auto_options`** -
+  Generates an ```OPTIONS``` handler for every route which doesn't define one itself,
+  responding with an ```Allow``` header listing the methods of its ```route.rs```.
+* **`auto_head`** -
+  Registers the ```get``` handler of every route as an explicit ```HEAD``` handler,
+  unless a ```head``` handler is defined. axum removes the response body.
+* **`compression`** -
+  A path prefix or list of path prefixes, e.g. ```compression = ["/api"]```.
+  Compresses the responses of all routes below them using
+  [```CompressionLayer```](https://docs.rs/tower-http/latest/tower_http/compression/struct.CompressionLayer.html)
+  (requires ```tower-http``` with one of the ```compression-*``` features).
+* **`env`** -
+  Either ```"dev"``` or ```"prod"```, e.g. ```env = "prod"```.
+  Always uses the route files of that environment, see [Environment Specific Routes](crate#environment-specific-routes).
+* **`deprecated`** -
+  An API version folder or list of them, e.g. ```deprecated = ["v1"]```,
+  see [API Versions](crate#api-versions).
+* **`sunset`** -
+  Sunset dates of deprecated API versions as HTTP dates, e.g.
+  ```sunset = { v1 = "Fri, 01 Jan 2027 00:00:00 GMT" }```.
+* **`case`** -
+  ```"kebab"``` serves ```snake_case``` folders like ```user_settings``` at kebab-case paths like ```/user-settings```,
+  ```"preserve"``` uses folder names as is (default).
+* **`visibility`** -
+  ```"crate"``` declares the generated route modules ```pub(crate)``` instead of ```pub``` (default),
+  e.g. for crates linting against ```unreachable_pub```. Handlers can be ```pub``` or ```pub(crate)``` either way.
+* **`param_syntax`** -
+  How path parameters are rendered, either ```"{id}"``` (default), ```"{:id}"```
+  or ```":id"``` (default with the ```axum-07``` feature).
+* **`trailing_slash`** -
+  How requests with a trailing slash like ```/users/``` are handled:
+  ```"strict"``` doesn't match them (default), ```"redirect"``` redirects them to ```/users```
+  with ```308 Permanent Redirect``` & ```"merge"``` handles them by the same route.
+* **`sitemap`** -
+  A base URL like ```sitemap = "https://example.com"```. Adds a ```/sitemap.xml``` route
+  listing all ```GET``` routes without path parameters.
+* **`sitemap_exclude`** -
+  A path prefix or list of path prefixes excluded from the sitemap, e.g. ```sitemap_exclude = ["/api"]```.
+* **`health`** -
+  A path like ```health = "/healthz"```, see [Health Checks](crate#health-checks).
+* **`request_id`** -
+  ```true``` or the name of a request id header like ```request_id = "x-correlation-id"```,
+  ```x-request-id``` by default. Gives every request without an id a UUID using
+  [```SetRequestIdLayer```](https://docs.rs/tower-http/latest/tower_http/request_id/struct.SetRequestIdLayer.html)
+  & copies it to the response (requires ```tower-http``` with the ```request-id``` feature).
+* **`trace`** -
+  ```trace = true``` wraps every route in a
+  [```TraceLayer```](https://docs.rs/tower-http/latest/tower_http/trace/struct.TraceLayer.html)
+  with spans named after its path like ```/users/{id}``` (requires the ```trace``` feature,
+  ```tracing``` & ```tower-http``` with the ```trace``` feature).
+* **`normalize_path`** -
+  ```normalize_path = true``` removes duplicate & trailing slashes from request paths before they're routed,
+  so ```//users//5/``` is served by ```/users/{id}```. Applies to the router of ```into_router_with_state```,
+  since a layer on the ```Router``` of ```into_router``` would only run after routing.
+  Requires ```tower``` with the ```util``` feature.
+* **`method_override`** -
+  ```method_override = true``` lets ```POST``` requests of HTML forms & legacy clients, which can only send
+  ```GET``` & ```POST```, be routed as ```PUT```, ```PATCH``` or ```DELETE``` requests, given in the
+  ```X-HTTP-Method-Override``` header or the ```_method``` field of URL encoded forms, which are read up to
+  the ```DefaultBodyLimit``` layered around the router. Like ```normalize_path```
+  it applies to the router of ```into_router_with_state``` & requires ```tower``` with the ```util``` feature.
+* **`livereload`** -
+  ```livereload = true``` injects a script into HTML responses of debug builds using
+  [```LiveReloadLayer```](https://docs.rs/tower-livereload/latest/tower_livereload/struct.LiveReloadLayer.html),
+  which reloads the page once the server is back after a restart, e.g. when it was rebuilt for
+  a changed route tree. Requires the ```livereload``` feature & ```tower-livereload```.
+* **`hot_reload`** -
+  ```hot_reload = true``` makes debug builds compatible with hot-patching by
+  [```subsecond```](https://docs.rs/subsecond), e.g. with ```dx serve --hot-patch```.
+  The router of ```into_router_with_state``` is rebuilt through ```subsecond::call``` whenever a patch is applied,
+  so patched handler bodies are served without restarting, while layers like ```#[concurrency_limit]``` keep
+  their state between requests. Release builds route directly.
+  Requires the ```hot-reload``` feature, ```subsecond``` & ```tower``` with the ```util``` feature.
+* **`csrf`** -
+  A path prefix or list of path prefixes, e.g. ```csrf = ["/account"]```, whose mutating requests
+  require a CSRF token, see [CSRF Protection](crate#csrf-protection). Requires the ```csrf``` feature
+  & ```getrandom```.
+* **`auth`** -
+  ```auth = jwt(audience = "...", issuer = "...")``` validates JSON Web Tokens, see
+  [JWT Authentication](crate#jwt-authentication). Requires the ```jwt``` feature & ```jsonwebtoken```.
+* **`host`** -
+  A host like ```host = "api.example.com"```, see [Virtual Hosts](crate#virtual-hosts).
+* **`locales`** -
+  A list of locales like ```locales = ["en", "de"]```, see [Locales](crate#locales).
+* **`config`** -
+  Generates ```into_router_with_config```, see [Runtime Configuration](crate#runtime-configuration).
+* **`follow_symlinks`** -
+  Whether symlinked route files & folders are scanned, ```true``` by default.
+  A symlinked folder pointing to one of its own parent folders is skipped, so cycles can't hang the build.
+* **`skip_hidden`** -
+  Whether files & folders starting with a ```.``` like ```.git``` or ```.backup``` are skipped, ```true``` by default,
+  so stray copies of routes in them aren't served. ```.well-known``` is scanned anyway.
+* **`max_depth`** -
+  How many folder levels below the route folder are scanned at most, e.g. ```max_depth = 2```
+  only registers ```/users``` & ```/users/{id}``` but nothing below them. Unlimited by default.
+* **`test_state`** -
+  A function creating the state used by the [Generated Tests](crate#generated-tests),
+  e.g. ```test_state = test_app_state```. Defaults to ```Default::default()```.
+* **`error`** -
+  An error type, e.g. ```error = AppError```. Every handler of a ```route.rs``` has to return
+  a ```Result<_, AppError>```, otherwise the macro reports it, keeping the error responses
+  of all routes consistent. Type aliases of ```Result``` aren't recognized.
+
+### Options File
+
+Options can also be set in a ```folder_router.toml``` at the root of the route folder,
+keeping the macro invocation short & the settings beside the routes they affect:
+```toml
+case = "kebab"
+trailing_slash = "redirect"
+compression = ["/assets"]
+sunset = { v1 = "Fri, 01 Jan 2027 00:00:00 GMT" }
+error = "crate::AppError"
+```
+Values are written as in the macro invocation, with paths like ```error``` & ```test_state``` as strings.
+Options of the macro invocation override the ones of the file.
+
+## Virtual Hosts
+
+With the ```host``` option an ```into_host_router(state, fallback)``` fn is generated,
+serving requests for that host by the folder router & all others by ```fallback```.
+Chaining them serves several folder routers for different hosts in one app:
+```rust,ignore
+#[folder_router("./src/api", ApiState, host = "api.example.com")]
+struct ApiRouter();
+
+#[folder_router("./src/www", WwwState)]
+struct WwwRouter();
+
+let app: Router = ApiRouter::into_host_router(api_state, WwwRouter::into_router().with_state(www_state));
+```
+The port is ignored unless the ```host``` includes one. Requires ```tower``` with the ```util``` feature.
+
+## Locales
+
+With the ```locales``` option all routes are served below a prefix per locale,
+e.g. ```/en/users``` & ```/de/users``` for ```locales = ["en", "de"]```.
+Handlers get the locale of a request as a ```Locale``` extension, generated next to the router struct:
+```rust,ignore
+use axum::{response::IntoResponse, Extension};
+
+use crate::Locale;
+
+pub async fn get(Extension(Locale(locale)): Extension<Locale>) -> impl IntoResponse {
+    format!("Hello in {locale}")
+}
+```
+Paths without a locale prefix redirect to the best matching locale of the ```Accept-Language``` header,
+or to the first locale, e.g. ```/users``` to ```/de/users``` for ```Accept-Language: de-DE,en;q=0.8```.
+
+## Runtime Configuration
+
+With ```config = true``` a ```FolderRouterConfig``` type is generated next to the struct,
+which disables route subtrees when the router is built, e.g. for feature flags:
+```rust,no_run
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# struct AppState;
+#[folder_router("./examples/versioned/api", AppState, config = true)]
+struct MyFolderRouter();
+
+# fn main() {
+let config = FolderRouterConfig::new().disable("/v1");
+let router: axum::Router<AppState> = MyFolderRouter::into_router_with_config(config);
+# }
+```
+A prefix disables the routes at its path & below it. ```into_router``` keeps all routes.
+Modules get a ```router_with_config``` function instead. The sitemap still lists disabled routes.
+
+## Generated Tests
+
+With the ```test-helpers``` feature a ```#[cfg(test)]``` module is generated next to the router,
+with a ```#[tokio::test]``` per route checking that none of its methods respond with
+```404 Not Found``` or ```405 Method Not Allowed```. Path parameters are filled with sample values
+like ```1``` for untyped & integer parameters.
+Routes with regex parameters, routers, services & public folders aren't tested.
+The ```test_state``` function is called before the router is built, so it can also set up the
+environment, like the env var of the ```auth = jwt(...)``` key.
+
+```rust,ignore
+fn test_app_state() -> AppState {
+    AppState::new_in_memory()
+}
+
+#[folder_router("./src/api", AppState, test_state = test_app_state)]
+struct MyFolderRouter();
+```
+
+The tests use ```tower::ServiceExt```, so ```tower``` with the ```util``` feature & ```tokio```
+with the ```macros``` and ```rt``` features need to be dev-dependencies.
+
+## Route Snapshots
+
+[```assert_routes!```](macro@assert_routes) expands to the route table of a directory as a
+```&[&str]``` slice, to be snapshot tested with e.g. ```insta```, or asserts it equals
+a list of expected routes:
+```rust,ignore
+#[test]
+fn routes_are_unchanged() {
+    axum_folder_router::assert_routes!("./examples/advanced/api", [
+        "GET /",
+        // ...
+    ]);
+}
+```
+
+[```folder_router_table!```](macro@folder_router_table) expands to a ```const``` compatible
+```(method, path, file)``` table instead, without compiling any handlers.
+
+The router struct implements ```Display```, printing the routes as an aligned
+```METHOD  PATH``` table, e.g. for logging them at startup:
+```rust,no_run
+# use axum_folder_router::folder_router;
+# #[derive(Clone)]
+# struct AppState;
+# #[folder_router("./examples/simple/api", AppState)]
+# struct MyFolderRouter();
+println!("{}", MyFolderRouter());
+```
+It also implements ```Debug``` as a list of ```"GET /path"``` entries, unless it's derived.
+
 ## Avoiding Cache Issues
 
 By default newly created route.rs files may be ignored due to cargo's build-in caching.
 
+Edits of existing files are picked up on any toolchain, as the route files are compiled as modules
+& the files configuring them, like ```redirects.toml```, ```config.toml``` or ```feature.txt```,
+are included with ```include_bytes!```.
+
 ### Nightly Rust
 
 If you're using a nightly toolchain, just enable the `nightly` feature.
@@ -161,10 +1195,15 @@ fn main() {
    );
 }
 ```
+
+During development, ```cargo folder-router watch my/routes``` of the
+[cargo-folder-router](https://crates.io/crates/cargo-folder-router) companion works without
+a ```build.rs```, touching ```src/main.rs``` whenever route files or folders are added or removed.
 */
 #![forbid(unsafe_code)]
 #![cfg_attr(feature = "nightly", feature(proc_macro_tracked_path))]
 
+use axum_folder_router_core::{generate, parse};
 #[cfg(feature = "nightly")]
 use proc_macro::tracked;
 use proc_macro::TokenStream;
@@ -172,9 +1211,6 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::parse_macro_input;
 
-mod generate;
-mod parse;
-
 /// Creates an Axum router module tree & creation function
 /// by scanning a directory for `route.rs` files.
 ///
@@ -184,6 +1220,10 @@ mod parse;
 ///   Cargo manifest directory
 /// * `state_type` - The type name of your application state that will be shared
 ///   across all routes
+/// * `key = value` - Optional settings, see [Options](crate#options)
+///
+/// The path & state can also be named, e.g.
+/// `#[folder_router(path = "src/api", state = AppState, auto_head = true)]`.
 #[allow(clippy::missing_panics_doc)]
 #[proc_macro_attribute]
 pub fn folder_router(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -193,10 +1233,71 @@ pub fn folder_router(attr: TokenStream, item: TokenStream) -> TokenStream {
         attr, item
     );
 
-    let mut errors = TokenStream2::new();
-
     let args = parse_macro_input!(attr as parse::FolderRouterArgs);
+    if let Ok(module) = syn::parse::<syn::ItemMod>(item.clone()) {
+        let mut errors = TokenStream2::new();
+        let routes = scan_routes(&mut errors, &args);
+        let module = generate::module_router(&mut errors, &args, &module, &routes);
+
+        return quote! {
+          #errors
+          #module
+        }
+        .into();
+    }
+
+    let item = parse_macro_input!(item as parse::FolderRouterItem);
+    let expanded = expand_folder_router(&args, &item);
+
+    quote! {
+      #item
+      #expanded
+    }
+    .into()
+}
+
+/// Derive form of [`macro@folder_router`], taking the path & state as named
+/// arguments of a `#[folder_router(...)]` helper attribute.
+///
+/// ```rust
+/// use axum_folder_router::FolderRouter;
+///
+/// #[derive(Clone)]
+/// struct AppState;
+///
+/// #[derive(FolderRouter)]
+/// #[folder_router(path = "./examples/simple/api", state = AppState)]
+/// struct MyFolderRouter();
+///
+/// let router: axum::Router<AppState> = MyFolderRouter::into_router();
+/// ```
+///
+/// The [Options](crate#options) follow the state, e.g.
+/// `#[folder_router(path = "src/api", state = AppState, auto_head = true)]`.
+#[proc_macro_derive(FolderRouter, attributes(folder_router))]
+pub fn derive_folder_router(input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as parse::FolderRouterItem);
+    let Some(attr) = item.attr("folder_router") else {
+        return syn::Error::new(
+            item.struct_name().span(),
+            "Missing `#[folder_router(path = \"...\", state = ...)]` attribute",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let args = match attr.parse_args::<parse::DeriveArgs>() {
+        Ok(derive_args) => derive_args.args,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
+    expand_folder_router(&args, &item).into()
+}
+
+// Scans the route directory of `args`, tracking it on nightly
+fn scan_routes(
+    errors: &mut TokenStream2,
+    args: &parse::FolderRouterArgs,
+) -> parse::FolderRouterRoutes {
     #[cfg(feature = "nightly")]
     {
         #[cfg(feature = "debug")]
@@ -207,17 +1308,417 @@ pub fn folder_router(attr: TokenStream, item: TokenStream) -> TokenStream {
         tracked::path(args.abs_norm_path().as_path().to_str().unwrap());
     }
 
-    let item = parse_macro_input!(item as parse::FolderRouterItem);
-    let routes = parse::FolderRouterRoutes::parse_from_path(&mut errors, &args.abs_norm_path());
+    parse::FolderRouterRoutes::parse_from_path(errors, &args.abs_norm_path(), args)
+}
 
-    let module_tree = generate::module_tree(&args, &item, &routes);
-    let router_impl = generate::router_impl(&mut errors, &args, &item, &routes);
+// Everything both macro forms generate besides the struct itself
+fn expand_folder_router(
+    args: &parse::FolderRouterArgs,
+    item: &parse::FolderRouterItem,
+) -> TokenStream2 {
+    let mut errors = TokenStream2::new();
+    let routes = scan_routes(&mut errors, args);
+    generate::check_conflicts(&mut errors, args, &routes);
+
+    let module_tree = generate::module_tree(args, item, &routes);
+    let router_impl = generate::router_impl(&mut errors, args, item, &routes);
+    let state_checks = generate::state_checks(args, item, &routes);
+    let state_glue = generate::subtree_state_glue(args, item, &routes);
+    let route_tests = generate::route_tests(args, item, &routes);
 
     quote! {
-      #item
       #errors
       #module_tree
       #router_impl
+      #state_checks
+      #state_glue
+      #route_tests
+    }
+}
+
+/// Expands to the routes found in a directory, one `"GET /users"` line per
+/// method & route, for snapshot tests catching accidentally changed routes.
+///
+/// Takes the route directory & the `folder_router` options affecting paths,
+/// e.g. `case` or `env`. Routes accepting any method, like nested routers, are
+/// listed as `"* /path"`.
+///
+/// ```rust
+/// let routes: &[&str] = axum_folder_router::assert_routes!(
+///     "./examples/versioned/api",
+///     case = "kebab"
+/// );
+/// assert_eq!(routes, ["GET /v1/users", "GET /v2/user-settings", "GET /v2/users"]);
+/// ```
+///
+/// With a trailing list of the expected lines it asserts they're equal:
+/// ```rust
+/// axum_folder_router::assert_routes!("./examples/simple/api", ["GET /"]);
+/// ```
+#[proc_macro]
+pub fn assert_routes(input: TokenStream) -> TokenStream {
+    let table_args = parse_macro_input!(input as parse::RouteTableArgs);
+    let args = &table_args.args;
+
+    #[cfg(feature = "nightly")]
+    tracked::path(args.abs_norm_path().as_path().to_str().unwrap());
+
+    let mut errors = TokenStream2::new();
+    let routes =
+        parse::FolderRouterRoutes::parse_from_path(&mut errors, &args.abs_norm_path(), args);
+    let assertion = generate::assert_routes(args, &routes, table_args.expected.as_ref());
+
+    quote! {
+        {
+            #errors
+            #assertion
+        }
+    }
+    .into()
+}
+
+/// Expands to a `(method, path, file)` table of the routes found in a
+/// directory, without generating any modules or a router.
+///
+/// Takes the same arguments as [`assert_routes!`](macro@assert_routes) &
+/// works in `const` items, so the route metadata can be used by docs sites,
+/// gateways or test crates without compiling the handlers.
+///
+/// ```rust
+/// const ROUTES: &[(&str, &str, &str)] =
+///     axum_folder_router::folder_router_table!("./examples/versioned/api");
+///
+/// assert_eq!(ROUTES[0], ("GET", "/v1/users", "v1/users/route.rs"));
+/// ```
+#[proc_macro]
+pub fn folder_router_table(input: TokenStream) -> TokenStream {
+    let table_args = parse_macro_input!(input as parse::RouteTableArgs);
+    let args = &table_args.args;
+
+    if let Some(expected) = &table_args.expected {
+        return syn::Error::new_spanned(
+            expected,
+            "Unexpected list, the table has no expected routes",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    #[cfg(feature = "nightly")]
+    tracked::path(args.abs_norm_path().as_path().to_str().unwrap());
+
+    let mut errors = TokenStream2::new();
+    let routes =
+        parse::FolderRouterRoutes::parse_from_path(&mut errors, &args.abs_norm_path(), args);
+    let table = generate::route_table_const(args, &routes);
+
+    quote! {
+        {
+            #errors
+            #table
+        }
+    }
+    .into()
+}
+
+/// Function-like form of [`macro@folder_router`], expanding to the
+/// `Router<S>` of the routes found in a directory, for quick `main`s &
+/// examples without a router struct.
+///
+/// Takes the same arguments as the attribute macro:
+/// ```rust
+/// use axum::Router;
+///
+/// #[derive(Clone)]
+/// struct AppState;
+///
+/// let router: Router<AppState> = axum_folder_router::router!("./examples/simple/api", AppState);
+/// let app: Router = router.with_state(AppState);
+/// ```
+///
+/// It's named `router!` as macros can't share the `folder_router` name of the
+/// attribute. The generated struct is hidden in the expression, so no
+/// [Generated Tests](crate#generated-tests) are emitted.
+#[proc_macro]
+pub fn router(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as parse::FolderRouterArgs);
+    let item: parse::FolderRouterItem = syn::parse_quote! { struct FolderRouter(); };
+    let state_type = &args.state_type;
+
+    let mut errors = TokenStream2::new();
+    let routes = scan_routes(&mut errors, &args);
+    generate::check_conflicts(&mut errors, &args, &routes);
+
+    let module_tree = generate::module_tree(&args, &item, &routes);
+    let router_impl = generate::router_impl(&mut errors, &args, &item, &routes);
+    let state_checks = generate::state_checks(&args, &item, &routes);
+    let state_glue = generate::subtree_state_glue(&args, &item, &routes);
+
+    quote! {
+        {
+            // Only `into_router` of the generated items is used
+            #[allow(dead_code)]
+            fn __folder_router() -> axum::Router<#state_type> {
+                #item
+                #errors
+                #module_tree
+                #router_impl
+                #state_checks
+                #state_glue
+
+                FolderRouter::into_router()
+            }
+
+            __folder_router()
+        }
+    }
+    .into()
+}
+
+/// Merges the routers of several folder router structs into one
+/// `Router<S>`, failing to compile if two of them register the same method
+/// at the same path, which [`Router::merge`](https://docs.rs/axum/latest/axum/struct.Router.html#method.merge)
+/// would only panic on at runtime.
+///
+/// The structs have to share their state & have no fields.
+///
+/// ```rust
+/// use axum_folder_router::{folder_router, merge_all};
+///
+/// #[derive(Clone)]
+/// struct AppState;
+///
+/// #[folder_router("./examples/simple/api", AppState)]
+/// struct ApiRouter();
+///
+/// #[folder_router("./examples/versioned/api", AppState)]
+/// struct VersionedRouter();
+///
+/// let router: axum::Router<AppState> = merge_all!(ApiRouter, VersionedRouter);
+/// ```
+#[proc_macro]
+pub fn merge_all(input: TokenStream) -> TokenStream {
+    let routers = parse_macro_input!(
+        input with syn::punctuated::Punctuated::<syn::Type, syn::Token![,]>::parse_terminated
+    );
+    let routers: Vec<syn::Type> = routers.into_iter().collect();
+
+    generate::merge_all(&routers).into()
+}
+
+/// Excludes a function in a `route.rs` from being registered as a handler.
+///
+/// Useful for helpers named like HTTP methods or temporarily disabled
+/// handlers, which need to stay `pub`.
+///
+/// ```rust
+/// #[axum_folder_router::skip]
+/// pub async fn delete() -> &'static str {
+///     "Not registered"
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn skip(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_macro_input!(attr as syn::parse::Nothing);
+    let item = TokenStream2::from(item);
+
+    // Skipped handlers aren't referenced by the generated router
+    quote! {
+        #[allow(dead_code)]
+        #item
     }
     .into()
 }
+
+/// Sets a timeout for a handler in a `route.rs`.
+///
+/// Requests taking longer are answered with `408 Request Timeout`.
+/// Accepts durations like `"500ms"`, `"5s"`, `"1m"` or `"1h"`.
+///
+/// Requires `tower-http` with the `timeout` feature in your dependencies.
+///
+/// ```rust
+/// #[axum_folder_router::timeout("5s")]
+/// pub async fn get() -> &'static str {
+///     "Hello World!"
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn timeout(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let duration = parse_macro_input!(attr as syn::LitStr);
+
+    if parse::parse_duration(&duration.value()).is_none() {
+        return syn::Error::new(
+            duration.span(),
+            "Invalid duration, expected e.g. \"500ms\", \"5s\", \"1m\" or \"1h\"",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    item
+}
+
+/// Sets the maximum request body size for a handler in a `route.rs`,
+/// using [`DefaultBodyLimit::max`](https://docs.rs/axum/latest/axum/extract/struct.DefaultBodyLimit.html#method.max).
+///
+/// Accepts sizes like `"512B"`, `"64KB"`, `"10MB"` or `"1GB"`, where
+/// `1KB` are 1024 bytes.
+///
+/// ```rust
+/// #[axum_folder_router::body_limit("10MB")]
+/// pub async fn post(body: axum::body::Bytes) -> String {
+///     format!("Uploaded {} bytes", body.len())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn body_limit(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let size = parse_macro_input!(attr as syn::LitStr);
+
+    if parse::parse_size(&size.value()).is_none() {
+        return syn::Error::new(
+            size.span(),
+            "Invalid size, expected e.g. \"512B\", \"64KB\", \"10MB\" or \"1GB\"",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    item
+}
+
+/// Limits how many requests a handler in a `route.rs` handles at once, using
+/// [`GlobalConcurrencyLimitLayer`](https://docs.rs/tower/latest/tower/limit/struct.GlobalConcurrencyLimitLayer.html).
+///
+/// Further requests wait until one of them is done, e.g. for expensive
+/// exports while the rest of the API is unbounded.
+///
+/// Requires `tower` with the `limit` feature in your dependencies.
+///
+/// ```rust
+/// #[axum_folder_router::concurrency_limit(4)]
+/// pub async fn get() -> &'static str {
+///     "Exported"
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn concurrency_limit(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let limit = parse_macro_input!(attr as syn::LitInt);
+
+    if !limit.base10_parse::<usize>().is_ok_and(|limit| limit > 0) {
+        return syn::Error::new(
+            limit.span(),
+            "Invalid concurrency limit, expected a number of requests like 4",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    item
+}
+
+/// Sets the upload policy of a handler in a `route.rs`, keeping it next to
+/// the handler.
+///
+/// * `limit` - maximum request body size, `"2MB"` by default like axum's
+///   default limit
+/// * `file_limit` - maximum size of each file of a `multipart/form-data`
+///   request
+/// * `accept` - content types of the request, or of the files of a
+///   `multipart/form-data` request, like `"image/png"` or `"image/*"`
+///
+/// Requests with other content types are answered with
+/// `415 Unsupported Media Type`, too large ones with `413 Payload Too Large`.
+/// Multipart requests are buffered up to the `limit` to check their files.
+///
+/// ```rust
+/// #[axum_folder_router::upload(limit = "10MB", file_limit = "2MB", accept = ["image/png", "image/jpeg"])]
+/// pub async fn post(body: axum::body::Bytes) -> String {
+///     format!("Uploaded {} bytes", body.len())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn upload(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_macro_input!(attr as parse::Upload);
+    item
+}
+
+/// Sets the `Cache-Control` header of the responses of a handler in a
+/// `route.rs`, unless the handler sets one itself.
+///
+/// ```rust
+/// #[axum_folder_router::cache_control("public, max-age=300")]
+/// pub async fn get() -> &'static str {
+///     "Cached for 5 minutes"
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn cache_control(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let value = parse_macro_input!(attr as syn::LitStr);
+
+    if !parse::is_header_value(&value.value()) {
+        return syn::Error::new(
+            value.span(),
+            "Invalid Cache-Control header, expected e.g. \"public, max-age=300\"",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    item
+}
+
+/// Gives the successful responses of a handler in a `route.rs` to `GET` &
+/// `HEAD` requests an `ETag` hashed from their body.
+///
+/// Requests with a matching `If-None-Match` header are answered with
+/// `304 Not Modified` & no body, so clients can cheaply revalidate their
+/// caches. The handler still runs, as the body has to be hashed.
+///
+/// ```rust
+/// #[axum_folder_router::etag]
+/// pub async fn get() -> &'static str {
+///     "Rarely changing JSON"
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn etag(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_macro_input!(attr as syn::parse::Nothing);
+    item
+}
+
+/// Rate limits a handler in a `route.rs` per peer IP address, using
+/// [`tower_governor`](https://docs.rs/tower_governor).
+///
+/// * `per_second` - requests replenished per second
+/// * `burst` - maximum requests at once, defaults to `per_second`
+///
+/// Requires the `rate-limit` feature and `tower_governor` in your
+/// dependencies. The peer IP is read from
+/// [`ConnectInfo`](https://docs.rs/axum/latest/axum/extract/struct.ConnectInfo.html),
+/// so serve your app with
+/// `into_make_service_with_connect_info::<SocketAddr>()`. Without it, every
+/// request to the handler is answered with `500 Internal Server Error`.
+///
+/// ```rust,ignore
+/// let app = MyFolderRouter::into_router().with_state(AppState);
+/// let listener = tokio::net::TcpListener::bind("127.0.0.1:3000").await?;
+/// axum::serve(
+///     listener,
+///     app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+/// )
+/// .await?;
+/// ```
+///
+/// ```rust
+/// #[axum_folder_router::rate_limit(per_second = 5, burst = 10)]
+/// pub async fn post() -> &'static str {
+///     "Posted successfully"
+/// }
+/// ```
+#[cfg(feature = "rate-limit")]
+#[proc_macro_attribute]
+pub fn rate_limit(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_macro_input!(attr as parse::RateLimit);
+    item
+}
@@ -0,0 +1,83 @@
+// Checks the responses of routes below an api_key.rs
+use axum::{
+    body::Body,
+    http::{Method, Request, StatusCode},
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState {
+    api_key: String,
+}
+
+#[folder_router("tests/api_key", AppState)]
+struct MyFolderRouter();
+
+async fn status(method: Method, path: &str, key: Option<&str>) -> StatusCode {
+    let app: Router = MyFolderRouter::into_router().with_state(AppState {
+        api_key: "secret".to_string(),
+    });
+    let mut request = Request::builder().method(method).uri(path);
+    if let Some(key) = key {
+        request = request.header("x-api-key", key);
+    }
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+async fn requests_without_a_valid_key_are_unauthorized() {
+    assert_eq!(
+        status(Method::GET, "/admin", None).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status(Method::GET, "/admin", Some("wrong")).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status(Method::DELETE, "/admin", None).await,
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn requests_with_a_valid_key_pass() {
+    assert_eq!(
+        status(Method::GET, "/admin", Some("secret")).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status(Method::DELETE, "/admin", Some("secret")).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn head_requests_are_checked_too() {
+    assert_eq!(
+        status(Method::HEAD, "/admin", None).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status(Method::HEAD, "/admin", Some("secret")).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn missing_methods_are_not_allowed() {
+    assert_eq!(
+        status(Method::POST, "/admin", Some("secret")).await,
+        StatusCode::METHOD_NOT_ALLOWED
+    );
+}
+
+#[tokio::test]
+async fn routes_outside_the_folder_are_public() {
+    assert_eq!(status(Method::GET, "/", None).await, StatusCode::OK);
+}
@@ -0,0 +1,5 @@
+use crate::AppState;
+
+pub async fn validate(state: &AppState, key: &str) -> bool {
+    !state.api_key.is_empty() && key == state.api_key
+}
@@ -0,0 +1,7 @@
+pub async fn get() -> &'static str {
+    "Admin"
+}
+
+pub async fn delete() -> &'static str {
+    "Deleted"
+}
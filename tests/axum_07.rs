@@ -0,0 +1,17 @@
+use std::process::Command;
+
+// Runs the tests of the `tests/axum_07` fixture, which depends on axum 0.7 &
+// the `axum-07` feature, so it can't be built by this crate's test targets
+#[test]
+fn axum_07_fixture() {
+    let status = Command::new(env!("CARGO"))
+        .args([
+            "test",
+            "--quiet",
+            "--manifest-path",
+            "tests/axum_07/Cargo.toml",
+        ])
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
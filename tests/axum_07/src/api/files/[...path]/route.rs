@@ -0,0 +1,5 @@
+use axum::extract::Path;
+
+pub async fn get(Path(path): Path<String>) -> String {
+    format!("File {path}")
+}
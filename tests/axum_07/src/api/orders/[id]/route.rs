@@ -0,0 +1,5 @@
+use axum::extract::Path;
+
+pub async fn get(Path(id): Path<String>) -> String {
+    format!("Order {id}")
+}
@@ -0,0 +1,5 @@
+use axum::extract::Path;
+
+pub async fn get(Path(id): Path<u32>) -> String {
+    format!("User {id}")
+}
@@ -0,0 +1,91 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+pub struct AppState;
+
+#[folder_router("./src/api", AppState)]
+pub struct MyFolderRouter();
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        http::{header, Request, StatusCode},
+        Router,
+    };
+    use tower::ServiceExt;
+
+    use super::{AppState, MyFolderRouter};
+
+    async fn get(path: &str) -> (StatusCode, String) {
+        let app: Router = MyFolderRouter::into_router().with_state(AppState);
+        let request = Request::get(path).body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = match response.headers().get(header::LOCATION) {
+            Some(location) => location.to_str().unwrap().to_string(),
+            None => {
+                let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                String::from_utf8(body.to_vec()).unwrap()
+            }
+        };
+        (status, body)
+    }
+
+    #[test]
+    fn routes_use_colon_syntax() {
+        assert_eq!(MyFolderRouter::ROUTES, &[
+            ("GET", "/", "route.rs"),
+            ("GET", "/admin", "(admin)/admin/route.rs"),
+            ("*", "/docs/*path", "redirects.toml"),
+            ("GET", "/files/*path", "files/[...path]/route.rs"),
+            ("GET", "/orders/:id", "orders/[id]/route.rs"),
+            ("*", "/people/:id", "redirects.toml"),
+            ("GET", "/users/:id", "users/[id]/route.rs"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn path_parameters_are_extracted() {
+        assert_eq!(get("/").await, (StatusCode::OK, "Hello World!".to_string()));
+        assert_eq!(
+            get("/users/7").await,
+            (StatusCode::OK, "User 7".to_string())
+        );
+        assert_eq!(
+            get("/files/a/b.txt").await,
+            (StatusCode::OK, "File a/b.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn routers_merge() {
+        let _: Router<AppState> = axum_folder_router::merge_all!(MyFolderRouter);
+    }
+
+    #[tokio::test]
+    async fn regex_parameters_are_checked() {
+        assert_eq!(
+            get("/orders/12").await,
+            (StatusCode::OK, "Order 12".to_string())
+        );
+        assert_eq!(get("/orders/abc").await.0, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn guards_reject_requests() {
+        assert_eq!(get("/admin").await.0, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn redirects_fill_path_parameters() {
+        assert_eq!(
+            get("/people/3").await,
+            (StatusCode::PERMANENT_REDIRECT, "/users/3".to_string())
+        );
+        assert_eq!(
+            get("/docs/a/b").await,
+            (StatusCode::FOUND, "/files/a/b".to_string())
+        );
+    }
+}
@@ -0,0 +1,106 @@
+// Checks the tokens & cookies of the `csrf` option
+use axum::{
+    body::Body,
+    extract::DefaultBodyLimit,
+    http::{header, Request, StatusCode},
+    response::Response,
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/csrf", AppState, csrf = "/")]
+struct MyFolderRouter();
+
+async fn send(request: Request<Body>) -> Response {
+    let app: Router = MyFolderRouter::into_router()
+        .with_state(AppState)
+        .layer(DefaultBodyLimit::max(128));
+    app.oneshot(request).await.unwrap()
+}
+
+async fn issued_cookie() -> String {
+    let response = send(Request::get("/").body(Body::empty()).unwrap()).await;
+    response.headers()[header::SET_COOKIE]
+        .to_str()
+        .unwrap()
+        .to_owned()
+}
+
+fn form(cookie: &str, body: String) -> Request<Body> {
+    Request::post("/")
+        .header(header::COOKIE, cookie)
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn tokens_are_random_256_bit_secure_cookies() {
+    let cookie = issued_cookie().await;
+    let mut attributes = cookie.split("; ");
+    let token = attributes
+        .next()
+        .unwrap()
+        .strip_prefix("csrf_token=")
+        .unwrap();
+    assert_eq!(token.len(), 64);
+    assert!(token.bytes().all(|byte| byte.is_ascii_hexdigit()));
+    assert_eq!(attributes.collect::<Vec<_>>(), [
+        "Path=/",
+        "Secure",
+        "SameSite=Strict"
+    ]);
+
+    assert_ne!(issued_cookie().await, cookie);
+}
+
+#[tokio::test]
+async fn forms_need_the_token_of_the_cookie() {
+    let cookie = issued_cookie().await;
+    let cookie = cookie.split(';').next().unwrap();
+    let token = cookie.strip_prefix("csrf_token=").unwrap();
+
+    let response = send(form(cookie, format!("_csrf={token}"))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(form(cookie, "_csrf=guessed".to_string())).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn forms_are_read_up_to_the_body_limit() {
+    let cookie = issued_cookie().await;
+    let cookie = cookie.split(';').next().unwrap();
+    let token = cookie.strip_prefix("csrf_token=").unwrap();
+
+    let padding = "a".repeat(128);
+    let response = send(form(cookie, format!("_csrf={token}&name={padding}"))).await;
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn planted_cookies_are_replaced() {
+    let planted = "csrf_token=planted";
+    let response = send(form(planted, "_csrf=planted".to_string())).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send(
+        Request::get("/")
+            .header(header::COOKIE, planted)
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    let cookie = response.headers()[header::SET_COOKIE].to_str().unwrap();
+    let token = cookie
+        .split(';')
+        .next()
+        .unwrap()
+        .strip_prefix("csrf_token=")
+        .unwrap();
+    assert_eq!(token.len(), 64);
+}
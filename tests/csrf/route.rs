@@ -0,0 +1,7 @@
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
+
+pub async fn post() -> &'static str {
+    "Posted!"
+}
@@ -1,4 +1,4 @@
 #[test]
 pub fn expand_snapshot_pass() {
-    macrotest::expand_args("tests/expand/*.rs", &["--features", "nightly,debug"]);
+    macrotest::expand_args("tests/expand/*.rs", &["--features", "nightly,debug,csrf"]);
 }
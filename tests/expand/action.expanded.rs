@@ -0,0 +1,106 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/action", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/action"
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/action"]
+mod __folder_router__myfolderrouter {
+    #[path = "contact"]
+    pub mod contact {
+        #[path = "action.rs"]
+        pub mod action {
+            use axum::{response::Redirect, Form};
+            pub async fn action(Form(email): Form<Vec<(String, String)>>) -> Redirect {
+                let _ = email;
+                Redirect::to("/contact")
+            }
+        }
+        #[path = "page.rs"]
+        pub mod page {
+            use axum::response::Html;
+            pub async fn page() -> Html<&'static str> {
+                Html("<form method=\"post\"><input name=\"email\"></form>")
+            }
+        }
+    }
+    #[path = "newsletter"]
+    pub mod newsletter {
+        #[path = "action.rs"]
+        pub mod action {
+            use axum::http::StatusCode;
+            pub async fn action() -> StatusCode {
+                StatusCode::NO_CONTENT
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("POST", "/contact", "contact/action.rs"),
+        ("GET", "/contact", "contact/page.rs"),
+        ("POST", "/newsletter", "newsletter/action.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_contact(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/contact",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::contact::page::page,
+                        )
+                        .post(__folder_router__myfolderrouter::contact::action::action),
+                );
+            router
+        }
+        router = __register_contact(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_newsletter(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/newsletter",
+                    axum::routing::post(
+                        __folder_router__myfolderrouter::newsletter::action::action,
+                    ),
+                );
+            router
+        }
+        router = __register_newsletter(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("POST  /contact\nGET   /contact\nPOST  /newsletter\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["POST /contact", "GET /contact", "POST /newsletter"];
+        f.debug_list().entries(routes).finish()
+    }
+}
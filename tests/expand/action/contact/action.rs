@@ -0,0 +1,6 @@
+use axum::{response::Redirect, Form};
+
+pub async fn action(Form(email): Form<Vec<(String, String)>>) -> Redirect {
+    let _ = email;
+    Redirect::to("/contact")
+}
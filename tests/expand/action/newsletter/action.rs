@@ -0,0 +1,5 @@
+use axum::http::StatusCode;
+
+pub async fn action() -> StatusCode {
+    StatusCode::NO_CONTENT
+}
@@ -1,17 +1,31 @@
 /// [folder_router] Running folder_router macro attrs:("examples/advanced/api", AppState) item: struct MyFolderRouter();
 /// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/advanced/api"
-/// [folder_router] Found route.rs for axum_path: "/files/{*path}", mod_path: ["files", "___path", "route"]
-/// [folder_router] Found methods for axum_path: "/files/{*path}", mod_path: ["files", "___path", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get", "post"]
+/// [folder_router] Found route.rs for axum_path: "/account", mod_path: ["_auth", "account", "route"]
+/// [folder_router] Found methods for axum_path: "/account", mod_path: ["_auth", "account", "route"], methods: ["get"]
 /// [folder_router] Found route.rs for axum_path: "/files", mod_path: ["files", "route"]
 /// [folder_router] Found methods for axum_path: "/files", mod_path: ["files", "route"], methods: ["get", "post"]
+/// [folder_router] Found route.rs for axum_path: "/files/{*path}", mod_path: ["files", "___path", "route"]
+/// [folder_router] Found methods for axum_path: "/files/{*path}", mod_path: ["files", "___path", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/fixtures", mod_path: ["fixtures", "route_dev"]
+/// [folder_router] Found methods for axum_path: "/fixtures", mod_path: ["fixtures", "route_dev"], methods: ["post"]
+/// [folder_router] Found route.rs for axum_path: "/legacy", mod_path: ["legacy", "route"]
+/// [folder_router] Found methods for axum_path: "/legacy", mod_path: ["legacy", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/orders/{id}", mod_path: ["orders", "__id", "route"]
+/// [folder_router] Found methods for axum_path: "/orders/{id}", mod_path: ["orders", "__id", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/orders/{id}/items/{sku}", mod_path: ["orders", "__id", "items", "__sku", "route"]
+/// [folder_router] Found methods for axum_path: "/orders/{id}/items/{sku}", mod_path: ["orders", "__id", "items", "__sku", "route"], methods: ["get"]
 /// [folder_router] Found route.rs for axum_path: "/ping", mod_path: ["ping", "route"]
 /// [folder_router] Found methods for axum_path: "/ping", mod_path: ["ping", "route"], methods: ["any", "get"]
-/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
-/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get", "post"]
-/// [folder_router] Found route.rs for axum_path: "/users/{:id}", mod_path: ["users", "__id", "route"]
-/// [folder_router] Found methods for axum_path: "/users/{:id}", mod_path: ["users", "__id", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/search", mod_path: ["search", "route"]
+/// [folder_router] Found methods for axum_path: "/search", mod_path: ["search", "route"], methods: []
+/// [folder_router] Found route.rs for axum_path: "/status", mod_path: ["status", "route"]
+/// [folder_router] Found methods for axum_path: "/status", mod_path: ["status", "route"], methods: ["get"]
 /// [folder_router] Found route.rs for axum_path: "/users", mod_path: ["users", "route"]
 /// [folder_router] Found methods for axum_path: "/users", mod_path: ["users", "route"], methods: ["get", "post"]
+/// [folder_router] Found route.rs for axum_path: "/users/{id}", mod_path: ["users", "__id", "route"]
+/// [folder_router] Found methods for axum_path: "/users/{id}", mod_path: ["users", "__id", "route"], methods: ["get", "delete"]
 #![feature(prelude_import)]
 #[macro_use]
 extern crate std;
@@ -33,6 +47,13 @@ impl ::core::clone::Clone for AppState {
 struct MyFolderRouter();
 #[path = "/home/tristand/code/axum-folder-router/examples/advanced/api"]
 mod __folder_router__myfolderrouter {
+    #[path = "method_not_allowed.rs"]
+    pub mod method_not_allowed {
+        use axum::{http::StatusCode, response::IntoResponse};
+        pub async fn method_not_allowed() -> impl IntoResponse {
+            (StatusCode::METHOD_NOT_ALLOWED, "Method not allowed")
+        }
+    }
     #[path = "route.rs"]
     pub mod route {
         use axum::response::{Html, IntoResponse};
@@ -43,6 +64,61 @@ mod __folder_router__myfolderrouter {
             "Posted successfully".into_response()
         }
     }
+    #[path = "(auth)"]
+    pub mod _auth {
+        #[path = "guard.rs"]
+        pub mod guard {
+            use axum::{
+                extract::Request, http::{header, StatusCode},
+                middleware::Next, response::{IntoResponse, Response},
+            };
+            pub async fn guard(request: Request, next: Next) -> Response {
+                if request.headers().contains_key(header::AUTHORIZATION) {
+                    next.run(request).await
+                } else {
+                    StatusCode::UNAUTHORIZED.into_response()
+                }
+            }
+        }
+        #[path = "account"]
+        pub mod account {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::response::{Html, IntoResponse};
+                pub async fn get() -> impl IntoResponse {
+                    Html("<h1>Your Account</h1>").into_response()
+                }
+            }
+        }
+    }
+    #[path = "articles"]
+    pub mod articles {
+        #[path = "resource.rs"]
+        pub mod resource {
+            use axum::{extract::Path, response::IntoResponse};
+            pub async fn index() -> impl IntoResponse {
+                "All articles"
+            }
+            pub async fn create() -> impl IntoResponse {
+                "Article created"
+            }
+            pub async fn show(Path(id): Path<String>) -> impl IntoResponse {
+                ::alloc::__export::must_use({
+                    ::alloc::fmt::format(format_args!("Article {0}", id))
+                })
+            }
+            pub async fn update(Path(id): Path<String>) -> impl IntoResponse {
+                ::alloc::__export::must_use({
+                    ::alloc::fmt::format(format_args!("Article {0} updated", id))
+                })
+            }
+            pub async fn destroy(Path(id): Path<String>) -> impl IntoResponse {
+                ::alloc::__export::must_use({
+                    ::alloc::fmt::format(format_args!("Article {0} deleted", id))
+                })
+            }
+        }
+    }
     #[path = "files"]
     pub mod files {
         #[path = "route.rs"]
@@ -70,6 +146,78 @@ mod __folder_router__myfolderrouter {
             }
         }
     }
+    #[path = "fixtures"]
+    pub mod fixtures {
+        #[path = "route.dev.rs"]
+        pub mod route_dev {
+            use axum::response::IntoResponse;
+            pub async fn post() -> impl IntoResponse {
+                "Fixtures loaded"
+            }
+        }
+    }
+    #[path = "legacy"]
+    pub mod legacy {
+        #[path = "route.rs"]
+        pub mod route {
+            use axum::response::{IntoResponse, Redirect};
+            pub const ROUTE_PATH: &str = "/legacy/users.php";
+            pub async fn get() -> impl IntoResponse {
+                Redirect::permanent("/users")
+            }
+        }
+    }
+    #[path = "metrics"]
+    pub mod metrics {
+        #[path = "router.rs"]
+        pub mod router {
+            use axum::{routing::get, Router};
+            pub fn router<S>() -> Router<S>
+            where
+                S: Clone + Send + Sync + 'static,
+            {
+                Router::new()
+                    .route("/", get(|| async { "requests_total 42" }))
+                    .route("/health", get(|| async { "OK" }))
+            }
+        }
+    }
+    #[path = "orders"]
+    pub mod orders {
+        #[path = "[id@u32]"]
+        pub mod __id {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::{extract::Path, response::IntoResponse};
+                pub const NAME: &str = "order";
+                pub const TAGS: &[&str] = &["orders"];
+                pub async fn get(Path(id): Path<u32>) -> impl IntoResponse {
+                    ::alloc::__export::must_use({
+                        ::alloc::fmt::format(format_args!("Order #{0}", id))
+                    })
+                }
+            }
+            #[path = "items"]
+            pub mod items {
+                #[path = "[sku]"]
+                pub mod __sku {
+                    #[path = "route.rs"]
+                    pub mod route {
+                        use axum::{extract::Path, response::IntoResponse};
+                        pub async fn get(
+                            Path((id, sku)): Path<(u32, String)>,
+                        ) -> impl IntoResponse {
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(
+                                    format_args!("Item {0} of order #{1}", sku, id),
+                                )
+                            })
+                        }
+                    }
+                }
+            }
+        }
+    }
     #[path = "ping"]
     pub mod ping {
         #[path = "route.rs"]
@@ -84,8 +232,61 @@ mod __folder_router__myfolderrouter {
             }
         }
     }
+    #[path = "readme"]
+    pub mod readme {
+        #[path = "service.rs"]
+        pub mod service {
+            use tower_http::services::ServeFile;
+            pub fn service() -> ServeFile {
+                ServeFile::new("README.md")
+            }
+        }
+    }
+    #[path = "search"]
+    pub mod search {
+        #[path = "route.rs"]
+        pub mod route {
+            use axum::routing::{on, MethodFilter, MethodRouter};
+            pub fn method_router<S>() -> MethodRouter<S>
+            where
+                S: Clone + Send + Sync + 'static,
+            {
+                on(
+                    MethodFilter::GET.or(MethodFilter::POST),
+                    || async { "Search results" },
+                )
+            }
+        }
+    }
+    #[path = "status"]
+    pub mod status {
+        #[path = "route.rs"]
+        pub mod route {
+            #![allow(non_snake_case)]
+            use axum::{
+                response::{Html, IntoResponse},
+                Json,
+            };
+            pub async fn get__json() -> impl IntoResponse {
+                Json(["ok"])
+            }
+            pub async fn get__html() -> impl IntoResponse {
+                Html("<p>ok</p>")
+            }
+        }
+    }
     #[path = "users"]
     pub mod users {
+        #[path = "cors.rs"]
+        pub mod cors {
+            use axum::http::Method;
+            use tower_http::cors::{Any, CorsLayer};
+            pub fn cors() -> CorsLayer {
+                CorsLayer::new()
+                    .allow_methods([Method::GET, Method::POST])
+                    .allow_origin(Any)
+            }
+        }
         #[path = "route.rs"]
         pub mod route {
             use axum::response::{Html, IntoResponse};
@@ -95,6 +296,10 @@ mod __folder_router__myfolderrouter {
             pub async fn post() -> impl IntoResponse {
                 "Posted successfully".into_response()
             }
+            #[allow(dead_code)]
+            pub async fn delete() -> impl IntoResponse {
+                "Deleted successfully".into_response()
+            }
         }
         #[path = "[id]"]
         pub mod __id {
@@ -106,51 +311,463 @@ mod __folder_router__myfolderrouter {
                         ::alloc::fmt::format(format_args!("User ID: {0}", id))
                     })
                 }
+                pub async fn delete(Path(id): Path<String>) -> impl IntoResponse {
+                    ::alloc::__export::must_use({
+                        ::alloc::fmt::format(format_args!("Deleted user {0}", id))
+                    })
+                }
             }
         }
     }
+    const _: &[u8] = b"[A-Z]{3}-[0-9]+\n";
 }
 impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("POST", "/", "route.rs"),
+        ("GET", "/account", "(auth)/account/route.rs"),
+        ("GET", "/articles", "articles/resource.rs"),
+        ("POST", "/articles", "articles/resource.rs"),
+        ("GET", "/articles/{id}", "articles/resource.rs"),
+        ("PUT", "/articles/{id}", "articles/resource.rs"),
+        ("PATCH", "/articles/{id}", "articles/resource.rs"),
+        ("DELETE", "/articles/{id}", "articles/resource.rs"),
+        ("GET", "/files", "files/route.rs"),
+        ("POST", "/files", "files/route.rs"),
+        ("GET", "/files/{*path}", "files/[...path]/route.rs"),
+        ("POST", "/fixtures", "fixtures/route.dev.rs"),
+        ("GET", "/legacy/users.php", "legacy/route.rs"),
+        ("*", "/metrics", "metrics/router.rs"),
+        ("GET", "/orders/{id}", "orders/[id@u32]/route.rs"),
+        ("GET", "/orders/{id}/items/{sku}", "orders/[id@u32]/items/[sku]/route.rs"),
+        ("ANY", "/ping", "ping/route.rs"),
+        ("GET", "/ping", "ping/route.rs"),
+        ("GET", "/public", "public/"),
+        ("*", "/readme", "readme/service.rs"),
+        ("*", "/search", "search/route.rs"),
+        ("GET", "/status", "status/route.rs"),
+        ("GET", "/users", "users/route.rs"),
+        ("POST", "/users", "users/route.rs"),
+        ("GET", "/users/{id}", "users/[id]/route.rs"),
+        ("DELETE", "/users/{id}", "users/[id]/route.rs"),
+    ] as &[(&str, &str, &str)];
     pub fn into_router() -> axum::Router<AppState> {
         let mut router = axum::Router::new();
-        router = router
-            .route(
-                "/files/{*path}",
-                axum::routing::get(
-                    __folder_router__myfolderrouter::files::___path::route::get,
-                ),
-            );
-        router = router
-            .route(
-                "/files",
-                axum::routing::get(__folder_router__myfolderrouter::files::route::get)
-                    .post(__folder_router__myfolderrouter::files::route::post),
-            );
-        router = router
-            .route(
-                "/ping",
-                axum::routing::any(__folder_router__myfolderrouter::ping::route::any)
-                    .get(__folder_router__myfolderrouter::ping::route::get),
-            );
         router = router
             .route(
                 "/",
                 axum::routing::get(__folder_router__myfolderrouter::route::get)
-                    .post(__folder_router__myfolderrouter::route::post),
+                    .post(__folder_router__myfolderrouter::route::post)
+                    .fallback(
+                        __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                    ),
             );
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register__auth(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/account",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::_auth::account::route::get,
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        )
+                        .route_layer(
+                            axum::middleware::from_fn(
+                                __folder_router__myfolderrouter::_auth::guard::guard,
+                            ),
+                        ),
+                );
+            router
+        }
+        router = __register__auth(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_files(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/files",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::files::route::get,
+                        )
+                        .post(
+                            axum::handler::Handler::layer(
+                                __folder_router__myfolderrouter::files::route::post,
+                                axum::extract::DefaultBodyLimit::max(10485760usize),
+                            ),
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        ),
+                );
+            router = router
+                .route(
+                    "/files/{*path}",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::files::___path::route::get,
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        ),
+                );
+            router
+        }
+        router = __register_files(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_fixtures(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            {
+                router = router
+                    .route(
+                        "/fixtures",
+                        axum::routing::post(
+                                __folder_router__myfolderrouter::fixtures::route_dev::post,
+                            )
+                            .fallback(
+                                __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                            ),
+                    );
+            }
+            router
+        }
+        router = __register_fixtures(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_legacy(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    __folder_router__myfolderrouter::legacy::route::ROUTE_PATH,
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::legacy::route::get,
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        ),
+                );
+            router
+        }
+        router = __register_legacy(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_orders(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/orders/{id}",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::orders::__id::route::get,
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        ),
+                );
+            let _: &str = __folder_router__myfolderrouter::orders::__id::route::NAME;
+            let _: &[&str] = __folder_router__myfolderrouter::orders::__id::route::TAGS;
+            router = router
+                .route(
+                    "/orders/{id}/items/{sku}",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::orders::__id::items::__sku::route::get,
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        )
+                        .route_layer(
+                            axum::middleware::from_fn(|
+                                params: axum::extract::RawPathParams,
+                                request: axum::extract::Request,
+                                next: axum::middleware::Next|
+                            async move {
+                                for (name, value) in &params {
+                                    static SKU_REGEX: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(||
+                                    regex::Regex::new("^(?:[A-Z]{3}-[0-9]+)$").unwrap());
+                                    if name == "sku" && !SKU_REGEX.is_match(value) {
+                                        return axum::response::IntoResponse::into_response(
+                                            axum::http::StatusCode::NOT_FOUND,
+                                        );
+                                    }
+                                }
+                                next.run(request).await
+                            }),
+                        ),
+                );
+            router
+        }
+        router = __register_orders(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_ping(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/ping",
+                    axum::routing::any(__folder_router__myfolderrouter::ping::route::any)
+                        .get(
+                            axum::handler::Handler::layer(
+                                __folder_router__myfolderrouter::ping::route::get,
+                                tower_http::timeout::TimeoutLayer::with_status_code(
+                                    axum::http::StatusCode::REQUEST_TIMEOUT,
+                                    std::time::Duration::from_millis(5000u64),
+                                ),
+                            ),
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        ),
+                );
+            router
+        }
+        router = __register_ping(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_search(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/search",
+                    __folder_router__myfolderrouter::search::route::method_router(),
+                );
+            router
+        }
+        router = __register_search(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_status(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/status",
+                    axum::routing::get(|
+                            axum::extract::State(state): axum::extract::State<AppState>,
+                            request: axum::extract::Request|
+                        async move {
+                            const MEDIA_TYPES: &[&str] = &[
+                                "application/json",
+                                "text/html",
+                            ];
+                            let accept = request
+                                .headers()
+                                .get(axum::http::header::ACCEPT)
+                                .and_then(|accept| accept.to_str().ok())
+                                .unwrap_or("*/*");
+                            let mut best: Option<(usize, f32)> = None;
+                            for media_range in accept.split(',') {
+                                let mut parts = media_range.split(';');
+                                let media_range = parts.next().unwrap_or_default().trim();
+                                let quality: f32 = parts
+                                    .find_map(|param| param.trim().strip_prefix("q="))
+                                    .and_then(|quality| quality.parse().ok())
+                                    .unwrap_or(1.0);
+                                if quality <= 0.0 {
+                                    continue;
+                                }
+                                for (index, media_type) in MEDIA_TYPES.iter().enumerate() {
+                                    let is_match = media_range == "*/*"
+                                        || media_range.eq_ignore_ascii_case(media_type)
+                                        || media_range
+                                            .strip_suffix("/*")
+                                            .is_some_and(|ty| media_type.split('/').next() == Some(ty));
+                                    if is_match
+                                        && best
+                                            .map_or(true, |(_, best_quality)| quality > best_quality)
+                                    {
+                                        best = Some((index, quality));
+                                    }
+                                }
+                            }
+                            let mut response = match best.map(|(index, _)| index) {
+                                Some(0usize) => {
+                                    axum::handler::Handler::call(
+                                            __folder_router__myfolderrouter::status::route::get__json,
+                                            request,
+                                            state,
+                                        )
+                                        .await
+                                }
+                                Some(1usize) => {
+                                    axum::handler::Handler::call(
+                                            __folder_router__myfolderrouter::status::route::get__html,
+                                            request,
+                                            state,
+                                        )
+                                        .await
+                                }
+                                _ => {
+                                    axum::response::IntoResponse::into_response(
+                                        axum::http::StatusCode::NOT_ACCEPTABLE,
+                                    )
+                                }
+                            };
+                            response
+                                .headers_mut()
+                                .append(
+                                    axum::http::header::VARY,
+                                    axum::http::HeaderValue::from_static("accept"),
+                                );
+                            response
+                        })
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        ),
+                );
+            router
+        }
+        router = __register_status(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_users(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/users",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::users::route::get,
+                        )
+                        .post(__folder_router__myfolderrouter::users::route::post)
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        )
+                        .layer(__folder_router__myfolderrouter::users::cors::cors()),
+                );
+            router = router
+                .route(
+                    "/users/{id}",
+                    {
+                        let method_router = axum::routing::MethodRouter::new();
+                        let method_router = method_router
+                            .get(
+                                __folder_router__myfolderrouter::users::__id::route::get,
+                            );
+                        let method_router = method_router
+                            .delete(
+                                __folder_router__myfolderrouter::users::__id::route::delete,
+                            );
+                        method_router
+                    }
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        )
+                        .layer(__folder_router__myfolderrouter::users::cors::cors()),
+                );
+            router
+        }
+        router = __register_users(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_articles(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/articles",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::articles::resource::index,
+                        )
+                        .post(
+                            __folder_router__myfolderrouter::articles::resource::create,
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        ),
+                );
+            router = router
+                .route(
+                    "/articles/{id}",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::articles::resource::show,
+                        )
+                        .put(__folder_router__myfolderrouter::articles::resource::update)
+                        .patch(
+                            __folder_router__myfolderrouter::articles::resource::update,
+                        )
+                        .delete(
+                            __folder_router__myfolderrouter::articles::resource::destroy,
+                        )
+                        .fallback(
+                            __folder_router__myfolderrouter::method_not_allowed::method_not_allowed,
+                        ),
+                );
+            router
+        }
+        router = __register_articles(router);
         router = router
-            .route(
-                "/users/{:id}",
-                axum::routing::get(
-                    __folder_router__myfolderrouter::users::__id::route::get,
+            .nest_service(
+                "/public",
+                tower_http::services::ServeDir::new(
+                    "/home/tristand/code/axum-folder-router/examples/advanced/api/public",
                 ),
             );
         router = router
-            .route(
-                "/users",
-                axum::routing::get(__folder_router__myfolderrouter::users::route::get)
-                    .post(__folder_router__myfolderrouter::users::route::post),
+            .nest_service(
+                "/readme",
+                __folder_router__myfolderrouter::readme::service::service(),
+            );
+        router = router
+            .nest(
+                "/metrics",
+                __folder_router__myfolderrouter::metrics::router::router(),
             );
         router
     }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str(
+            "GET     /\nPOST    /\nGET     /account\nGET     /articles\nPOST    /articles\nGET     /articles/{id}\nPUT     /articles/{id}\nPATCH   /articles/{id}\nDELETE  /articles/{id}\nGET     /files\nPOST    /files\nGET     /files/{*path}\nPOST    /fixtures\nGET     /legacy/users.php\n*       /metrics\nGET     /orders/{id}\nGET     /orders/{id}/items/{sku}\nANY     /ping\nGET     /ping\nGET     /public\n*       /readme\n*       /search\nGET     /status\nGET     /users\nPOST    /users\nGET     /users/{id}\nDELETE  /users/{id}\n",
+        )
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /",
+            "POST /",
+            "GET /account",
+            "GET /articles",
+            "POST /articles",
+            "GET /articles/{id}",
+            "PUT /articles/{id}",
+            "PATCH /articles/{id}",
+            "DELETE /articles/{id}",
+            "GET /files",
+            "POST /files",
+            "GET /files/{*path}",
+            "POST /fixtures",
+            "GET /legacy/users.php",
+            "* /metrics",
+            "GET /orders/{id}",
+            "GET /orders/{id}/items/{sku}",
+            "ANY /ping",
+            "GET /ping",
+            "GET /public",
+            "* /readme",
+            "* /search",
+            "GET /status",
+            "GET /users",
+            "POST /users",
+            "GET /users/{id}",
+            "DELETE /users/{id}",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
 }
@@ -0,0 +1,236 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/api_key", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/api_key"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/admin", mod_path: ["admin", "route"]
+/// [folder_router] Found methods for axum_path: "/admin", mod_path: ["admin", "route"], methods: ["get", "delete"]
+/// [folder_router] Found route.rs for axum_path: "/admin/reports", mod_path: ["admin", "reports", "route"]
+/// [folder_router] Found methods for axum_path: "/admin/reports", mod_path: ["admin", "reports", "route"], methods: ["post"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/api_key"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "admin"]
+    pub mod admin {
+        #[path = "api_key.rs"]
+        pub mod api_key {
+            use crate::AppState;
+            pub const HEADER: &str = "x-admin-key";
+            pub async fn validate(_state: &AppState, key: &str) -> bool {
+                key == "secret"
+            }
+        }
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Admin"
+            }
+            pub async fn delete() -> &'static str {
+                "Deleted"
+            }
+        }
+        #[path = "reports"]
+        pub mod reports {
+            #[path = "page.rs"]
+            pub mod page {
+                use axum::response::Html;
+                pub async fn page() -> Html<&'static str> {
+                    Html("<h1>Reports</h1>")
+                }
+            }
+            #[path = "route.rs"]
+            pub mod route {
+                pub async fn post() -> &'static str {
+                    "Report created"
+                }
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/admin", "admin/route.rs"),
+        ("DELETE", "/admin", "admin/route.rs"),
+        ("POST", "/admin/reports", "admin/reports/route.rs"),
+        ("GET", "/admin/reports", "admin/reports/page.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_admin(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/admin",
+                    {
+                        let method_router = axum::routing::get(
+                                __folder_router__myfolderrouter::admin::route::get,
+                            )
+                            .delete(
+                                __folder_router__myfolderrouter::admin::route::delete,
+                            );
+                        let check = move |
+                            axum::extract::State(state): axum::extract::State<AppState>,
+                            request: axum::extract::Request|
+                        async move {
+                            let key = request
+                                .headers()
+                                .get(
+                                    __folder_router__myfolderrouter::admin::api_key::HEADER,
+                                )
+                                .and_then(|key| key.to_str().ok());
+                            let valid = match key {
+                                Some(key) => {
+                                    __folder_router__myfolderrouter::admin::api_key::validate(
+                                            &state,
+                                            key,
+                                        )
+                                        .await
+                                }
+                                None => false,
+                            };
+                            if !valid {
+                                return axum::response::IntoResponse::into_response(
+                                    axum::http::StatusCode::UNAUTHORIZED,
+                                );
+                            }
+                            axum::handler::Handler::call(method_router, request, state)
+                                .await
+                        };
+                        axum::routing::on(
+                            axum::routing::MethodFilter::GET
+                                .or(axum::routing::MethodFilter::DELETE),
+                            check,
+                        )
+                    },
+                );
+            router = router
+                .route(
+                    "/admin/reports",
+                    {
+                        let method_router = axum::routing::post(
+                            __folder_router__myfolderrouter::admin::reports::route::post,
+                        );
+                        let check = move |
+                            axum::extract::State(state): axum::extract::State<AppState>,
+                            request: axum::extract::Request|
+                        async move {
+                            let key = request
+                                .headers()
+                                .get(
+                                    __folder_router__myfolderrouter::admin::api_key::HEADER,
+                                )
+                                .and_then(|key| key.to_str().ok());
+                            let valid = match key {
+                                Some(key) => {
+                                    __folder_router__myfolderrouter::admin::api_key::validate(
+                                            &state,
+                                            key,
+                                        )
+                                        .await
+                                }
+                                None => false,
+                            };
+                            if !valid {
+                                return axum::response::IntoResponse::into_response(
+                                    axum::http::StatusCode::UNAUTHORIZED,
+                                );
+                            }
+                            axum::handler::Handler::call(method_router, request, state)
+                                .await
+                        };
+                        axum::routing::on(axum::routing::MethodFilter::POST, check)
+                    },
+                );
+            router = router
+                .route(
+                    "/admin/reports",
+                    {
+                        let method_router = axum::routing::get(
+                            __folder_router__myfolderrouter::admin::reports::page::page,
+                        );
+                        let check = move |
+                            axum::extract::State(state): axum::extract::State<AppState>,
+                            request: axum::extract::Request|
+                        async move {
+                            let key = request
+                                .headers()
+                                .get(
+                                    __folder_router__myfolderrouter::admin::api_key::HEADER,
+                                )
+                                .and_then(|key| key.to_str().ok());
+                            let valid = match key {
+                                Some(key) => {
+                                    __folder_router__myfolderrouter::admin::api_key::validate(
+                                            &state,
+                                            key,
+                                        )
+                                        .await
+                                }
+                                None => false,
+                            };
+                            if !valid {
+                                return axum::response::IntoResponse::into_response(
+                                    axum::http::StatusCode::UNAUTHORIZED,
+                                );
+                            }
+                            axum::handler::Handler::call(method_router, request, state)
+                                .await
+                        };
+                        axum::routing::on(axum::routing::MethodFilter::GET, check)
+                    },
+                );
+            router
+        }
+        router = __register_admin(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str(
+            "GET     /\nGET     /admin\nDELETE  /admin\nPOST    /admin/reports\nGET     /admin/reports\n",
+        )
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /",
+            "GET /admin",
+            "DELETE /admin",
+            "POST /admin/reports",
+            "GET /admin/reports",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
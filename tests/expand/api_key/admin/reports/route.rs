@@ -0,0 +1,3 @@
+pub async fn post() -> &'static str {
+    "Report created"
+}
@@ -0,0 +1,73 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/cache_control", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/cache_control"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/cache_control"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route(
+                "/",
+                axum::routing::get(
+                    axum::handler::Handler::layer(
+                        __folder_router__myfolderrouter::route::get,
+                        axum::middleware::map_response(|
+                            mut response: axum::response::Response|
+                        async move {
+                            response
+                                .headers_mut()
+                                .entry(axum::http::header::CACHE_CONTROL)
+                                .or_insert(const {
+                                    axum::http::HeaderValue::from_static("public, max-age=300")
+                                });
+                            response
+                        }),
+                    ),
+                ),
+            );
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /"];
+        f.debug_list().entries(routes).finish()
+    }
+}
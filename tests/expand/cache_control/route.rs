@@ -0,0 +1,4 @@
+#[axum_folder_router::cache_control("public, max-age=300")]
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
@@ -0,0 +1,199 @@
+/// [folder_router] Running folder_router macro attrs:("examples/versioned/api", AppState, config = true) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/versioned/api"
+/// [folder_router] Found route.rs for axum_path: "/v1/users", mod_path: ["v1", "users", "route"]
+/// [folder_router] Found methods for axum_path: "/v1/users", mod_path: ["v1", "users", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/v2/user_settings", mod_path: ["v2", "user_settings", "route"]
+/// [folder_router] Found methods for axum_path: "/v2/user_settings", mod_path: ["v2", "user_settings", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/v2/users", mod_path: ["v2", "users", "route"]
+/// [folder_router] Found methods for axum_path: "/v2/users", mod_path: ["v2", "users", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/examples/versioned/api"]
+mod __folder_router__myfolderrouter {
+    #[path = "v1"]
+    pub mod v1 {
+        #[path = "users"]
+        pub mod users {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::response::IntoResponse;
+                pub async fn get() -> impl IntoResponse {
+                    "alice, bob"
+                }
+            }
+        }
+    }
+    #[path = "v2"]
+    pub mod v2 {
+        #[path = "user_settings"]
+        pub mod user_settings {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::{response::IntoResponse, Json};
+                pub async fn get() -> impl IntoResponse {
+                    Json(["dark_mode"])
+                }
+            }
+        }
+        #[path = "users"]
+        pub mod users {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::{response::IntoResponse, Json};
+                pub async fn get() -> impl IntoResponse {
+                    Json(["alice", "bob"])
+                }
+            }
+        }
+    }
+}
+/// Route subtrees to leave out of the router at startup
+pub struct FolderRouterConfig {
+    disabled: Vec<String>,
+}
+#[automatically_derived]
+impl ::core::clone::Clone for FolderRouterConfig {
+    #[inline]
+    fn clone(&self) -> FolderRouterConfig {
+        FolderRouterConfig {
+            disabled: ::core::clone::Clone::clone(&self.disabled),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for FolderRouterConfig {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field1_finish(
+            f,
+            "FolderRouterConfig",
+            "disabled",
+            &&self.disabled,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::default::Default for FolderRouterConfig {
+    #[inline]
+    fn default() -> FolderRouterConfig {
+        FolderRouterConfig {
+            disabled: ::core::default::Default::default(),
+        }
+    }
+}
+impl FolderRouterConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Disables all routes at or below `prefix`, e.g. `/admin`
+    pub fn disable(mut self, prefix: impl Into<String>) -> Self {
+        self.disabled.push(prefix.into());
+        self
+    }
+    pub fn is_enabled(&self, path: &str) -> bool {
+        !self
+            .disabled
+            .iter()
+            .any(|prefix| {
+                let prefix = prefix.trim_end_matches('/');
+                prefix.is_empty() || path == prefix
+                    || path
+                        .strip_prefix(prefix)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/v1/users", "v1/users/route.rs"),
+        ("GET", "/v2/user_settings", "v2/user_settings/route.rs"),
+        ("GET", "/v2/users", "v2/users/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        Self::into_router_with_config(FolderRouterConfig::default())
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+    pub fn into_router_with_config(
+        config: FolderRouterConfig,
+    ) -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_v1(
+            mut router: axum::Router<AppState>,
+            config: &FolderRouterConfig,
+        ) -> axum::Router<AppState> {
+            if config.is_enabled("/v1/users") {
+                router = router
+                    .route(
+                        "/v1/users",
+                        axum::routing::get(
+                            __folder_router__myfolderrouter::v1::users::route::get,
+                        ),
+                    );
+            }
+            router
+        }
+        router = __register_v1(router, &config);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_v2(
+            mut router: axum::Router<AppState>,
+            config: &FolderRouterConfig,
+        ) -> axum::Router<AppState> {
+            if config.is_enabled("/v2/user_settings") {
+                router = router
+                    .route(
+                        "/v2/user_settings",
+                        axum::routing::get(
+                            __folder_router__myfolderrouter::v2::user_settings::route::get,
+                        ),
+                    );
+            }
+            if config.is_enabled("/v2/users") {
+                router = router
+                    .route(
+                        "/v2/users",
+                        axum::routing::get(
+                            __folder_router__myfolderrouter::v2::users::route::get,
+                        ),
+                    );
+            }
+            router
+        }
+        router = __register_v2(router, &config);
+        router
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /v1/users\nGET  /v2/user_settings\nGET  /v2/users\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /v1/users",
+            "GET /v2/user_settings",
+            "GET /v2/users",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
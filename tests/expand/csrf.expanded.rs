@@ -0,0 +1,261 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/csrf", AppState, csrf = "/account") item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/csrf"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/csrf"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "account"]
+    pub mod account {
+        #[path = "action.rs"]
+        pub mod action {
+            pub async fn action() -> &'static str {
+                "Saved"
+            }
+        }
+        #[path = "page.rs"]
+        pub mod page {
+            use axum::{response::Html, Extension};
+            use crate::CsrfToken;
+            pub async fn page(
+                Extension(CsrfToken(token)): Extension<CsrfToken>,
+            ) -> Html<String> {
+                Html(
+                    ::alloc::__export::must_use({
+                        ::alloc::fmt::format(
+                            format_args!(
+                                "<form method=\"post\"><input type=\"hidden\" name=\"_csrf\" value=\"{0}\"></form>",
+                                token,
+                            ),
+                        )
+                    }),
+                )
+            }
+        }
+    }
+}
+/// The CSRF token of a request, to be submitted as the `_csrf` field
+/// of forms or in the `x-csrf-token` header
+pub struct CsrfToken(pub String);
+#[automatically_derived]
+impl ::core::clone::Clone for CsrfToken {
+    #[inline]
+    fn clone(&self) -> CsrfToken {
+        CsrfToken(::core::clone::Clone::clone(&self.0))
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for CsrfToken {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "CsrfToken", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for CsrfToken {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for CsrfToken {
+    #[inline]
+    fn eq(&self, other: &CsrfToken) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for CsrfToken {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_receiver_is_total_eq(&self) -> () {
+        let _: ::core::cmp::AssertParamIsEq<String>;
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("POST", "/account", "account/action.rs"),
+        ("GET", "/account", "account/page.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        async fn check_csrf(
+            mut request: axum::extract::Request,
+            next: axum::middleware::Next,
+        ) -> axum::response::Response {
+            use axum::http::{header, HeaderValue, Method, StatusCode};
+            let cookie = request
+                .headers()
+                .get_all(header::COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(';'))
+                .find_map(|cookie| cookie.trim().strip_prefix("csrf_token="))
+                .filter(|token| {
+                    token.len() == 64
+                        && token
+                            .bytes()
+                            .all(|byte| {
+                                #[allow(non_exhaustive_omitted_patterns)]
+                                match byte {
+                                    b'0'..=b'9' | b'a'..=b'f' => true,
+                                    _ => false,
+                                }
+                            })
+                })
+                .map(ToString::to_string);
+            if !#[allow(non_exhaustive_omitted_patterns)]
+            match *request.method() {
+                Method::GET | Method::HEAD | Method::OPTIONS => true,
+                _ => false,
+            } {
+                let mut submitted = request
+                    .headers()
+                    .get("x-csrf-token")
+                    .and_then(|value| value.to_str().ok())
+                    .map(ToString::to_string);
+                if submitted.is_none() {
+                    (request, submitted) = match form_field(request, "_csrf").await {
+                        Ok(field) => field,
+                        Err(response) => return response,
+                    };
+                }
+                let valid = cookie
+                    .as_ref()
+                    .zip(submitted)
+                    .is_some_and(|(cookie, submitted)| {
+                        cookie.len() == submitted.len()
+                            && cookie
+                                .bytes()
+                                .zip(submitted.bytes())
+                                .fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+                    });
+                if !valid {
+                    return axum::response::IntoResponse::into_response(
+                        StatusCode::FORBIDDEN,
+                    );
+                }
+            }
+            let issued = cookie.is_none();
+            let token = if let Some(cookie) = cookie {
+                cookie
+            } else {
+                let mut bytes = [0u8; 32];
+                if getrandom::fill(&mut bytes).is_err() {
+                    return axum::response::IntoResponse::into_response(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    );
+                }
+                bytes
+                    .iter()
+                    .map(|byte| ::alloc::__export::must_use({
+                        ::alloc::fmt::format(format_args!("{0:02x}", byte))
+                    }))
+                    .collect()
+            };
+            request.extensions_mut().insert(CsrfToken(token.clone()));
+            let mut response = next.run(request).await;
+            if issued {
+                let cookie = ::alloc::__export::must_use({
+                    ::alloc::fmt::format(
+                        format_args!(
+                            "csrf_token={0}; Path=/; Secure; SameSite=Strict",
+                            token,
+                        ),
+                    )
+                });
+                response
+                    .headers_mut()
+                    .append(header::SET_COOKIE, HeaderValue::from_str(&cookie).unwrap());
+            }
+            response
+        }
+        async fn form_field(
+            request: axum::extract::Request,
+            field: &str,
+        ) -> Result<(axum::extract::Request, Option<String>), axum::response::Response> {
+            let is_form = request
+                .headers()
+                .get(axum::http::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| {
+                    value.starts_with("application/x-www-form-urlencoded")
+                });
+            if !is_form {
+                return Ok((request, None));
+            }
+            let (parts, body) = axum::RequestExt::with_limited_body(request)
+                .into_parts();
+            let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+                return Err(
+                    axum::response::IntoResponse::into_response(
+                        axum::http::StatusCode::PAYLOAD_TOO_LARGE,
+                    ),
+                );
+            };
+            let value = String::from_utf8_lossy(&bytes)
+                .split('&')
+                .find_map(|pair| pair.strip_prefix(field)?.strip_prefix('='))
+                .map(ToString::to_string);
+            let request = axum::extract::Request::from_parts(
+                parts,
+                axum::body::Body::from(bytes),
+            );
+            Ok((request, value))
+        }
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_account(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/account",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::account::page::page,
+                        )
+                        .post(__folder_router__myfolderrouter::account::action::action)
+                        .route_layer(axum::middleware::from_fn(check_csrf)),
+                );
+            router
+        }
+        router = __register_account(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET   /\nPOST  /account\nGET   /account\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "POST /account", "GET /account"];
+        f.debug_list().entries(routes).finish()
+    }
+}
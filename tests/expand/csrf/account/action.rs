@@ -0,0 +1,3 @@
+pub async fn action() -> &'static str {
+    "Saved"
+}
@@ -0,0 +1,9 @@
+use axum::{response::Html, Extension};
+
+use crate::CsrfToken;
+
+pub async fn page(Extension(CsrfToken(token)): Extension<CsrfToken>) -> Html<String> {
+    Html(format!(
+        "<form method=\"post\"><input type=\"hidden\" name=\"_csrf\" value=\"{token}\"></form>"
+    ))
+}
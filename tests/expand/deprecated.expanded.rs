@@ -0,0 +1,148 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/deprecated", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/deprecated"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get", "post"]
+/// [folder_router] Found route.rs for axum_path: "/legacy", mod_path: ["legacy", "route"]
+/// [folder_router] Found methods for axum_path: "/legacy", mod_path: ["legacy", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/deprecated"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        #[deprecated = "Use POST instead"]
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+        pub async fn post() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "legacy"]
+    pub mod legacy {
+        #[path = "route.rs"]
+        pub mod route {
+            pub const DEPRECATED: bool = true;
+            pub const SUNSET: &str = "Fri, 01 Jan 2027 00:00:00 GMT";
+            pub const DEPRECATION_LINK: &str = "https://example.com/migrating";
+            pub async fn get() -> &'static str {
+                "Legacy"
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("POST", "/", "route.rs"),
+        ("GET", "/legacy", "legacy/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        #[allow(deprecated)]
+        {
+            router = router
+                .route(
+                    "/",
+                    axum::routing::get(
+                            axum::handler::Handler::layer(
+                                __folder_router__myfolderrouter::route::get,
+                                axum::middleware::map_response(|
+                                    mut response: axum::response::Response|
+                                async move {
+                                    response
+                                        .headers_mut()
+                                        .insert(
+                                            axum::http::header::HeaderName::from_static("deprecation"),
+                                            const { axum::http::HeaderValue::from_static("true") },
+                                        );
+                                    response
+                                }),
+                            ),
+                        )
+                        .post(__folder_router__myfolderrouter::route::post),
+                );
+        }
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_legacy(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/legacy",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::legacy::route::get,
+                        )
+                        .layer(
+                            axum::middleware::map_response(|
+                                mut response: axum::response::Response|
+                            async move {
+                                response
+                                    .headers_mut()
+                                    .insert(
+                                        axum::http::header::HeaderName::from_static("deprecation"),
+                                        const { axum::http::HeaderValue::from_static("true") },
+                                    );
+                                response
+                                    .headers_mut()
+                                    .insert(
+                                        axum::http::header::HeaderName::from_static("sunset"),
+                                        const {
+                                            axum::http::HeaderValue::from_static(
+                                                "Fri, 01 Jan 2027 00:00:00 GMT",
+                                            )
+                                        },
+                                    );
+                                response
+                                    .headers_mut()
+                                    .insert(
+                                        axum::http::header::HeaderName::from_static("link"),
+                                        const {
+                                            axum::http::HeaderValue::from_static(
+                                                "<https://example.com/migrating>; rel=\"deprecation\"",
+                                            )
+                                        },
+                                    );
+                                response
+                            }),
+                        ),
+                );
+            let _: bool = __folder_router__myfolderrouter::legacy::route::DEPRECATED;
+            let _: &str = __folder_router__myfolderrouter::legacy::route::SUNSET;
+            let _: &str = __folder_router__myfolderrouter::legacy::route::DEPRECATION_LINK;
+            router
+        }
+        router = __register_legacy(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET   /\nPOST  /\nGET   /legacy\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "POST /", "GET /legacy"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,7 @@
+pub const DEPRECATED: bool = true;
+pub const SUNSET: &str = "Fri, 01 Jan 2027 00:00:00 GMT";
+pub const DEPRECATION_LINK: &str = "https://example.com/migrating";
+
+pub async fn get() -> &'static str {
+    "Legacy"
+}
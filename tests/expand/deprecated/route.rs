@@ -0,0 +1,8 @@
+#[deprecated = "Use POST instead"]
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
+
+pub async fn post() -> &'static str {
+    "Hello World!"
+}
@@ -0,0 +1,60 @@
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/simple/api"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::FolderRouter;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+#[folder_router(path = "examples/simple/api", state = AppState, auto_head = true)]
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/examples/simple/api"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        use axum::response::{Html, IntoResponse};
+        pub async fn get() -> impl IntoResponse {
+            Html("<h1>Hello World!</h1>").into_response()
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route(
+                "/",
+                axum::routing::get(__folder_router__myfolderrouter::route::get)
+                    .head(__folder_router__myfolderrouter::route::get),
+            );
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,8 @@
+use axum_folder_router::FolderRouter;
+
+#[derive(Clone)]
+struct AppState;
+
+#[derive(FolderRouter)]
+#[folder_router(path = "examples/simple/api", state = AppState, auto_head = true)]
+struct MyFolderRouter();
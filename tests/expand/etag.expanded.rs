@@ -0,0 +1,246 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/etag", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/etag"
+/// [folder_router] Found route.rs for axum_path: "/static", mod_path: ["r#static", "route"]
+/// [folder_router] Found methods for axum_path: "/static", mod_path: ["r#static", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/users", mod_path: ["users", "route"]
+/// [folder_router] Found methods for axum_path: "/users", mod_path: ["users", "route"], methods: ["get", "post"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/etag"]
+mod __folder_router__myfolderrouter {
+    #[path = "static"]
+    pub mod r#static {
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Hello World!"
+            }
+        }
+    }
+    #[path = "users"]
+    pub mod users {
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "[]"
+            }
+            pub async fn post() -> &'static str {
+                "Created"
+            }
+        }
+    }
+    const _: &[u8] = b"etag = true\n";
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/static", "static/route.rs"),
+        ("GET", "/users", "users/route.rs"),
+        ("POST", "/users", "users/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_static(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/static",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::r#static::route::get,
+                        )
+                        .layer(
+                            axum::middleware::from_fn(|
+                                request: axum::extract::Request,
+                                next: axum::middleware::Next|
+                            async move {
+                                use axum::http::{header, HeaderValue, Method, StatusCode};
+                                let cacheable = #[allow(non_exhaustive_omitted_patterns)]
+                                match *request.method() {
+                                    Method::GET | Method::HEAD => true,
+                                    _ => false,
+                                };
+                                let if_none_match = request
+                                    .headers()
+                                    .get(header::IF_NONE_MATCH)
+                                    .cloned();
+                                let response = next.run(request).await;
+                                if !cacheable || response.status() != StatusCode::OK
+                                    || response.headers().contains_key(header::ETAG)
+                                {
+                                    return response;
+                                }
+                                let (mut parts, body) = response.into_parts();
+                                let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await
+                                else {
+                                    return axum::response::IntoResponse::into_response(
+                                        StatusCode::INTERNAL_SERVER_ERROR,
+                                    );
+                                };
+                                let hash = bytes
+                                    .iter()
+                                    .fold(
+                                        0xcbf2_9ce4_8422_2325_u64,
+                                        |hash, byte| {
+                                            (hash ^ u64::from(*byte))
+                                                .wrapping_mul(0x0000_0100_0000_01b3)
+                                        },
+                                    );
+                                let etag = ::alloc::__export::must_use({
+                                    ::alloc::fmt::format(format_args!("\"{0:016x}\"", hash))
+                                });
+                                let matches = if_none_match
+                                    .as_ref()
+                                    .and_then(|value| value.to_str().ok())
+                                    .is_some_and(|value| {
+                                        value
+                                            .split(',')
+                                            .map(str::trim)
+                                            .any(|tag| {
+                                                tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag
+                                            })
+                                    });
+                                parts
+                                    .headers
+                                    .insert(
+                                        header::ETAG,
+                                        HeaderValue::from_str(&etag).unwrap(),
+                                    );
+                                if matches {
+                                    parts.status = StatusCode::NOT_MODIFIED;
+                                    parts.headers.remove(header::CONTENT_LENGTH);
+                                    return axum::response::Response::from_parts(
+                                        parts,
+                                        axum::body::Body::empty(),
+                                    );
+                                }
+                                axum::response::Response::from_parts(
+                                    parts,
+                                    axum::body::Body::from(bytes),
+                                )
+                            }),
+                        ),
+                );
+            router
+        }
+        router = __register_static(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_users(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/users",
+                    axum::routing::get(
+                            axum::handler::Handler::layer(
+                                __folder_router__myfolderrouter::users::route::get,
+                                axum::middleware::from_fn(|
+                                    request: axum::extract::Request,
+                                    next: axum::middleware::Next|
+                                async move {
+                                    use axum::http::{header, HeaderValue, Method, StatusCode};
+                                    let cacheable = #[allow(non_exhaustive_omitted_patterns)]
+                                    match *request.method() {
+                                        Method::GET | Method::HEAD => true,
+                                        _ => false,
+                                    };
+                                    let if_none_match = request
+                                        .headers()
+                                        .get(header::IF_NONE_MATCH)
+                                        .cloned();
+                                    let response = next.run(request).await;
+                                    if !cacheable || response.status() != StatusCode::OK
+                                        || response.headers().contains_key(header::ETAG)
+                                    {
+                                        return response;
+                                    }
+                                    let (mut parts, body) = response.into_parts();
+                                    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await
+                                    else {
+                                        return axum::response::IntoResponse::into_response(
+                                            StatusCode::INTERNAL_SERVER_ERROR,
+                                        );
+                                    };
+                                    let hash = bytes
+                                        .iter()
+                                        .fold(
+                                            0xcbf2_9ce4_8422_2325_u64,
+                                            |hash, byte| {
+                                                (hash ^ u64::from(*byte))
+                                                    .wrapping_mul(0x0000_0100_0000_01b3)
+                                            },
+                                        );
+                                    let etag = ::alloc::__export::must_use({
+                                        ::alloc::fmt::format(format_args!("\"{0:016x}\"", hash))
+                                    });
+                                    let matches = if_none_match
+                                        .as_ref()
+                                        .and_then(|value| value.to_str().ok())
+                                        .is_some_and(|value| {
+                                            value
+                                                .split(',')
+                                                .map(str::trim)
+                                                .any(|tag| {
+                                                    tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag
+                                                })
+                                        });
+                                    parts
+                                        .headers
+                                        .insert(
+                                            header::ETAG,
+                                            HeaderValue::from_str(&etag).unwrap(),
+                                        );
+                                    if matches {
+                                        parts.status = StatusCode::NOT_MODIFIED;
+                                        parts.headers.remove(header::CONTENT_LENGTH);
+                                        return axum::response::Response::from_parts(
+                                            parts,
+                                            axum::body::Body::empty(),
+                                        );
+                                    }
+                                    axum::response::Response::from_parts(
+                                        parts,
+                                        axum::body::Body::from(bytes),
+                                    )
+                                }),
+                            ),
+                        )
+                        .post(__folder_router__myfolderrouter::users::route::post),
+                );
+            router
+        }
+        router = __register_users(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET   /static\nGET   /users\nPOST  /users\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /static", "GET /users", "POST /users"];
+        f.debug_list().entries(routes).finish()
+    }
+}
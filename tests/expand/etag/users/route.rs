@@ -0,0 +1,8 @@
+#[axum_folder_router::etag]
+pub async fn get() -> &'static str {
+    "[]"
+}
+
+pub async fn post() -> &'static str {
+    "Created"
+}
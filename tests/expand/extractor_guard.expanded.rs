@@ -0,0 +1,154 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/extractor_guard", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/extractor_guard"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/admin", mod_path: ["admin", "route"]
+/// [folder_router] Found methods for axum_path: "/admin", mod_path: ["admin", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/reports", mod_path: ["reports", "route"]
+/// [folder_router] Found methods for axum_path: "/reports", mod_path: ["reports", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/extractor_guard"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "admin"]
+    pub mod admin {
+        #[path = "guard.rs"]
+        pub mod guard {
+            use axum::{
+                extract::FromRequestParts, http::{header, request::Parts, StatusCode},
+            };
+            pub struct Guard;
+            impl<S: Sync> FromRequestParts<S> for Guard {
+                type Rejection = StatusCode;
+                async fn from_request_parts(
+                    parts: &mut Parts,
+                    _state: &S,
+                ) -> Result<Self, Self::Rejection> {
+                    if parts.headers.contains_key(header::AUTHORIZATION) {
+                        Ok(Self)
+                    } else {
+                        Err(StatusCode::UNAUTHORIZED)
+                    }
+                }
+            }
+        }
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Admin"
+            }
+        }
+    }
+    #[path = "reports"]
+    pub mod reports {
+        #[path = "route.rs"]
+        pub mod route {
+            use axum::{extract::FromRequestParts, http::{request::Parts, StatusCode}};
+            pub struct Guard;
+            impl<S: Sync> FromRequestParts<S> for Guard {
+                type Rejection = StatusCode;
+                async fn from_request_parts(
+                    parts: &mut Parts,
+                    _state: &S,
+                ) -> Result<Self, Self::Rejection> {
+                    if parts.uri.query().is_some_and(|query| query.contains("token=")) {
+                        Ok(Self)
+                    } else {
+                        Err(StatusCode::FORBIDDEN)
+                    }
+                }
+            }
+            pub async fn get() -> &'static str {
+                "Reports"
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/admin", "admin/route.rs"),
+        ("GET", "/reports", "reports/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_admin(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/admin",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::admin::route::get,
+                        )
+                        .route_layer(
+                            axum::middleware::from_extractor::<
+                                __folder_router__myfolderrouter::admin::guard::Guard,
+                            >(),
+                        ),
+                );
+            router
+        }
+        router = __register_admin(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_reports(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/reports",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::reports::route::get,
+                        )
+                        .route_layer(
+                            axum::middleware::from_extractor::<
+                                __folder_router__myfolderrouter::reports::route::Guard,
+                            >(),
+                        ),
+                );
+            router
+        }
+        router = __register_reports(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\nGET  /admin\nGET  /reports\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /admin", "GET /reports"];
+        f.debug_list().entries(routes).finish()
+    }
+}
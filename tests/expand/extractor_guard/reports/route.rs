@@ -0,0 +1,22 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{request::Parts, StatusCode},
+};
+
+pub struct Guard;
+
+impl<S: Sync> FromRequestParts<S> for Guard {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if parts.uri.query().is_some_and(|query| query.contains("token=")) {
+            Ok(Self)
+        } else {
+            Err(StatusCode::FORBIDDEN)
+        }
+    }
+}
+
+pub async fn get() -> &'static str {
+    "Reports"
+}
@@ -0,0 +1,82 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/feature_gated", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/feature_gated"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/enterprise/audit", mod_path: ["enterprise", "audit", "route"]
+/// [folder_router] Found methods for axum_path: "/enterprise/audit", mod_path: ["enterprise", "audit", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/enterprise/reports", mod_path: ["enterprise", "reports", "route"]
+/// [folder_router] Found methods for axum_path: "/enterprise/reports", mod_path: ["enterprise", "reports", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/feature_gated"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "enterprise"]
+    pub mod enterprise {
+        #[path = "audit"]
+        pub mod audit {}
+        #[path = "reports"]
+        pub mod reports {}
+    }
+    const _: &[u8] = b"enterprise\n";
+    const _: &[u8] = b"audit-log\n";
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/enterprise/audit", "enterprise/audit/route.rs"),
+        ("GET", "/enterprise/reports", "enterprise/reports/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_enterprise(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router
+        }
+        router = __register_enterprise(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\nGET  /enterprise/audit\nGET  /enterprise/reports\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /",
+            "GET /enterprise/audit",
+            "GET /enterprise/reports",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,19 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState {
+    name: String,
+}
+
+#[folder_router("examples/simple/api", AppState)]
+struct MyFolderRouter {
+    name: String,
+}
+
+impl From<MyFolderRouter> for AppState {
+    fn from(router: MyFolderRouter) -> Self {
+        Self {
+            name: router.name,
+        }
+    }
+}
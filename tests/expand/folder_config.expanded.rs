@@ -0,0 +1,136 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/folder_config", AppState, compression = ["/"]) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/folder_config"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/admin", mod_path: ["admin", "route"]
+/// [folder_router] Found methods for axum_path: "/admin", mod_path: ["admin", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/admin/uploads", mod_path: ["admin", "uploads", "route"]
+/// [folder_router] Found methods for axum_path: "/admin/uploads", mod_path: ["admin", "uploads", "route"], methods: ["post"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum::{extract::Request, middleware::Next, response::Response};
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+async fn require_admin(request: Request, next: Next) -> Response {
+    next.run(request).await
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/folder_config"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "admin"]
+    pub mod admin {
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Hello World!"
+            }
+        }
+        #[path = "uploads"]
+        pub mod uploads {
+            #[path = "route.rs"]
+            pub mod route {
+                pub async fn post(body: String) -> String {
+                    body
+                }
+            }
+        }
+    }
+    const _: &[u8] = b"guard = \"crate::require_admin\"\ntimeout = \"5s\"\n";
+    const _: &[u8] = b"timeout = \"30s\"\nbody_limit = \"10MB\"\ncompression = false\n";
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/admin", "admin/route.rs"),
+        ("POST", "/admin/uploads", "admin/uploads/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route(
+                "/",
+                axum::routing::get(__folder_router__myfolderrouter::route::get)
+                    .layer(tower_http::compression::CompressionLayer::new()),
+            );
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_admin(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/admin",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::admin::route::get,
+                        )
+                        .route_layer(axum::middleware::from_fn(crate::require_admin))
+                        .layer::<
+                            _,
+                            std::convert::Infallible,
+                        >(
+                            tower_http::timeout::TimeoutLayer::with_status_code(
+                                axum::http::StatusCode::REQUEST_TIMEOUT,
+                                std::time::Duration::from_millis(5000u64),
+                            ),
+                        )
+                        .layer(tower_http::compression::CompressionLayer::new()),
+                );
+            router = router
+                .route(
+                    "/admin/uploads",
+                    axum::routing::post(
+                            __folder_router__myfolderrouter::admin::uploads::route::post,
+                        )
+                        .route_layer(axum::middleware::from_fn(crate::require_admin))
+                        .layer::<
+                            _,
+                            std::convert::Infallible,
+                        >(
+                            tower_http::timeout::TimeoutLayer::with_status_code(
+                                axum::http::StatusCode::REQUEST_TIMEOUT,
+                                std::time::Duration::from_millis(30000u64),
+                            ),
+                        )
+                        .layer::<
+                            _,
+                            std::convert::Infallible,
+                        >(axum::extract::DefaultBodyLimit::max(10485760usize)),
+                );
+            router
+        }
+        router = __register_admin(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET   /\nGET   /admin\nPOST  /admin/uploads\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /admin", "POST /admin/uploads"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,12 @@
+use axum::{extract::Request, middleware::Next, response::Response};
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+async fn require_admin(request: Request, next: Next) -> Response {
+    next.run(request).await
+}
+
+#[folder_router("tests/expand/folder_config", AppState, compression = ["/"])]
+struct MyFolderRouter();
@@ -0,0 +1,3 @@
+pub async fn post(body: String) -> String {
+    body
+}
@@ -0,0 +1,146 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/fragment", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/fragment"
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/fragment"]
+mod __folder_router__myfolderrouter {
+    #[path = "layout.rs"]
+    pub mod layout {
+        pub async fn layout(content: String) -> String {
+            ::alloc::__export::must_use({
+                ::alloc::fmt::format(
+                    format_args!("<html><body>{0}</body></html>", content),
+                )
+            })
+        }
+    }
+    #[path = "contact"]
+    pub mod contact {
+        #[path = "fragment.rs"]
+        pub mod fragment {
+            use axum::response::Html;
+            pub async fn form() -> Html<&'static str> {
+                Html("<form hx-post=\"/contact\"><input name=\"email\"></form>")
+            }
+            pub async fn success() -> Html<&'static str> {
+                Html("<p>Thanks!</p>")
+            }
+        }
+        #[path = "page.rs"]
+        pub mod page {
+            use axum::response::Html;
+            pub async fn page() -> Html<&'static str> {
+                Html("<form method=\"post\"><input name=\"email\"></form>")
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/contact", "contact/page.rs"),
+        ("GET", "/contact/fragments/form", "contact/fragment.rs"),
+        ("GET", "/contact/fragments/success", "contact/fragment.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        async fn wrap_in_layout<F: std::future::Future<Output = String>>(
+            response: axum::response::Response,
+            layout: impl FnOnce(String) -> F,
+        ) -> axum::response::Response {
+            let is_html = response
+                .headers()
+                .get(axum::http::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("text/html"));
+            if !is_html {
+                return response;
+            }
+            let (mut parts, body) = response.into_parts();
+            let Ok(content) = axum::body::to_bytes(body, usize::MAX).await else {
+                return axum::response::IntoResponse::into_response(
+                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                );
+            };
+            let html = layout(String::from_utf8_lossy(&content).into_owned()).await;
+            parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+            axum::response::Response::from_parts(parts, axum::body::Body::from(html))
+        }
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_contact(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/contact",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::contact::page::page,
+                        )
+                        .route_layer(
+                            axum::middleware::from_fn(|
+                                request: axum::extract::Request,
+                                next: axum::middleware::Next|
+                            async move {
+                                wrap_in_layout(
+                                        next.run(request).await,
+                                        __folder_router__myfolderrouter::layout::layout,
+                                    )
+                                    .await
+                            }),
+                        ),
+                );
+            router = router
+                .route(
+                    "/contact/fragments/form",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::contact::fragment::form,
+                    ),
+                );
+            router = router
+                .route(
+                    "/contact/fragments/success",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::contact::fragment::success,
+                    ),
+                );
+            router
+        }
+        router = __register_contact(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str(
+            "GET  /contact\nGET  /contact/fragments/form\nGET  /contact/fragments/success\n",
+        )
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /contact",
+            "GET /contact/fragments/form",
+            "GET /contact/fragments/success",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,9 @@
+use axum::response::Html;
+
+pub async fn form() -> Html<&'static str> {
+    Html("<form hx-post=\"/contact\"><input name=\"email\"></form>")
+}
+
+pub async fn success() -> Html<&'static str> {
+    Html("<p>Thanks!</p>")
+}
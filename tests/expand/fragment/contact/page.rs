@@ -0,0 +1,5 @@
+use axum::response::Html;
+
+pub async fn page() -> Html<&'static str> {
+    Html("<form method=\"post\"><input name=\"email\"></form>")
+}
@@ -0,0 +1,3 @@
+pub async fn layout(content: String) -> String {
+    format!("<html><body>{content}</body></html>")
+}
@@ -0,0 +1,6 @@
+use std::marker::PhantomData;
+
+use axum_folder_router::folder_router;
+
+#[folder_router("examples/simple/api", S)]
+struct MyFolderRouter<S>(PhantomData<S>);
@@ -0,0 +1,86 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/graphql", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/graphql"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/graphql"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "graphql"]
+    pub mod graphql {
+        #[path = "graphql.rs"]
+        pub mod graphql {
+            use axum::{response::Html, Json};
+            pub async fn playground() -> Html<&'static str> {
+                Html("<html><body>GraphiQL</body></html>")
+            }
+            pub async fn execute(Json(query): Json<String>) -> Json<String> {
+                Json(query)
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/graphql", "graphql/graphql.rs"),
+        ("POST", "/graphql", "graphql/graphql.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_graphql(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/graphql",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::graphql::graphql::playground,
+                        )
+                        .post(__folder_router__myfolderrouter::graphql::graphql::execute),
+                );
+            router
+        }
+        router = __register_graphql(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET   /\nGET   /graphql\nPOST  /graphql\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /graphql", "POST /graphql"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,9 @@
+use axum::{response::Html, Json};
+
+pub async fn playground() -> Html<&'static str> {
+    Html("<html><body>GraphiQL</body></html>")
+}
+
+pub async fn execute(Json(query): Json<String>) -> Json<String> {
+    Json(query)
+}
@@ -0,0 +1,5 @@
+use axum::http::StatusCode;
+
+pub async fn ready() -> StatusCode {
+    StatusCode::SERVICE_UNAVAILABLE
+}
@@ -0,0 +1,168 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/keyword_params", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/keyword_params"
+/// [folder_router] Found route.rs for axum_path: "/files/{*self}", mod_path: ["files", "___self", "route"]
+/// [folder_router] Found methods for axum_path: "/files/{*self}", mod_path: ["files", "___self", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/match/{async}", mod_path: ["r#match", "__async", "route"]
+/// [folder_router] Found methods for axum_path: "/match/{async}", mod_path: ["r#match", "__async", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/self", mod_path: ["self_", "route"]
+/// [folder_router] Found methods for axum_path: "/self", mod_path: ["self_", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/{type}", mod_path: ["__type", "route"]
+/// [folder_router] Found methods for axum_path: "/{type}", mod_path: ["__type", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/keyword_params"]
+mod __folder_router__myfolderrouter {
+    #[path = "[type]"]
+    pub mod __type {
+        #[path = "route.rs"]
+        pub mod route {
+            use axum::extract::Path;
+            pub async fn get(Path(kind): Path<String>) -> String {
+                kind
+            }
+        }
+    }
+    #[path = "files"]
+    pub mod files {
+        #[path = "[...self]"]
+        pub mod ___self {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::extract::Path;
+                pub async fn get(Path(rest): Path<String>) -> String {
+                    rest
+                }
+            }
+        }
+    }
+    #[path = "match"]
+    pub mod r#match {
+        #[path = "[async@u32]"]
+        pub mod __async {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::extract::Path;
+                pub async fn get(Path(id): Path<u32>) -> String {
+                    id.to_string()
+                }
+            }
+        }
+    }
+    #[path = "self"]
+    pub mod self_ {
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Hello World!"
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/files/{*self}", "files/[...self]/route.rs"),
+        ("GET", "/match/{async}", "match/[async@u32]/route.rs"),
+        ("GET", "/self", "self/route.rs"),
+        ("GET", "/{type}", "[type]/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_files(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/files/{*self}",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::files::___self::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register_files(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_match(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/match/{async}",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::r#match::__async::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register_match(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_self_(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/self",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::self_::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register_self_(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register___type(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/{type}",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::__type::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register___type(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str(
+            "GET  /files/{*self}\nGET  /match/{async}\nGET  /self\nGET  /{type}\n",
+        )
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /files/{*self}",
+            "GET /match/{async}",
+            "GET /self",
+            "GET /{type}",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
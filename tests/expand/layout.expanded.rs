@@ -0,0 +1,163 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/layout", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/layout"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/docs", mod_path: ["docs", "route"]
+/// [folder_router] Found methods for axum_path: "/docs", mod_path: ["docs", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/layout"]
+mod __folder_router__myfolderrouter {
+    #[path = "layout.rs"]
+    pub mod layout {
+        pub async fn layout(content: String) -> String {
+            ::alloc::__export::must_use({
+                ::alloc::fmt::format(
+                    format_args!("<html><body>{0}</body></html>", content),
+                )
+            })
+        }
+    }
+    #[path = "route.rs"]
+    pub mod route {
+        use axum::response::Html;
+        pub async fn get() -> Html<&'static str> {
+            Html("<h1>Home</h1>")
+        }
+    }
+    #[path = "docs"]
+    pub mod docs {
+        #[path = "layout.rs"]
+        pub mod layout {
+            pub async fn layout(content: String) -> String {
+                ::alloc::__export::must_use({
+                    ::alloc::fmt::format(
+                        format_args!("<nav>Docs</nav><main>{0}</main>", content),
+                    )
+                })
+            }
+        }
+        #[path = "route.rs"]
+        pub mod route {
+            use axum::response::Html;
+            pub async fn get() -> Html<&'static str> {
+                Html("<h1>Docs</h1>")
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/docs", "docs/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        async fn wrap_in_layout<F: std::future::Future<Output = String>>(
+            response: axum::response::Response,
+            layout: impl FnOnce(String) -> F,
+        ) -> axum::response::Response {
+            let is_html = response
+                .headers()
+                .get(axum::http::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("text/html"));
+            if !is_html {
+                return response;
+            }
+            let (mut parts, body) = response.into_parts();
+            let Ok(content) = axum::body::to_bytes(body, usize::MAX).await else {
+                return axum::response::IntoResponse::into_response(
+                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                );
+            };
+            let html = layout(String::from_utf8_lossy(&content).into_owned()).await;
+            parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+            axum::response::Response::from_parts(parts, axum::body::Body::from(html))
+        }
+        router = router
+            .route(
+                "/",
+                axum::routing::get(__folder_router__myfolderrouter::route::get)
+                    .route_layer(
+                        axum::middleware::from_fn(|
+                            request: axum::extract::Request,
+                            next: axum::middleware::Next|
+                        async move {
+                            wrap_in_layout(
+                                    next.run(request).await,
+                                    __folder_router__myfolderrouter::layout::layout,
+                                )
+                                .await
+                        }),
+                    ),
+            );
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_docs(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/docs",
+                    axum::routing::get(__folder_router__myfolderrouter::docs::route::get)
+                        .route_layer(
+                            axum::middleware::from_fn(|
+                                request: axum::extract::Request,
+                                next: axum::middleware::Next|
+                            async move {
+                                wrap_in_layout(
+                                        next.run(request).await,
+                                        __folder_router__myfolderrouter::docs::layout::layout,
+                                    )
+                                    .await
+                            }),
+                        )
+                        .route_layer(
+                            axum::middleware::from_fn(|
+                                request: axum::extract::Request,
+                                next: axum::middleware::Next|
+                            async move {
+                                wrap_in_layout(
+                                        next.run(request).await,
+                                        __folder_router__myfolderrouter::layout::layout,
+                                    )
+                                    .await
+                            }),
+                        ),
+                );
+            router
+        }
+        router = __register_docs(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\nGET  /docs\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /docs"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,201 @@
+/// [folder_router] Running folder_router macro attrs:("examples/simple/api", AppState, locales = ["en", "de"]) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/simple/api"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/examples/simple/api"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        use axum::response::{Html, IntoResponse};
+        pub async fn get() -> impl IntoResponse {
+            Html("<h1>Hello World!</h1>").into_response()
+        }
+    }
+}
+/// The locale prefix of a request, e.g. `en` for `/en/users`
+pub struct Locale(pub &'static str);
+#[automatically_derived]
+#[doc(hidden)]
+unsafe impl ::core::clone::TrivialClone for Locale {}
+#[automatically_derived]
+impl ::core::clone::Clone for Locale {
+    #[inline]
+    fn clone(&self) -> Locale {
+        let _: ::core::clone::AssertParamIsClone<&'static str>;
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for Locale {}
+#[automatically_derived]
+impl ::core::fmt::Debug for Locale {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "Locale", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for Locale {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Locale {
+    #[inline]
+    fn eq(&self, other: &Locale) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Locale {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_receiver_is_total_eq(&self) -> () {
+        let _: ::core::cmp::AssertParamIsEq<&'static str>;
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        async fn redirect_to_locale(
+            axum::extract::OriginalUri(uri): axum::extract::OriginalUri,
+            headers: axum::http::HeaderMap,
+        ) -> axum::response::Response {
+            const LOCALES: &[&str] = &["en", "de"];
+            let path = uri.path().trim_end_matches('/');
+            let first_segment = path
+                .trim_start_matches('/')
+                .split('/')
+                .next()
+                .unwrap_or_default();
+            if LOCALES.contains(&first_segment) {
+                return axum::response::IntoResponse::into_response(
+                    axum::http::StatusCode::NOT_FOUND,
+                );
+            }
+            let accept_language = headers
+                .get(axum::http::header::ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default();
+            let mut languages: Vec<(&str, f32)> = accept_language
+                .split(',')
+                .map(|language| {
+                    let mut parts = language.split(';');
+                    let tag = parts.next().unwrap_or_default().trim();
+                    let quality = parts
+                        .find_map(|param| param.trim().strip_prefix("q="))
+                        .and_then(|quality| quality.parse().ok())
+                        .unwrap_or(1.0);
+                    (tag, quality)
+                })
+                .collect();
+            languages.sort_by(|a, b| b.1.total_cmp(&a.1));
+            let locale = languages
+                .iter()
+                .find_map(|(tag, _)| {
+                    LOCALES
+                        .iter()
+                        .find(|locale| tag.eq_ignore_ascii_case(locale))
+                        .or_else(|| {
+                            let primary = tag.split('-').next().unwrap_or_default();
+                            LOCALES
+                                .iter()
+                                .find(|locale| primary.eq_ignore_ascii_case(locale))
+                        })
+                })
+                .unwrap_or(&LOCALES[0]);
+            let location = match uri.query() {
+                Some(query) => {
+                    ::alloc::__export::must_use({
+                        ::alloc::fmt::format(
+                            format_args!("/{0}{1}?{2}", locale, path, query),
+                        )
+                    })
+                }
+                None => {
+                    ::alloc::__export::must_use({
+                        ::alloc::fmt::format(format_args!("/{0}{1}", locale, path))
+                    })
+                }
+            };
+            let mut response = axum::response::IntoResponse::into_response(
+                axum::response::Redirect::temporary(&location),
+            );
+            response
+                .headers_mut()
+                .insert(
+                    axum::http::header::VARY,
+                    axum::http::HeaderValue::from_static("accept-language"),
+                );
+            response
+        }
+        let localized = router;
+        let mut router = axum::Router::new();
+        router = router
+            .nest(
+                "/en",
+                localized
+                    .clone()
+                    .layer(
+                        axum::middleware::from_fn(|
+                            mut request: axum::extract::Request,
+                            next: axum::middleware::Next|
+                        async move {
+                            request.extensions_mut().insert(Locale("en"));
+                            next.run(request).await
+                        }),
+                    ),
+            );
+        router = router
+            .nest(
+                "/de",
+                localized
+                    .clone()
+                    .layer(
+                        axum::middleware::from_fn(|
+                            mut request: axum::extract::Request,
+                            next: axum::middleware::Next|
+                        async move {
+                            request.extensions_mut().insert(Locale("de"));
+                            next.run(request).await
+                        }),
+                    ),
+            );
+        router = router.fallback(redirect_to_locale);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,252 @@
+/// [folder_router] Running folder_router macro attrs:("examples/simple/api", AppState) item: struct ApiRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/simple/api"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Running folder_router macro attrs:("examples/versioned/api", AppState) item: struct OtherRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/versioned/api"
+/// [folder_router] Found route.rs for axum_path: "/v1/users", mod_path: ["v1", "users", "route"]
+/// [folder_router] Found methods for axum_path: "/v1/users", mod_path: ["v1", "users", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/v2/user_settings", mod_path: ["v2", "user_settings", "route"]
+/// [folder_router] Found methods for axum_path: "/v2/user_settings", mod_path: ["v2", "user_settings", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/v2/users", mod_path: ["v2", "users", "route"]
+/// [folder_router] Found methods for axum_path: "/v2/users", mod_path: ["v2", "users", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::{folder_router, merge_all};
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct ApiRouter();
+#[path = "/home/tristand/code/axum-folder-router/examples/simple/api"]
+mod __folder_router__apirouter {
+    #[path = "route.rs"]
+    pub mod route {
+        use axum::response::{Html, IntoResponse};
+        pub async fn get() -> impl IntoResponse {
+            Html("<h1>Hello World!</h1>").into_response()
+        }
+    }
+}
+impl ApiRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__apirouter::route::get));
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for ApiRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\n")
+    }
+}
+impl ::core::fmt::Debug for ApiRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /"];
+        f.debug_list().entries(routes).finish()
+    }
+}
+struct OtherRouter();
+#[path = "/home/tristand/code/axum-folder-router/examples/versioned/api"]
+mod __folder_router__otherrouter {
+    #[path = "v1"]
+    pub mod v1 {
+        #[path = "users"]
+        pub mod users {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::response::IntoResponse;
+                pub async fn get() -> impl IntoResponse {
+                    "alice, bob"
+                }
+            }
+        }
+    }
+    #[path = "v2"]
+    pub mod v2 {
+        #[path = "user_settings"]
+        pub mod user_settings {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::{response::IntoResponse, Json};
+                pub async fn get() -> impl IntoResponse {
+                    Json(["dark_mode"])
+                }
+            }
+        }
+        #[path = "users"]
+        pub mod users {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::{response::IntoResponse, Json};
+                pub async fn get() -> impl IntoResponse {
+                    Json(["alice", "bob"])
+                }
+            }
+        }
+    }
+}
+impl OtherRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/v1/users", "v1/users/route.rs"),
+        ("GET", "/v2/user_settings", "v2/user_settings/route.rs"),
+        ("GET", "/v2/users", "v2/users/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_v1(mut router: axum::Router<AppState>) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/v1/users",
+                    axum::routing::get(
+                        __folder_router__otherrouter::v1::users::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register_v1(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_v2(mut router: axum::Router<AppState>) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/v2/user_settings",
+                    axum::routing::get(
+                        __folder_router__otherrouter::v2::user_settings::route::get,
+                    ),
+                );
+            router = router
+                .route(
+                    "/v2/users",
+                    axum::routing::get(
+                        __folder_router__otherrouter::v2::users::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register_v2(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for OtherRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /v1/users\nGET  /v2/user_settings\nGET  /v2/users\n")
+    }
+}
+impl ::core::fmt::Debug for OtherRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /v1/users",
+            "GET /v2/user_settings",
+            "GET /v2/users",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
+fn router() -> axum::Router<AppState> {
+    {
+        const _: () = {
+            const fn is_param(path: &[u8], i: usize) -> bool {
+                i > 0 && path[i - 1] == b'/' && #[allow(non_exhaustive_omitted_patterns)]
+                    match path[i] {
+                        b'{' | b':' | b'*' => true,
+                        _ => false,
+                    }
+            }
+            const fn param_end(path: &[u8], mut i: usize) -> usize {
+                let braced = path[i] == b'{';
+                while i < path.len() && path[i] != if braced { b'}' } else { b'/' } {
+                    i += 1;
+                }
+                if braced && i < path.len() { i + 1 } else { i }
+            }
+            const fn same_path(a: &str, b: &str) -> bool {
+                let (a, b) = (a.as_bytes(), b.as_bytes());
+                let (mut i, mut j) = (0, 0);
+                while i < a.len() && j < b.len() {
+                    if is_param(a, i) && is_param(b, j) {
+                        i = param_end(a, i);
+                        j = param_end(b, j);
+                    } else if a[i] != b[j] {
+                        return false;
+                    } else {
+                        i += 1;
+                        j += 1;
+                    }
+                }
+                i == a.len() && j == b.len()
+            }
+            const fn same_str(a: &str, b: &str) -> bool {
+                let (a, b) = (a.as_bytes(), b.as_bytes());
+                if a.len() != b.len() {
+                    return false;
+                }
+                let mut i = 0;
+                while i < a.len() {
+                    if a[i] != b[i] {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+            const fn conflict(
+                a: &'static [(&'static str, &'static str, &'static str)],
+                b: &'static [(&'static str, &'static str, &'static str)],
+            ) -> Option<&'static str> {
+                let mut i = 0;
+                while i < a.len() {
+                    let mut j = 0;
+                    while j < b.len() {
+                        let ((method_a, path_a, _), (method_b, path_b, _)) = (
+                            a[i],
+                            b[j],
+                        );
+                        let same_method = same_str(method_a, method_b)
+                            || same_str(method_a, "*") || same_str(method_b, "*");
+                        if same_method && same_path(path_a, path_b) {
+                            return Some(path_a);
+                        }
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                None
+            }
+            #[allow(non_upper_case_globals)]
+            const ApiRouter_conflicts_with_OtherRouter: () = if let Some(path) = conflict(
+                <ApiRouter>::ROUTES,
+                <OtherRouter>::ROUTES,
+            ) {
+                {
+                    ::core::panicking::panic_display(&path);
+                }
+            };
+            ApiRouter_conflicts_with_OtherRouter;
+        };
+        <ApiRouter>::into_router().merge(<OtherRouter>::into_router())
+    }
+}
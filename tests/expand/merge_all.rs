@@ -0,0 +1,14 @@
+use axum_folder_router::{folder_router, merge_all};
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router("examples/simple/api", AppState)]
+struct ApiRouter();
+
+#[folder_router("examples/versioned/api", AppState)]
+struct OtherRouter();
+
+fn router() -> axum::Router<AppState> {
+    merge_all!(ApiRouter, OtherRouter)
+}
@@ -0,0 +1,128 @@
+/// [folder_router] Running folder_router macro attrs:("examples/simple/api", AppState, method_override = true) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/simple/api"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/examples/simple/api"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        use axum::response::{Html, IntoResponse};
+        pub async fn get() -> impl IntoResponse {
+            Html("<h1>Hello World!</h1>").into_response()
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        async fn form_field(
+            request: axum::extract::Request,
+            field: &str,
+        ) -> Result<(axum::extract::Request, Option<String>), axum::response::Response> {
+            let is_form = request
+                .headers()
+                .get(axum::http::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| {
+                    value.starts_with("application/x-www-form-urlencoded")
+                });
+            if !is_form {
+                return Ok((request, None));
+            }
+            let (parts, body) = axum::RequestExt::with_limited_body(request)
+                .into_parts();
+            let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+                return Err(
+                    axum::response::IntoResponse::into_response(
+                        axum::http::StatusCode::PAYLOAD_TOO_LARGE,
+                    ),
+                );
+            };
+            let value = String::from_utf8_lossy(&bytes)
+                .split('&')
+                .find_map(|pair| pair.strip_prefix(field)?.strip_prefix('='))
+                .map(ToString::to_string);
+            let request = axum::extract::Request::from_parts(
+                parts,
+                axum::body::Body::from(bytes),
+            );
+            Ok((request, value))
+        }
+        let router = { Self::into_router().with_state(state) };
+        axum::Router::new()
+            .fallback(move |mut request: axum::extract::Request| {
+                let router = router.clone();
+                async move {
+                    use axum::http::Method;
+                    if request.method() == Method::POST {
+                        let mut method = request
+                            .headers()
+                            .get("x-http-method-override")
+                            .and_then(|value| value.to_str().ok())
+                            .map(ToString::to_string);
+                        if method.is_none() {
+                            (request, method) = match form_field(request, "_method")
+                                .await
+                            {
+                                Ok(field) => field,
+                                Err(response) => return response,
+                            };
+                        }
+                        let method = method
+                            .and_then(|method| {
+                                method.to_ascii_uppercase().parse::<Method>().ok()
+                            })
+                            .filter(|method| {
+                                #[allow(non_exhaustive_omitted_patterns)]
+                                match *method {
+                                    Method::PUT | Method::PATCH | Method::DELETE => true,
+                                    _ => false,
+                                }
+                            });
+                        if let Some(method) = method {
+                            *request.method_mut() = method;
+                        }
+                    }
+                    axum::response::IntoResponse::into_response(
+                        tower::ServiceExt::oneshot(router, request).await,
+                    )
+                }
+            })
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,13 @@
+use axum::{extract::Request, middleware::Next, response::Response};
+
+pub async fn middleware(request: Request, next: Next) -> Response {
+    let mut response = next.run(request).await;
+    response
+        .headers_mut()
+        .insert("x-route", "users".parse().unwrap());
+    response
+}
+
+pub async fn get() -> &'static str {
+    "Users"
+}
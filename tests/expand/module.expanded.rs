@@ -0,0 +1,35 @@
+/// [folder_router] Running folder_router macro attrs:("examples/simple/api", AppState) item: mod api {}
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/simple/api"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+#[path = "/home/tristand/code/axum-folder-router/examples/simple/api"]
+mod api {
+    #[allow(unused_imports)]
+    use super::*;
+    #[path = "route.rs"]
+    pub mod route {
+        use axum::response::{Html, IntoResponse};
+        pub async fn get() -> impl IntoResponse {
+            Html("<h1>Hello World!</h1>").into_response()
+        }
+    }
+    pub fn router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router.route("/", axum::routing::get(self::route::get));
+        router
+    }
+}
@@ -0,0 +1,10 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router("examples/simple/api", AppState)]
+struct BillingRouter();
+
+#[folder_router("tests/expand/mount", AppState)]
+struct MyFolderRouter();
@@ -0,0 +1 @@
+pub type Mount = crate::BillingRouter;
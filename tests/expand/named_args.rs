@@ -0,0 +1,7 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router(path = "examples/simple/api", state = AppState, auto_head = true)]
+struct MyFolderRouter();
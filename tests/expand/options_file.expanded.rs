@@ -0,0 +1,82 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/options_file", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/options_file"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/user-settings", mod_path: ["user_settings", "route"]
+/// [folder_router] Found methods for axum_path: "/user-settings", mod_path: ["user_settings", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/options_file"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "user_settings"]
+    pub mod user_settings {
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Hello World!"
+            }
+        }
+    }
+    const _: &[u8] = b"case = \"kebab\"\ntrailing_slash = \"merge\"\ncompression = [\"/user-settings\"]\ndeprecated = []\n";
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/user-settings", "user_settings/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_user_settings(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            let method_router = axum::routing::get(
+                    __folder_router__myfolderrouter::user_settings::route::get,
+                )
+                .layer(tower_http::compression::CompressionLayer::new());
+            router = router.route("/user-settings", method_router.clone());
+            router = router.route("/user-settings/", method_router);
+            router
+        }
+        router = __register_user_settings(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\nGET  /user-settings\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /user-settings"];
+        f.debug_list().entries(routes).finish()
+    }
+}
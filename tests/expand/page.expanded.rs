@@ -0,0 +1,97 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/page", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/page"
+/// [folder_router] Found route.rs for axum_path: "/contact", mod_path: ["contact", "route"]
+/// [folder_router] Found methods for axum_path: "/contact", mod_path: ["contact", "route"], methods: ["post"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/page"]
+mod __folder_router__myfolderrouter {
+    #[path = "page.rs"]
+    pub mod page {
+        use axum::response::Html;
+        pub async fn page() -> Html<&'static str> {
+            Html("<h1>Home</h1>")
+        }
+    }
+    #[path = "contact"]
+    pub mod contact {
+        #[path = "page.rs"]
+        pub mod page {
+            use axum::response::Html;
+            pub async fn page() -> Html<&'static str> {
+                Html("<form method=\"post\"><input name=\"email\"></form>")
+            }
+        }
+        #[path = "route.rs"]
+        pub mod route {
+            use axum::Json;
+            pub async fn post(Json(email): Json<String>) -> Json<String> {
+                Json(email)
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "page.rs"),
+        ("POST", "/contact", "contact/route.rs"),
+        ("GET", "/contact", "contact/page.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_contact(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/contact",
+                    axum::routing::post(
+                        __folder_router__myfolderrouter::contact::route::post,
+                    ),
+                );
+            router = router
+                .route(
+                    "/contact",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::contact::page::page,
+                    ),
+                );
+            router
+        }
+        router = __register_contact(router);
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::page::page));
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET   /\nPOST  /contact\nGET   /contact\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "POST /contact", "GET /contact"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,5 @@
+use axum::Json;
+
+pub async fn post(Json(email): Json<String>) -> Json<String> {
+    Json(email)
+}
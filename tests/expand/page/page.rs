@@ -0,0 +1,5 @@
+use axum::response::Html;
+
+pub async fn page() -> Html<&'static str> {
+    Html("<h1>Home</h1>")
+}
@@ -0,0 +1,163 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/redirects", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/redirects"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/redirects"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    const _: &[u8] = b"\"/about-us\" = \"/about\"\n\"/blog/{slug}\" = { to = \"/posts/{slug}\", status = 301 }\n\"/docs/{*path}\" = { to = \"https://docs.example.com/{path}\", status = 302 }\n";
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("*", "/about-us", "redirects.toml"),
+        ("*", "/blog/{slug}", "redirects.toml"),
+        ("*", "/docs/{*path}", "redirects.toml"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        router = router
+            .route(
+                "/about-us",
+                axum::routing::any(|| async {
+                    (
+                        axum::http::StatusCode::PERMANENT_REDIRECT,
+                        [(axum::http::header::LOCATION, "/about")],
+                    )
+                }),
+            );
+        router = router
+            .route(
+                "/blog/{slug}",
+                axum::routing::any(|params: axum::extract::RawPathParams| async move {
+                    let encode = |name: &str, value: &str| {
+                        use std::fmt::Write;
+                        let mut encoded = String::with_capacity(value.len());
+                        for byte in value.bytes() {
+                            if byte.is_ascii_alphanumeric()
+                                || b"-._~!$&'()*+,;=:@".contains(&byte)
+                                || (byte == b'/' && false)
+                            {
+                                encoded.push(char::from(byte));
+                            } else {
+                                encoded.write_fmt(format_args!("%{0:02X}", byte)).unwrap();
+                            }
+                        }
+                        encoded
+                    };
+                    let mut location = String::from("/posts/{slug}");
+                    for (name, value) in &params {
+                        let value = encode(name, value);
+                        location = location
+                            .replace(
+                                &::alloc::__export::must_use({
+                                    ::alloc::fmt::format(format_args!("{{*{0}}}", name))
+                                }),
+                                &value,
+                            )
+                            .replace(
+                                &::alloc::__export::must_use({
+                                    ::alloc::fmt::format(format_args!("{{{0}}}", name))
+                                }),
+                                &value,
+                            );
+                    }
+                    (
+                        axum::http::StatusCode::MOVED_PERMANENTLY,
+                        [(axum::http::header::LOCATION, location)],
+                    )
+                }),
+            );
+        router = router
+            .route(
+                "/docs/{*path}",
+                axum::routing::any(|params: axum::extract::RawPathParams| async move {
+                    let encode = |name: &str, value: &str| {
+                        use std::fmt::Write;
+                        let mut encoded = String::with_capacity(value.len());
+                        for byte in value.bytes() {
+                            if byte.is_ascii_alphanumeric()
+                                || b"-._~!$&'()*+,;=:@".contains(&byte)
+                                || (byte == b'/'
+                                    && #[allow(non_exhaustive_omitted_patterns)]
+                                    match name {
+                                        "path" => true,
+                                        _ => false,
+                                    })
+                            {
+                                encoded.push(char::from(byte));
+                            } else {
+                                encoded.write_fmt(format_args!("%{0:02X}", byte)).unwrap();
+                            }
+                        }
+                        encoded
+                    };
+                    let mut location = String::from("https://docs.example.com/{path}");
+                    for (name, value) in &params {
+                        let value = encode(name, value);
+                        location = location
+                            .replace(
+                                &::alloc::__export::must_use({
+                                    ::alloc::fmt::format(format_args!("{{*{0}}}", name))
+                                }),
+                                &value,
+                            )
+                            .replace(
+                                &::alloc::__export::must_use({
+                                    ::alloc::fmt::format(format_args!("{{{0}}}", name))
+                                }),
+                                &value,
+                            );
+                    }
+                    (
+                        axum::http::StatusCode::FOUND,
+                        [(axum::http::header::LOCATION, location)],
+                    )
+                }),
+            );
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\n*    /about-us\n*    /blog/{slug}\n*    /docs/{*path}\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /",
+            "* /about-us",
+            "* /blog/{slug}",
+            "* /docs/{*path}",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,73 @@
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/router_macro"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+fn main() {
+    let _router: axum::Router<AppState> = {
+        #[allow(dead_code)]
+        fn __folder_router() -> axum::Router<AppState> {
+            struct FolderRouter();
+            #[path = "/home/tristand/code/axum-folder-router/tests/expand/router_macro"]
+            mod __folder_router__folderrouter {
+                #[path = "route.rs"]
+                pub mod route {
+                    pub async fn get() -> &'static str {
+                        "Hello World!"
+                    }
+                }
+            }
+            impl FolderRouter {
+                /// The `(method, path, file)` of every route, checked for
+                /// conflicts by `merge_all!`
+                pub const ROUTES: &'static [(
+                    &'static str,
+                    &'static str,
+                    &'static str,
+                )] = &[("GET", "/", "route.rs")] as &[(&str, &str, &str)];
+                pub fn into_router() -> axum::Router<AppState> {
+                    let mut router = axum::Router::new();
+                    router = router
+                        .route(
+                            "/",
+                            axum::routing::get(__folder_router__folderrouter::route::get),
+                        );
+                    router
+                }
+                pub fn into_router_with_state(state: AppState) -> axum::Router {
+                    Self::into_router().with_state(state)
+                }
+            }
+            impl ::core::fmt::Display for FolderRouter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.write_str("GET  /\n")
+                }
+            }
+            impl ::core::fmt::Debug for FolderRouter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    let routes: &[&str] = &["GET /"];
+                    f.debug_list().entries(routes).finish()
+                }
+            }
+            FolderRouter::into_router()
+        }
+        __folder_router()
+    };
+}
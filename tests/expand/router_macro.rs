@@ -0,0 +1,7 @@
+#[derive(Clone)]
+struct AppState;
+
+fn main() {
+    let _router: axum::Router<AppState> =
+        axum_folder_router::router!("tests/expand/router_macro", AppState);
+}
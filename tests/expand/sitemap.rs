@@ -0,0 +1,12 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router(
+    "examples/versioned/api",
+    AppState,
+    sitemap = "https://example.com",
+    sitemap_exclude = ["/v1"]
+)]
+struct MyFolderRouter();
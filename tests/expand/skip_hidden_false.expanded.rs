@@ -0,0 +1,116 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/hidden_dirs", AppState, skip_hidden = false) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/hidden_dirs"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/.backup", mod_path: ["_backup", "route"]
+/// [folder_router] Found methods for axum_path: "/.backup", mod_path: ["_backup", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/.well-known/security.txt", mod_path: ["_well_known", "security_txt", "route"]
+/// [folder_router] Found methods for axum_path: "/.well-known/security.txt", mod_path: ["_well_known", "security_txt", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/hidden_dirs"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = ".backup"]
+    pub mod _backup {
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Hello World!"
+            }
+        }
+    }
+    #[path = ".well-known"]
+    pub mod _well_known {
+        #[path = "security.txt"]
+        pub mod security_txt {
+            #[path = "route.rs"]
+            pub mod route {
+                pub async fn get() -> &'static str {
+                    "Hello World!"
+                }
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/.backup", ".backup/route.rs"),
+        ("GET", "/.well-known/security.txt", ".well-known/security.txt/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register__backup(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/.backup",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::_backup::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register__backup(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register__well_known(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/.well-known/security.txt",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::_well_known::security_txt::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register__well_known(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\nGET  /.backup\nGET  /.well-known/security.txt\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /",
+            "GET /.backup",
+            "GET /.well-known/security.txt",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
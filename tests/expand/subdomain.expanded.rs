@@ -0,0 +1,168 @@
+/// [folder_router] Running folder_router macro attrs:("examples/multi_tenant/api", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/examples/multi_tenant/api"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/dashboard", mod_path: ["__host_tenant", "dashboard", "route"]
+/// [folder_router] Found methods for axum_path: "/dashboard", mod_path: ["__host_tenant", "dashboard", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/examples/multi_tenant/api"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        use axum::response::{Html, IntoResponse};
+        pub async fn get() -> impl IntoResponse {
+            Html("<h1>Pick a tenant, e.g. acme.localhost:3000/dashboard</h1>")
+        }
+    }
+    #[path = "[tenant]!host"]
+    pub mod __host_tenant {
+        #[path = "dashboard"]
+        pub mod dashboard {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::{response::IntoResponse, Extension};
+                use crate::Tenant;
+                pub async fn get(
+                    Extension(Tenant(tenant)): Extension<Tenant>,
+                ) -> impl IntoResponse {
+                    ::alloc::__export::must_use({
+                        ::alloc::fmt::format(format_args!("Dashboard of {0}", tenant))
+                    })
+                }
+            }
+        }
+    }
+}
+///The `tenant` subdomain of a request, from its `[tenant]!host` folder
+pub struct Tenant(pub String);
+#[automatically_derived]
+impl ::core::clone::Clone for Tenant {
+    #[inline]
+    fn clone(&self) -> Tenant {
+        Tenant(::core::clone::Clone::clone(&self.0))
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for Tenant {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "Tenant", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for Tenant {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Tenant {
+    #[inline]
+    fn eq(&self, other: &Tenant) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Tenant {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_receiver_is_total_eq(&self) -> () {
+        let _: ::core::cmp::AssertParamIsEq<String>;
+    }
+}
+impl Tenant {
+    fn from_host(host: &str) -> Option<Self> {
+        let host = host.split(':').next().unwrap_or_default();
+        let subdomain: Option<&str> = match host
+            .split('.')
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [subdomain, "localhost"] | [subdomain, _, _] => Some(*subdomain),
+            _ => None,
+        };
+        subdomain.map(|subdomain| Self(subdomain.to_string()))
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/dashboard", "[tenant]!host/dashboard/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register___host_tenant(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/dashboard",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::__host_tenant::dashboard::route::get,
+                        )
+                        .route_layer(
+                            axum::middleware::from_fn(|
+                                mut request: axum::extract::Request,
+                                next: axum::middleware::Next|
+                            async move {
+                                let host = request
+                                    .uri()
+                                    .host()
+                                    .or_else(|| {
+                                        request
+                                            .headers()
+                                            .get(axum::http::header::HOST)
+                                            .and_then(|host| host.to_str().ok())
+                                    })
+                                    .unwrap_or_default();
+                                match Tenant::from_host(host) {
+                                    Some(subdomain) => {
+                                        request.extensions_mut().insert(subdomain);
+                                        next.run(request).await
+                                    }
+                                    None => {
+                                        axum::response::IntoResponse::into_response(
+                                            axum::http::StatusCode::NOT_FOUND,
+                                        )
+                                    }
+                                }
+                            }),
+                        ),
+                );
+            router
+        }
+        router = __register___host_tenant(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\nGET  /dashboard\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /dashboard"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,132 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/subtree_from_ref", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/subtree_from_ref"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/admin", mod_path: ["admin", "route"]
+/// [folder_router] Found methods for axum_path: "/admin", mod_path: ["admin", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState {
+    admin: AdminState,
+}
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState {
+            admin: ::core::clone::Clone::clone(&self.admin),
+        }
+    }
+}
+struct AdminState {
+    name: String,
+}
+#[automatically_derived]
+impl ::core::clone::Clone for AdminState {
+    #[inline]
+    fn clone(&self) -> AdminState {
+        AdminState {
+            name: ::core::clone::Clone::clone(&self.name),
+        }
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/subtree_from_ref"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "admin"]
+    pub mod admin {
+        #[path = "route.rs"]
+        pub mod route {
+            use axum::extract::State;
+            use crate::AdminState;
+            pub async fn get(State(state): State<AdminState>) -> String {
+                state.name
+            }
+        }
+        #[path = "state.rs"]
+        pub mod state {
+            pub type State = crate::AdminState;
+            pub fn from_ref(state: &crate::AppState) -> State {
+                state.admin.clone()
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/admin", "admin/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_admin(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/admin",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::admin::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register_admin(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\nGET  /admin\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /admin"];
+        f.debug_list().entries(routes).finish()
+    }
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn __check_states() {
+        {
+            #[diagnostic::on_unimplemented(
+                message = "`get` in admin/route.rs extracts `State<{Self}>`, which can't be extracted from `AppState`",
+                label = "implement `FromRef<AppState>` for `{Self}` or extract `State<AppState>`"
+            )]
+            trait __ExtractState0 {}
+            impl<__T: axum::extract::FromRef<AppState>> __ExtractState0 for __T {}
+            fn check<__T: __ExtractState0, __R>(
+                _: fn(axum::extract::State<__T>) -> __R,
+            ) {}
+            check(__folder_router__myfolderrouter::admin::route::get);
+        }
+    }
+};
+const _: () = {
+    impl axum::extract::FromRef<AppState>
+    for __folder_router__myfolderrouter::admin::state::State {
+        fn from_ref(state: &AppState) -> Self {
+            __folder_router__myfolderrouter::admin::state::from_ref(state)
+        }
+    }
+};
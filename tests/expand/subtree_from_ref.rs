@@ -0,0 +1,14 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState {
+    admin: AdminState,
+}
+
+#[derive(Clone)]
+struct AdminState {
+    name: String,
+}
+
+#[folder_router("tests/expand/subtree_from_ref", AppState)]
+struct MyFolderRouter();
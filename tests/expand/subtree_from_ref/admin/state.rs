@@ -0,0 +1,5 @@
+pub type State = crate::AdminState;
+
+pub fn from_ref(state: &crate::AppState) -> State {
+    state.admin.clone()
+}
@@ -0,0 +1,134 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/subtree_state", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/subtree_state"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/admin", mod_path: ["admin", "route"]
+/// [folder_router] Found methods for axum_path: "/admin", mod_path: ["admin", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/admin/users", mod_path: ["admin", "users", "route"]
+/// [folder_router] Found methods for axum_path: "/admin/users", mod_path: ["admin", "users", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct AdminState {
+    name: String,
+}
+#[automatically_derived]
+impl ::core::clone::Clone for AdminState {
+    #[inline]
+    fn clone(&self) -> AdminState {
+        AdminState {
+            name: ::core::clone::Clone::clone(&self.name),
+        }
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/subtree_state"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "admin"]
+    pub mod admin {
+        #[path = "route.rs"]
+        pub mod route {
+            use axum::extract::State;
+            use crate::AdminState;
+            pub async fn get(State(state): State<AdminState>) -> String {
+                state.name
+            }
+        }
+        #[path = "state.rs"]
+        pub mod state {
+            pub type State = crate::AdminState;
+            pub fn state() -> State {
+                crate::AdminState {
+                    name: "admin".to_string(),
+                }
+            }
+        }
+        #[path = "users"]
+        pub mod users {
+            #[path = "route.rs"]
+            pub mod route {
+                use axum::extract::State;
+                use crate::AdminState;
+                pub async fn get(State(state): State<AdminState>) -> String {
+                    ::alloc::__export::must_use({
+                        ::alloc::fmt::format(format_args!("{0} users", state.name))
+                    })
+                }
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/admin", "admin/route.rs"),
+        ("GET", "/admin/users", "admin/users/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        router = router
+            .merge({
+                type AdminState = __folder_router__myfolderrouter::admin::state::State;
+                let mut router = axum::Router::<AdminState>::new();
+                #[inline(never)]
+                #[allow(unused_mut)]
+                fn __register_admin(
+                    mut router: axum::Router<AdminState>,
+                ) -> axum::Router<AdminState> {
+                    router = router
+                        .route(
+                            "/admin",
+                            axum::routing::get(
+                                __folder_router__myfolderrouter::admin::route::get,
+                            ),
+                        );
+                    router = router
+                        .route(
+                            "/admin/users",
+                            axum::routing::get(
+                                __folder_router__myfolderrouter::admin::users::route::get,
+                            ),
+                        );
+                    router
+                }
+                router = __register_admin(router);
+                router.with_state(__folder_router__myfolderrouter::admin::state::state())
+            });
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET  /\nGET  /admin\nGET  /admin/users\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /admin", "GET /admin/users"];
+        f.debug_list().entries(routes).finish()
+    }
+}
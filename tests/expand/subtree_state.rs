@@ -0,0 +1,12 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[derive(Clone)]
+struct AdminState {
+    name: String,
+}
+
+#[folder_router("tests/expand/subtree_state", AppState)]
+struct MyFolderRouter();
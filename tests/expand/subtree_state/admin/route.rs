@@ -0,0 +1,7 @@
+use axum::extract::State;
+
+use crate::AdminState;
+
+pub async fn get(State(state): State<AdminState>) -> String {
+    state.name
+}
@@ -0,0 +1,7 @@
+pub type State = crate::AdminState;
+
+pub fn state() -> State {
+    crate::AdminState {
+        name: "admin".to_string(),
+    }
+}
@@ -0,0 +1,7 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router("examples/versioned/api", AppState, trailing_slash = "redirect")]
+struct MyFolderRouter();
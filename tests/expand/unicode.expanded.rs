@@ -0,0 +1,134 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/unicode", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/unicode"
+/// [folder_router] Found route.rs for axum_path: "/hello%20world", mod_path: ["hello_u20_world", "route"]
+/// [folder_router] Found methods for axum_path: "/hello%20world", mod_path: ["hello_u20_world", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/%C3%BCber", mod_path: ["_ufc_ber", "route"]
+/// [folder_router] Found methods for axum_path: "/%C3%BCber", mod_path: ["_ufc_ber", "route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/%E6%9D%B1%E4%BA%AC/{名前}", mod_path: ["_u6771__u4eac_", "___u540d__u524d_", "route"]
+/// [folder_router] Found methods for axum_path: "/%E6%9D%B1%E4%BA%AC/{名前}", mod_path: ["_u6771__u4eac_", "___u540d__u524d_", "route"], methods: ["get"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/unicode"]
+mod __folder_router__myfolderrouter {
+    #[path = "hello world"]
+    pub mod hello_u20_world {
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Hello World!"
+            }
+        }
+    }
+    #[path = "über"]
+    pub mod _ufc_ber {
+        #[path = "route.rs"]
+        pub mod route {
+            pub async fn get() -> &'static str {
+                "Hello World!"
+            }
+        }
+    }
+    #[path = "東京"]
+    pub mod _u6771__u4eac_ {
+        #[path = "[名前]"]
+        pub mod ___u540d__u524d_ {
+            #[path = "route.rs"]
+            pub mod route {
+                pub async fn get() -> &'static str {
+                    "Hello World!"
+                }
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/%C3%BCber", "über/route.rs"),
+        ("GET", "/%E6%9D%B1%E4%BA%AC/{名前}", "東京/[名前]/route.rs"),
+        ("GET", "/hello%20world", "hello world/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_hello_u20_world(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/hello%20world",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::hello_u20_world::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register_hello_u20_world(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register__ufc_ber(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/%C3%BCber",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::_ufc_ber::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register__ufc_ber(router);
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register__u6771__u4eac_(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/%E6%9D%B1%E4%BA%AC/{名前}",
+                    axum::routing::get(
+                        __folder_router__myfolderrouter::_u6771__u4eac_::___u540d__u524d_::route::get,
+                    ),
+                );
+            router
+        }
+        router = __register__u6771__u4eac_(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str(
+            "GET  /%C3%BCber\nGET  /%E6%9D%B1%E4%BA%AC/{名前}\nGET  /hello%20world\n",
+        )
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &[
+            "GET /%C3%BCber",
+            "GET /%E6%9D%B1%E4%BA%AC/{名前}",
+            "GET /hello%20world",
+        ];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,159 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/upload", AppState) item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/upload"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["post"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/upload"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub mod route {
+        pub async fn post(body: axum::body::Bytes) -> String {
+            ::alloc::__export::must_use({
+                ::alloc::fmt::format(format_args!("Uploaded {0} bytes", body.len()))
+            })
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("POST", "/", "route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route(
+                "/",
+                axum::routing::post(
+                    axum::handler::Handler::layer(
+                        axum::handler::Handler::layer(
+                            __folder_router__myfolderrouter::route::post,
+                            axum::extract::DefaultBodyLimit::max(10485760usize),
+                        ),
+                        axum::middleware::from_fn(|
+                            request: axum::extract::Request,
+                            next: axum::middleware::Next|
+                        async move {
+                            use axum::{
+                                http::{header, StatusCode},
+                                response::IntoResponse,
+                            };
+                            const ACCEPT: &[&str] = &["image/png", "image/*"];
+                            let accepts = |content_type: &str| {
+                                let essence = content_type
+                                    .split(';')
+                                    .next()
+                                    .unwrap_or_default();
+                                let essence = essence.trim().to_ascii_lowercase();
+                                ACCEPT.is_empty()
+                                    || ACCEPT
+                                        .iter()
+                                        .any(|accepted| match accepted.strip_suffix("/*") {
+                                            Some(kind) => essence.split('/').next() == Some(kind),
+                                            None => essence == *accepted,
+                                        })
+                            };
+                            let content_type = request
+                                .headers()
+                                .get(header::CONTENT_TYPE)
+                                .and_then(|value| value.to_str().ok())
+                                .unwrap_or_default()
+                                .to_string();
+                            if !content_type
+                                .to_ascii_lowercase()
+                                .starts_with("multipart/form-data")
+                            {
+                                if !accepts(&content_type) {
+                                    return StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response();
+                                }
+                                return next.run(request).await;
+                            }
+                            let Some(boundary) = content_type
+                                .split(';')
+                                .find_map(|param| param.trim().strip_prefix("boundary="))
+                                .map(|boundary| ::alloc::__export::must_use({
+                                    ::alloc::fmt::format(
+                                        format_args!("--{0}", boundary.trim_matches('"')),
+                                    )
+                                })) else {
+                                return StatusCode::BAD_REQUEST.into_response();
+                            };
+                            let (parts, body) = request.into_parts();
+                            let Ok(bytes) = axum::body::to_bytes(body, 10485760usize)
+                                .await else {
+                                return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+                            };
+                            let find = |haystack: &[u8], needle: &[u8]| {
+                                haystack
+                                    .windows(needle.len())
+                                    .position(|window| window == needle)
+                            };
+                            let mut rest: &[u8] = &bytes;
+                            while let Some(start) = find(rest, boundary.as_bytes()) {
+                                rest = &rest[start + boundary.len()..];
+                                if rest.starts_with(b"--") {
+                                    break;
+                                }
+                                let part = &rest[..find(rest, boundary.as_bytes())
+                                    .unwrap_or(rest.len())];
+                                let Some(headers_end) = find(part, b"\r\n\r\n") else {
+                                    return StatusCode::BAD_REQUEST.into_response();
+                                };
+                                let headers = String::from_utf8_lossy(&part[..headers_end])
+                                    .to_ascii_lowercase();
+                                if headers.contains("filename=") {
+                                    let file_type = headers
+                                        .lines()
+                                        .find_map(|line| line.strip_prefix("content-type:"))
+                                        .map_or("application/octet-stream", str::trim);
+                                    if !accepts(file_type) {
+                                        return StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response();
+                                    }
+                                    if part.len().saturating_sub(headers_end + 6) > 2097152usize
+                                    {
+                                        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+                                    }
+                                }
+                                rest = &rest[part.len()..];
+                            }
+                            let request = axum::extract::Request::from_parts(
+                                parts,
+                                axum::body::Body::from(bytes),
+                            );
+                            next.run(request).await
+                        }),
+                    ),
+                ),
+            );
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("POST  /\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["POST /"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,12 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router(
+    "examples/versioned/api",
+    AppState,
+    deprecated = ["v1"],
+    sunset = { v1 = "Fri, 01 Jan 2027 00:00:00 GMT" }
+)]
+struct MyFolderRouter();
@@ -0,0 +1,88 @@
+/// [folder_router] Running folder_router macro attrs:("tests/expand/visibility", AppState, visibility = "crate") item: struct MyFolderRouter();
+/// [folder_router] Tracking path: "/home/tristand/code/axum-folder-router/tests/expand/visibility"
+/// [folder_router] Found route.rs for axum_path: "/", mod_path: ["route"]
+/// [folder_router] Found methods for axum_path: "/", mod_path: ["route"], methods: ["get"]
+/// [folder_router] Found route.rs for axum_path: "/users", mod_path: ["users", "route"]
+/// [folder_router] Found methods for axum_path: "/users", mod_path: ["users", "route"], methods: ["get", "post"]
+#![feature(prelude_import)]
+#[macro_use]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use axum_folder_router::folder_router;
+struct AppState;
+#[automatically_derived]
+impl ::core::clone::Clone for AppState {
+    #[inline]
+    fn clone(&self) -> AppState {
+        AppState
+    }
+}
+struct MyFolderRouter();
+#[path = "/home/tristand/code/axum-folder-router/tests/expand/visibility"]
+mod __folder_router__myfolderrouter {
+    #[path = "route.rs"]
+    pub(crate) mod route {
+        pub(crate) async fn get() -> &'static str {
+            "Hello World!"
+        }
+    }
+    #[path = "users"]
+    pub(crate) mod users {
+        #[path = "route.rs"]
+        pub(crate) mod route {
+            use axum::extract::Json;
+            pub(crate) async fn get() -> &'static str {
+                "Users"
+            }
+            pub(crate) async fn post(Json(name): Json<String>) -> String {
+                name
+            }
+        }
+    }
+}
+impl MyFolderRouter {
+    /// The `(method, path, file)` of every route, checked for
+    /// conflicts by `merge_all!`
+    pub const ROUTES: &'static [(&'static str, &'static str, &'static str)] = &[
+        ("GET", "/", "route.rs"),
+        ("GET", "/users", "users/route.rs"),
+        ("POST", "/users", "users/route.rs"),
+    ] as &[(&str, &str, &str)];
+    pub fn into_router() -> axum::Router<AppState> {
+        let mut router = axum::Router::new();
+        router = router
+            .route("/", axum::routing::get(__folder_router__myfolderrouter::route::get));
+        #[inline(never)]
+        #[allow(unused_mut)]
+        fn __register_users(
+            mut router: axum::Router<AppState>,
+        ) -> axum::Router<AppState> {
+            router = router
+                .route(
+                    "/users",
+                    axum::routing::get(
+                            __folder_router__myfolderrouter::users::route::get,
+                        )
+                        .post(__folder_router__myfolderrouter::users::route::post),
+                );
+            router
+        }
+        router = __register_users(router);
+        router
+    }
+    pub fn into_router_with_state(state: AppState) -> axum::Router {
+        Self::into_router().with_state(state)
+    }
+}
+impl ::core::fmt::Display for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("GET   /\nGET   /users\nPOST  /users\n")
+    }
+}
+impl ::core::fmt::Debug for MyFolderRouter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let routes: &[&str] = &["GET /", "GET /users", "POST /users"];
+        f.debug_list().entries(routes).finish()
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) async fn get() -> &'static str {
+    "Hello World!"
+}
@@ -0,0 +1,9 @@
+use axum::extract::Json;
+
+pub(crate) async fn get() -> &'static str {
+    "Users"
+}
+
+pub(crate) async fn post(Json(name): Json<String>) -> String {
+    name
+}
@@ -3,3 +3,17 @@ fn ui() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/failures/*.rs");
 }
+
+#[cfg(feature = "rate-limit")]
+#[test]
+fn ui_rate_limit() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/failures/rate_limit/*.rs");
+}
+
+#[cfg(not(feature = "sessions"))]
+#[test]
+fn ui_without_sessions() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/failures/without_sessions/*.rs");
+}
@@ -0,0 +1,6 @@
+use axum_folder_router::FolderRouter;
+
+#[derive(FolderRouter)]
+struct MyFolderRouter();
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[axum_folder_router::cache_control("max-age=300\n")]
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[axum_folder_router::concurrency_limit(0)]
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
+
+fn main() {}
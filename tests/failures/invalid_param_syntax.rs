@@ -0,0 +1,9 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router("../../../../examples/simple/api", AppState, param_syntax = "<id>")]
+struct MyFolderRouter();
+
+fn main() {}
@@ -0,0 +1,3 @@
+pub const ROUTE_PATH: &str = "legacy/users";
+
+pub async fn get() {}
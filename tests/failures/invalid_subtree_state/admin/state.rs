@@ -0,0 +1,3 @@
+pub fn state() -> u32 {
+    0
+}
@@ -0,0 +1,6 @@
+#[axum_folder_router::timeout("5 parsecs")]
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[axum_folder_router::upload(limit = "10MB", accept = ["png"])]
+pub async fn post() -> &'static str {
+    "Uploaded"
+}
+
+fn main() {}
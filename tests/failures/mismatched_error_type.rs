@@ -0,0 +1,25 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+struct AppError;
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+    }
+}
+
+#[folder_router(
+    "../../../../tests/failures/mismatched_error_type",
+    AppState,
+    error = AppError
+)]
+struct MyFolderRouter();
+
+fn main() {}
@@ -0,0 +1,13 @@
+use axum::http::StatusCode;
+
+pub async fn get() -> Result<&'static str, crate::AppError> {
+    Ok("Hello World!")
+}
+
+pub async fn post() -> Result<&'static str, StatusCode> {
+    Err(StatusCode::BAD_REQUEST)
+}
+
+pub async fn delete() -> &'static str {
+    "Deleted"
+}
@@ -0,0 +1,12 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[derive(Clone)]
+struct Db;
+
+#[folder_router("../../../../tests/failures/mismatched_state", AppState)]
+struct MyFolderRouter();
+
+fn main() {}
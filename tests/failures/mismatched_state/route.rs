@@ -0,0 +1,7 @@
+use axum::extract::State;
+
+use crate::AppState;
+
+pub async fn get(State(_state): State<AppState>) -> &'static str {
+    "Hello World!"
+}
@@ -0,0 +1,10 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+
+use crate::Db;
+
+pub async fn get(Path(id): Path<u32>, State(_db): State<Db>) -> impl IntoResponse {
+    format!("User {id}")
+}
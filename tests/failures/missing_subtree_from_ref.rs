@@ -0,0 +1,14 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[derive(Clone)]
+struct AdminState {
+    name: String,
+}
+
+#[folder_router("../../../../tests/failures/missing_subtree_from_ref", AppState)]
+struct MyFolderRouter();
+
+fn main() {}
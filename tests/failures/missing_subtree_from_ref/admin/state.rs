@@ -0,0 +1 @@
+pub type State = crate::AdminState;
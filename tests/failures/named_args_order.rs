@@ -0,0 +1,9 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router(state = AppState, path = "../../../../examples/simple/api")]
+struct MyFolderRouter();
+
+fn main() {}
@@ -0,0 +1,7 @@
+use crate::AppState;
+
+pub const HEADER: &str = "x-admin-key";
+
+pub async fn validate(_state: &AppState, key: &str) -> bool {
+    key == "secret"
+}
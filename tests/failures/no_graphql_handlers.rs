@@ -0,0 +1,9 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router("../../../../tests/failures/no_graphql_handlers", AppState)]
+struct MyFolderRouter();
+
+fn main() {}
@@ -0,0 +1,3 @@
+pub async fn graphiql() -> &'static str {
+    "GraphiQL"
+}
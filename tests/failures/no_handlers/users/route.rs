@@ -0,0 +1,11 @@
+async fn get() -> &'static str {
+    "Users"
+}
+
+pub fn post() -> &'static str {
+    "Created"
+}
+
+pub async fn fetch() -> &'static str {
+    "Users"
+}
@@ -0,0 +1,6 @@
+#[axum_folder_router::rate_limit(per_second = 2_000_000_000, burst = 10)]
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
+
+fn main() {}
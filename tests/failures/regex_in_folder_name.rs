@@ -0,0 +1,9 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router("../../../../tests/failures/regex_in_folder_name", AppState)]
+struct MyFolderRouter();
+
+fn main() {}
@@ -0,0 +1,7 @@
+pub async fn get() -> &'static str {
+    "Account"
+}
+
+pub async fn post() -> &'static str {
+    "Updated"
+}
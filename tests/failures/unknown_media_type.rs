@@ -0,0 +1,9 @@
+use axum_folder_router::folder_router;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router("../../../../tests/failures/unknown_media_type", AppState)]
+struct MyFolderRouter();
+
+fn main() {}
@@ -0,0 +1,3 @@
+pub async fn get__csv() -> &'static str {
+    "status\nok"
+}
@@ -0,0 +1,7 @@
+pub async fn get<T: Default + ToString>() -> String {
+    T::default().to_string()
+}
+
+pub async fn post() -> &'static str {
+    "Created"
+}
@@ -0,0 +1,69 @@
+// Checks the subdomains of `[tenant]!host` folders relative to the `host`
+// option
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    routing::any,
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/subdomains", AppState, host = "example.co.uk:3000")]
+struct MyFolderRouter();
+
+async fn body(app: Router, host: &str) -> (StatusCode, String) {
+    let response = app
+        .oneshot(
+            Request::get("/dashboard")
+                .header(header::HOST, host)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn fallback() -> Router {
+    Router::new().route("/dashboard", any(|| async { "Fallback" }))
+}
+
+#[tokio::test]
+async fn subdomains_are_extracted() {
+    let app = MyFolderRouter::into_host_router(AppState, fallback());
+    assert_eq!(
+        body(app, "acme.example.co.uk:3000").await,
+        (StatusCode::OK, "Dashboard of acme".to_string())
+    );
+}
+
+#[tokio::test]
+async fn nested_subdomains_are_not_served() {
+    let app = MyFolderRouter::into_host_router(AppState, fallback());
+    assert_eq!(
+        body(app.clone(), "eu.acme.example.co.uk:3000").await,
+        (StatusCode::OK, "Fallback".to_string())
+    );
+
+    // Served by the router directly, they have no subdomain
+    let app: Router = MyFolderRouter::into_router().with_state(AppState);
+    assert_eq!(
+        body(app, "eu.acme.example.co.uk:3000").await.0,
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn ports_of_the_host_are_compared() {
+    let app = MyFolderRouter::into_host_router(AppState, fallback());
+    assert_eq!(
+        body(app, "acme.example.co.uk:4000").await,
+        (StatusCode::OK, "Fallback".to_string())
+    );
+}
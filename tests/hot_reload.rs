@@ -0,0 +1,37 @@
+// Checks that the router of `hot_reload = true` is built once instead of per
+// request, so handler layers keep their state
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState {
+    in_flight:     Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+#[folder_router("tests/hot_reload", AppState, hot_reload = true)]
+struct MyFolderRouter();
+
+#[tokio::test]
+async fn layers_keep_their_state_between_requests() {
+    let state = AppState::default();
+    let app = MyFolderRouter::into_router_with_state(state.clone());
+    let request = || {
+        app.clone()
+            .oneshot(Request::get("/").body(Body::empty()).unwrap())
+    };
+
+    let (first, second) = tokio::join!(request(), request());
+    assert_eq!(first.unwrap().status(), StatusCode::OK);
+    assert_eq!(second.unwrap().status(), StatusCode::OK);
+    assert_eq!(state.max_in_flight.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,14 @@
+use std::{sync::atomic::Ordering, time::Duration};
+
+use axum::extract::State;
+
+use crate::AppState;
+
+#[axum_folder_router::concurrency_limit(1)]
+pub async fn get(State(state): State<AppState>) -> &'static str {
+    let in_flight = state.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+    state.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    state.in_flight.fetch_sub(1, Ordering::SeqCst);
+    "Exported"
+}
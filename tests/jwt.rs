@@ -0,0 +1,110 @@
+// Checks the bearer tokens validated by `auth = jwt(...)`
+use std::{
+    sync::Once,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    Router,
+};
+use axum_folder_router::folder_router;
+use jsonwebtoken::{EncodingKey, Header};
+use tower::ServiceExt;
+
+#[derive(Clone)]
+struct AppState;
+
+#[folder_router(
+    "tests/jwt",
+    AppState,
+    auth = jwt(paths = ["/admin"], key_env = "TEST_JWT_SECRET"),
+    test_state = test_state
+)]
+struct MyFolderRouter();
+
+// The smoke tests of `test-helpers` would panic without the key
+#[cfg(not(feature = "test-helpers"))]
+#[folder_router("examples/simple/api", AppState, auth = jwt(key_env = "MISSING_JWT_SECRET"))]
+struct MissingKeyRouter();
+
+const SECRET: &str = "secret";
+
+// Sets the key once, as tests run in parallel threads
+fn test_state() -> AppState {
+    static KEY: Once = Once::new();
+    KEY.call_once(|| std::env::set_var("TEST_JWT_SECRET", SECRET));
+    AppState
+}
+
+fn token(expires_in: i64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let claims = serde_json::json!({
+        "sub": "alice",
+        "exp": now.cast_signed() + expires_in,
+    });
+    jsonwebtoken::encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(SECRET.as_bytes()),
+    )
+    .unwrap()
+}
+
+async fn get(path: &str, token: Option<&str>) -> (StatusCode, String) {
+    let state = test_state();
+    let app: Router = MyFolderRouter::into_router().with_state(state);
+
+    let mut request = Request::get(path);
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn valid_tokens_pass_their_claims() {
+    assert_eq!(
+        get("/admin", Some(&token(60))).await,
+        (StatusCode::OK, "Hello alice!".to_string())
+    );
+}
+
+#[tokio::test]
+async fn expired_tokens_are_rejected() {
+    assert_eq!(
+        get("/admin", Some(&token(-3600))).await.0,
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn missing_tokens_are_rejected() {
+    assert_eq!(get("/admin", None).await.0, StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        get("/admin", Some("not-a-jwt")).await.0,
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn routes_outside_the_paths_are_not_validated() {
+    assert_eq!(get("/", None).await.0, StatusCode::OK);
+}
+
+#[cfg(not(feature = "test-helpers"))]
+#[test]
+#[should_panic(expected = "The MISSING_JWT_SECRET env var holding the JWT key isn't set")]
+fn missing_keys_fail_when_the_router_is_built() {
+    let _: Router<AppState> = MissingKeyRouter::into_router();
+}
@@ -0,0 +1,5 @@
+use axum::Extension;
+
+pub async fn get(Extension(claims): Extension<serde_json::Value>) -> String {
+    format!("Hello {}!", claims["sub"].as_str().unwrap_or_default())
+}
@@ -0,0 +1,43 @@
+// Checks the `_method` form field of the `method_override` option
+use axum::{
+    body::{to_bytes, Body},
+    extract::DefaultBodyLimit,
+    http::{header, Request, StatusCode},
+    response::Response,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/method_override", AppState, method_override = true)]
+struct MyFolderRouter();
+
+async fn post_form(body: String) -> Response {
+    let app = MyFolderRouter::into_router_with_state(AppState).layer(DefaultBodyLimit::max(128));
+    let request = Request::post("/")
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(body))
+        .unwrap();
+    app.oneshot(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn forms_are_routed_as_their_method() {
+    let response = post_form("_method=delete".to_string()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(&body[..], b"Deleted!");
+
+    let response = post_form("name=Ferris".to_string()).await;
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(&body[..], b"Posted!");
+}
+
+#[tokio::test]
+async fn forms_are_read_up_to_the_body_limit() {
+    let padding = "a".repeat(128);
+    let response = post_form(format!("_method=delete&name={padding}")).await;
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
@@ -0,0 +1,7 @@
+pub async fn post() -> &'static str {
+    "Posted!"
+}
+
+pub async fn delete() -> &'static str {
+    "Deleted!"
+}
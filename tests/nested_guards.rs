@@ -0,0 +1,63 @@
+// Checks that guard.rs files also protect the routers & services nested in
+// their folders
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/nested_guards", AppState)]
+struct MyFolderRouter();
+
+async fn status(path: &str, authorization: Option<&str>) -> StatusCode {
+    let app: Router = MyFolderRouter::into_router().with_state(AppState);
+    let mut request = Request::get(path);
+    if let Some(authorization) = authorization {
+        request = request.header(header::AUTHORIZATION, authorization);
+    }
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    response.status()
+}
+
+#[tokio::test]
+async fn nested_routers_are_guarded() {
+    assert_eq!(status("/metrics", None).await, StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        status("/metrics", Some("Bearer token")).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn nested_services_are_guarded() {
+    assert_eq!(status("/readme", None).await, StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        status("/readme", Some("Bearer token")).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn public_folders_are_guarded() {
+    assert_eq!(
+        status("/public/hello.txt", None).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status("/public/hello.txt", Some("Bearer token")).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn routes_outside_the_guarded_folder_are_not_guarded() {
+    assert_eq!(status("/", None).await, StatusCode::OK);
+}
@@ -0,0 +1,7 @@
+use axum::{routing::get, Router};
+
+use crate::AppState;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("/", get(|| async { "requests_total 42" }))
+}
@@ -0,0 +1,5 @@
+use tower_http::services::ServeFile;
+
+pub fn service() -> ServeFile {
+    ServeFile::new("README.md")
+}
@@ -0,0 +1,67 @@
+// Checks `#[rate_limit(...)]` limits requests per peer IP
+use std::net::SocketAddr;
+
+use axum::{
+    body::Body,
+    extract::connect_info::IntoMakeServiceWithConnectInfo,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::{Service, ServiceExt};
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/rate_limit", AppState)]
+struct MyFolderRouter();
+
+async fn get(
+    make_service: &mut IntoMakeServiceWithConnectInfo<Router, SocketAddr>,
+    peer: &str,
+) -> StatusCode {
+    let app = make_service
+        .call(peer.parse::<SocketAddr>().unwrap())
+        .await
+        .unwrap();
+    app.oneshot(Request::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+async fn requests_are_limited_per_peer() {
+    let mut make_service = MyFolderRouter::into_router()
+        .with_state(AppState)
+        .into_make_service_with_connect_info::<SocketAddr>();
+
+    assert_eq!(
+        get(&mut make_service, "10.0.0.1:1000").await,
+        StatusCode::OK
+    );
+    // Other ports of the same IP share its limit
+    assert_eq!(
+        get(&mut make_service, "10.0.0.1:2000").await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        get(&mut make_service, "10.0.0.1:1000").await,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    assert_eq!(
+        get(&mut make_service, "10.0.0.2:1000").await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn requests_without_connect_info_fail() {
+    let app: Router = MyFolderRouter::into_router().with_state(AppState);
+    let status = app
+        .oneshot(Request::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+        .status();
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
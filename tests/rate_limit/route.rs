@@ -0,0 +1,4 @@
+#[axum_folder_router::rate_limit(per_second = 1, burst = 2)]
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
@@ -0,0 +1,67 @@
+// Checks the `Location` of redirects with path parameters
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/redirects", AppState)]
+struct MyFolderRouter();
+
+async fn redirect(path: &str) -> (StatusCode, Option<String>) {
+    let app: Router = MyFolderRouter::into_router().with_state(AppState);
+    let response = app
+        .oneshot(Request::get(path).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let location = response
+        .headers()
+        .get(header::LOCATION)
+        .map(|location| location.to_str().unwrap().to_string());
+    (response.status(), location)
+}
+
+#[tokio::test]
+async fn parameters_are_percent_encoded() {
+    assert_eq!(
+        redirect("/blog/a%20b").await,
+        (
+            StatusCode::MOVED_PERMANENTLY,
+            Some("/posts/a%20b".to_string())
+        )
+    );
+    assert_eq!(
+        redirect("/blog/caf%C3%A9").await,
+        (
+            StatusCode::MOVED_PERMANENTLY,
+            Some("/posts/caf%C3%A9".to_string())
+        )
+    );
+}
+
+#[tokio::test]
+async fn line_breaks_cant_drop_the_location() {
+    assert_eq!(
+        redirect("/blog/a%0d%0a").await,
+        (
+            StatusCode::MOVED_PERMANENTLY,
+            Some("/posts/a%0D%0A".to_string())
+        )
+    );
+}
+
+#[tokio::test]
+async fn catch_all_parameters_keep_their_slashes() {
+    assert_eq!(
+        redirect("/docs/guide/a%2Fb%3Fc").await,
+        (
+            StatusCode::FOUND,
+            Some("https://docs.example.com/guide/a/b%3Fc".to_string())
+        )
+    );
+}
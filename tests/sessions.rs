@@ -0,0 +1,68 @@
+// Checks that `(session)` groups answer requests without a session with
+// `401 Unauthorized`
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+use tower_sessions::{MemoryStore, SessionManagerLayer};
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/sessions", AppState)]
+struct MyFolderRouter();
+
+fn app() -> Router {
+    MyFolderRouter::into_router()
+        .with_state(AppState)
+        .layer(SessionManagerLayer::new(MemoryStore::default()).with_secure(false))
+}
+
+#[tokio::test]
+async fn requests_without_a_session_are_rejected() {
+    let response = app()
+        .oneshot(Request::get("/account").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn requests_with_a_session_pass() {
+    let app = app();
+    let response = app
+        .clone()
+        .oneshot(Request::post("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let cookie = response.headers()[header::SET_COOKIE]
+        .to_str()
+        .unwrap()
+        .split(';')
+        .next()
+        .unwrap()
+        .to_owned();
+
+    let response = app
+        .oneshot(
+            Request::get("/account")
+                .header(header::COOKIE, cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn routes_outside_the_group_need_no_session() {
+    let response = app()
+        .oneshot(Request::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,6 @@
+use tower_sessions::Session;
+
+pub async fn get(session: Session) -> String {
+    let name: Option<String> = session.get("name").await.unwrap();
+    format!("Hello {}!", name.unwrap_or_default())
+}
@@ -0,0 +1,9 @@
+use tower_sessions::Session;
+
+pub async fn get() -> &'static str {
+    "Hello World!"
+}
+
+pub async fn post(session: Session) {
+    session.insert("name", "Ferris").await.unwrap();
+}
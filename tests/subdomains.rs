@@ -0,0 +1,48 @@
+// Checks the subdomains of `[tenant]!host` folders without the `host` option
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/subdomains", AppState)]
+struct MyFolderRouter();
+
+async fn dashboard(host: &str) -> (StatusCode, String) {
+    let app: Router = MyFolderRouter::into_router().with_state(AppState);
+    let response = app
+        .oneshot(
+            Request::get("/dashboard")
+                .header(header::HOST, host)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn subdomains_are_extracted() {
+    for host in ["acme.example.com", "acme.localhost", "acme.localhost:3000"] {
+        assert_eq!(
+            dashboard(host).await,
+            (StatusCode::OK, "Dashboard of acme".to_string()),
+            "{host}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn hosts_without_a_single_label_subdomain_are_not_found() {
+    for host in ["example.com", "localhost:3000", "eu.acme.example.com"] {
+        assert_eq!(dashboard(host).await.0, StatusCode::NOT_FOUND, "{host}");
+    }
+}
@@ -0,0 +1,7 @@
+use axum::Extension;
+
+use crate::Tenant;
+
+pub async fn get(Extension(Tenant(tenant)): Extension<Tenant>) -> String {
+    format!("Dashboard of {tenant}")
+}
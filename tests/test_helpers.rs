@@ -0,0 +1,16 @@
+// Runs the smoke tests generated for the advanced example
+use axum_folder_router::folder_router;
+
+#[derive(Clone, Debug)]
+struct AppState {
+    _foo: String,
+}
+
+fn app_state() -> AppState {
+    AppState {
+        _foo: String::new(),
+    }
+}
+
+#[folder_router("examples/advanced/api", AppState, test_state = app_state)]
+struct MyFolderRouter();
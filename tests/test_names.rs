@@ -0,0 +1,9 @@
+// Compiles the smoke tests generated for routes whose test names collide,
+// `/a/_2` & `/{a}`, taking the name of `/a`, both being `route_a_2`
+use axum_folder_router::folder_router;
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/test_names", AppState)]
+struct MyFolderRouter();
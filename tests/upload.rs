@@ -0,0 +1,109 @@
+// Checks the limits & content types of `#[upload(...)]` handlers
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Router,
+};
+use axum_folder_router::folder_router;
+use tower::ServiceExt;
+
+#[derive(Clone, Default)]
+struct AppState;
+
+#[folder_router("tests/upload", AppState)]
+struct MyFolderRouter();
+
+const BOUNDARY: &str = "X-BOUNDARY";
+
+// A multipart body holding a single file of `file_type`
+fn multipart_file(file_type: &str, file_len: usize) -> String {
+    format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; \
+         filename=\"file\"\r\nContent-Type: {file_type}\r\n\r\n{}\r\n--{BOUNDARY}--\r\n",
+        "a".repeat(file_len)
+    )
+}
+
+// A multipart body `len` bytes long in total
+fn multipart(file_type: &str, len: usize) -> String {
+    multipart_file(file_type, len - multipart_file(file_type, 0).len())
+}
+
+async fn upload(path: &str, content_type: Option<&str>, body: String) -> StatusCode {
+    let app: Router = MyFolderRouter::into_router().with_state(AppState);
+    let mut request = Request::post(path);
+    if let Some(content_type) = content_type {
+        request = request.header(header::CONTENT_TYPE, content_type);
+    }
+    app.oneshot(request.body(Body::from(body)).unwrap())
+        .await
+        .unwrap()
+        .status()
+}
+
+fn form_data() -> String {
+    format!("multipart/form-data; boundary={BOUNDARY}")
+}
+
+#[tokio::test]
+async fn accepted_content_types_pass() {
+    let status = upload("/", Some(&form_data()), multipart("image/png", 200)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn other_content_types_are_rejected() {
+    let status = upload("/", Some(&form_data()), multipart("text/html", 200)).await;
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn uploads_are_limited() {
+    let status = upload("/", Some(&form_data()), multipart("image/png", 1024)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let status = upload("/", Some(&form_data()), multipart("image/png", 1025)).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn files_are_limited() {
+    let status = upload(
+        "/files",
+        Some(&form_data()),
+        multipart_file("image/png", 16),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let status = upload(
+        "/files",
+        Some(&form_data()),
+        multipart_file("image/png", 17),
+    )
+    .await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn plain_bodies_need_an_accepted_content_type() {
+    let status = upload("/", Some("image/png"), "png".to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let status = upload("/", Some("text/plain"), "text".to_string()).await;
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let status = upload("/", None, "text".to_string()).await;
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn multipart_bodies_need_a_boundary() {
+    let status = upload(
+        "/",
+        Some("multipart/form-data"),
+        multipart("image/png", 200),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
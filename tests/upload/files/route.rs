@@ -0,0 +1,4 @@
+#[axum_folder_router::upload(file_limit = "16B")]
+pub async fn post(body: axum::body::Bytes) -> String {
+    format!("Uploaded {} bytes", body.len())
+}
@@ -0,0 +1,4 @@
+#[axum_folder_router::upload(limit = "1KB", accept = ["image/*"])]
+pub async fn post(body: axum::body::Bytes) -> String {
+    format!("Uploaded {} bytes", body.len())
+}